@@ -0,0 +1,6 @@
+// eslint-disable-next-line no-debugger
+debugger;
+
+// eslint-disable-next-line no-console
+const x = 1;
+console.log(x);
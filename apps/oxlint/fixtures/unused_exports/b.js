@@ -0,0 +1,3 @@
+export * from './c';
+
+export const reexported = 1;
@@ -0,0 +1,2 @@
+export const c = 1;
+export function unusedC() {}
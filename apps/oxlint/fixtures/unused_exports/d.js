@@ -0,0 +1,3 @@
+// eslint-disable-next-line import/no-unused-modules
+export const disabled = 1;
+export const unusedD = 2;
@@ -0,0 +1,7 @@
+import { a } from './a';
+import { c } from './b';
+import * as ns from './ns';
+
+export { reexported } from './b';
+
+console.log(a, c, ns);
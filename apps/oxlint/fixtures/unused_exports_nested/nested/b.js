@@ -0,0 +1 @@
+export const unusedB = 1;
@@ -0,0 +1 @@
+export const unusedA = 1;
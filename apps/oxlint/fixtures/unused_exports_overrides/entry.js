@@ -0,0 +1 @@
+export const unusedEntry = 1;
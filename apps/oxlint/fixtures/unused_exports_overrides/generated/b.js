@@ -0,0 +1 @@
+export const unusedGenerated = 1;
@@ -1,7 +1,8 @@
 use std::{path::PathBuf, str::FromStr};
 
 use bpaf::Bpaf;
-use oxc_linter::AllowWarnDeny;
+use oxc_diagnostics::BaselineMode;
+use oxc_linter::{AllowWarnDeny, FixKind};
 
 use super::{
     expand_glob,
@@ -27,16 +28,39 @@ pub struct LintCommand {
     #[bpaf(external)]
     pub ignore_options: IgnoreOptions,
 
+    #[bpaf(external)]
+    pub stdin_options: StdinOptions,
+
+    #[bpaf(external)]
+    pub git_options: GitOptions,
+
     #[bpaf(external)]
     pub warning_options: WarningOptions,
 
+    #[bpaf(external)]
+    pub inline_config_options: InlineConfigOptions,
+
+    #[bpaf(external)]
+    pub baseline_options: BaselineOptions,
+
     #[bpaf(external)]
     pub output_options: OutputOptions,
 
+    #[bpaf(external)]
+    pub cache_options: CacheOptions,
+
+    #[bpaf(external)]
+    pub timing_options: TimingOptions,
+
     /// list all the rules that are currently registered
     #[bpaf(long("rules"), switch, hide_usage)]
     pub list_rules: bool,
 
+    /// Migrate the ESLint configuration of the current directory into `.oxlintrc.json`,
+    /// from `.eslintrc.json`, `.eslintrc.yaml`, `.eslintrc.yml`, `.eslintrc` or `eslintConfig` in `package.json`
+    #[bpaf(switch, hide_usage)]
+    pub migrate: bool,
+
     #[bpaf(external)]
     pub misc_options: MiscOptions,
 
@@ -62,6 +86,7 @@ pub struct BasicOptions {
     /// Oxlint configuration file (experimental)
     ///  * only `.json` extension is supported
     ///  * tries to be compatible with the ESLint v8's format
+    ///  * defaults to the nearest `.oxlintrc.json` files
     #[bpaf(long, short, argument("./oxlintrc.json"))]
     pub config: Option<PathBuf>,
 
@@ -122,6 +147,68 @@ pub struct FixOptions {
     /// Fix as many issues as possible. Only unfixed issues are reported in the output
     #[bpaf(switch)]
     pub fix: bool,
+
+    /// Apply auto-fixable suggestions as well. May change program behavior.
+    #[bpaf(switch, hide_usage)]
+    pub fix_suggestions: bool,
+
+    /// Apply dangerous fixes and suggestions as well. May change program behavior.
+    #[bpaf(switch, hide_usage)]
+    pub fix_dangerously: bool,
+}
+
+impl FixOptions {
+    /// Kinds of fixes to apply, where `--fix-suggestions` and `--fix-dangerously` imply `--fix`.
+    pub fn fix_kind(&self) -> FixKind {
+        let mut kind = FixKind::None;
+        if self.fix || self.fix_suggestions || self.fix_dangerously {
+            kind |= FixKind::SafeFix;
+        }
+        if self.fix_suggestions || self.fix_dangerously {
+            kind |= FixKind::Suggestion;
+        }
+        if self.fix_dangerously {
+            kind |= FixKind::DangerousFix;
+        }
+        kind
+    }
+}
+
+/// Using stdin
+#[derive(Debug, Clone, Bpaf)]
+pub struct StdinOptions {
+    /// Lint code provided on stdin instead of files.
+    /// With `--fix`, only the fixed code is printed to stdout
+    #[bpaf(switch, hide_usage)]
+    pub stdin: bool,
+
+    /// Path of the code provided on stdin, which does not need to exist,
+    /// for inferring its language and configuration
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub stdin_filename: Option<PathBuf>,
+}
+
+/// Changed Files
+#[derive(Debug, Clone, Bpaf)]
+pub struct GitOptions {
+    /// Only lint files changed since the merge base of the git ref and `HEAD`,
+    /// including uncommitted and untracked files
+    #[bpaf(argument("REF"), hide_usage)]
+    pub changed_since: Option<String>,
+
+    /// Only lint files staged in git, which must not have unstaged changes
+    #[bpaf(switch, hide_usage)]
+    pub staged: bool,
+
+    /// Only report diagnostics on the changed lines of files, with `--changed-since` or `--staged`
+    #[bpaf(switch, hide_usage)]
+    pub only_changed_lines: bool,
+}
+
+impl GitOptions {
+    pub fn is_enabled(&self) -> bool {
+        self.changed_since.is_some() || self.staged
+    }
 }
 
 /// Handle Warnings
@@ -141,14 +228,91 @@ pub struct WarningOptions {
     pub max_warnings: Option<usize>,
 }
 
+/// Inline Configuration Comments
+#[derive(Debug, Clone, Bpaf)]
+pub struct InlineConfigOptions {
+    /// Report directive comments like `// eslint-disable-line` as errors,
+    /// when no errors would have been reported on that line anyway
+    #[bpaf(switch, hide_usage)]
+    pub report_unused_disable_directives: bool,
+
+    /// Same as `--report-unused-disable-directives`, but with the given severity (off, warn or error)
+    #[bpaf(argument("SEVERITY"), optional, hide_usage)]
+    pub report_unused_disable_directives_severity: Option<AllowWarnDeny>,
+}
+
+impl InlineConfigOptions {
+    /// Severity of unused disable directives, not reported when `Allow`.
+    pub fn report_unused_directives(&self) -> AllowWarnDeny {
+        match self.report_unused_disable_directives_severity {
+            Some(severity) => severity,
+            None if self.report_unused_disable_directives => AllowWarnDeny::Deny,
+            None => AllowWarnDeny::Allow,
+        }
+    }
+}
+
+/// Baseline
+#[derive(Debug, Clone, Bpaf)]
+pub struct BaselineOptions {
+    #[bpaf(external, optional, hide_usage)]
+    pub baseline_file: Option<BaselineFile>,
+}
+
+#[derive(Debug, Clone, Bpaf)]
+#[bpaf(adjacent)]
+pub struct BaselineFile {
+    // The `--baseline` flag itself, which the mode and the path must follow.
+    /// Suppress the diagnostics recorded in a baseline file
+    pub baseline: (),
+
+    /// `write` all diagnostics, or `check` to only report new ones
+    #[bpaf(positional("MODE"))]
+    pub mode: BaselineMode,
+
+    /// Path of the baseline file
+    #[bpaf(positional("FILE"))]
+    pub path: PathBuf,
+}
+
 /// Output
 #[derive(Debug, Clone, Bpaf)]
 pub struct OutputOptions {
-    /// Use a specific output format (default, json, unix, checkstyle, github)
+    /// Use a specific output format (default, json, unix, checkstyle, github, sarif, junit, gitlab)
     #[bpaf(long, short, fallback(OutputFormat::Default), hide_usage)]
     pub format: OutputFormat,
 }
 
+/// Caching
+#[derive(Debug, Clone, Bpaf)]
+pub struct CacheOptions {
+    /// Only lint changed files, reusing the results of unchanged files from previous runs
+    #[bpaf(switch, hide_usage)]
+    pub cache: bool,
+
+    /// Path of the cache file, defaults to `.oxlintcache`
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub cache_location: Option<PathBuf>,
+}
+
+/// Timing
+#[derive(Debug, Clone, Bpaf)]
+pub struct TimingOptions {
+    /// Print the time spent in each rule, and in parsing, semantic analysis and linting
+    #[bpaf(switch, hide_usage)]
+    pub timing: bool,
+
+    /// Write the timings of each rule and file as JSON to a file
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub timing_json: Option<PathBuf>,
+}
+
+impl TimingOptions {
+    pub fn is_enabled(&self) -> bool {
+        self.timing || self.timing_json.is_some()
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum OutputFormat {
     Default,
@@ -158,6 +322,14 @@ pub enum OutputFormat {
     Json,
     Unix,
     Checkstyle,
+    /// Static Analysis Results Interchange Format
+    /// <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+    Sarif,
+    /// JUnit XML, read by Jenkins and other CI services
+    Junit,
+    /// GitLab Code Quality
+    /// <https://docs.gitlab.com/ee/ci/testing/code_quality.html>
+    Gitlab,
 }
 
 impl FromStr for OutputFormat {
@@ -170,6 +342,9 @@ impl FromStr for OutputFormat {
             "unix" => Ok(Self::Unix),
             "checkstyle" => Ok(Self::Checkstyle),
             "github" => Ok(Self::Github),
+            "sarif" => Ok(Self::Sarif),
+            "junit" => Ok(Self::Junit),
+            "gitlab" => Ok(Self::Gitlab),
             _ => Err(format!("'{s}' is not a known format")),
         }
     }
@@ -258,7 +433,8 @@ mod warning_options {
 mod lint_options {
     use std::{fs::File, path::PathBuf};
 
-    use oxc_linter::AllowWarnDeny;
+    use oxc_diagnostics::BaselineMode;
+    use oxc_linter::{AllowWarnDeny, FixKind};
 
     use super::{lint_command, LintCommand, OutputFormat};
 
@@ -273,6 +449,9 @@ mod lint_options {
         assert_eq!(options.paths, vec![PathBuf::from(".")]);
         assert!(!options.fix_options.fix);
         assert!(!options.list_rules);
+        assert!(!options.cache_options.cache);
+        assert_eq!(options.inline_config_options.report_unused_directives(), AllowWarnDeny::Allow);
+        assert!(!options.git_options.is_enabled());
         assert_eq!(options.output_options.format, OutputFormat::Default);
     }
 
@@ -335,6 +514,21 @@ mod lint_options {
     fn fix() {
         let options = get_lint_options("--fix test.js");
         assert!(options.fix_options.fix);
+        assert_eq!(options.fix_options.fix_kind(), FixKind::SafeFix);
+    }
+
+    #[test]
+    fn fix_suggestions() {
+        let options = get_lint_options("--fix-suggestions test.js");
+        assert!(options.fix_options.fix_suggestions);
+        assert_eq!(options.fix_options.fix_kind(), FixKind::SafeFix | FixKind::Suggestion);
+    }
+
+    #[test]
+    fn fix_dangerously() {
+        let options = get_lint_options("--fix-dangerously test.js");
+        assert!(options.fix_options.fix_dangerously);
+        assert_eq!(options.fix_options.fix_kind(), FixKind::all());
     }
 
     #[test]
@@ -357,6 +551,15 @@ mod lint_options {
         let options = get_lint_options("-f json");
         assert_eq!(options.output_options.format, OutputFormat::Json);
         assert!(options.paths.is_empty());
+
+        let options = get_lint_options("-f sarif");
+        assert_eq!(options.output_options.format, OutputFormat::Sarif);
+
+        let options = get_lint_options("-f junit");
+        assert_eq!(options.output_options.format, OutputFormat::Junit);
+
+        let options = get_lint_options("-f gitlab");
+        assert_eq!(options.output_options.format, OutputFormat::Gitlab);
     }
 
     #[test]
@@ -368,9 +571,102 @@ mod lint_options {
         ));
     }
 
+    #[test]
+    fn changed_since() {
+        let options = get_lint_options("--changed-since origin/main --only-changed-lines src");
+        assert_eq!(options.git_options.changed_since.as_deref(), Some("origin/main"));
+        assert!(!options.git_options.staged);
+        assert!(options.git_options.only_changed_lines);
+        assert!(options.git_options.is_enabled());
+    }
+
+    #[test]
+    fn staged() {
+        let options = get_lint_options("--staged");
+        assert!(options.git_options.staged);
+        assert!(options.git_options.is_enabled());
+        assert!(options.paths.is_empty());
+    }
+
+    #[test]
+    fn report_unused_disable_directives() {
+        let options = get_lint_options("--report-unused-disable-directives");
+        assert_eq!(options.inline_config_options.report_unused_directives(), AllowWarnDeny::Deny);
+
+        let options = get_lint_options("--report-unused-disable-directives-severity warn");
+        assert_eq!(options.inline_config_options.report_unused_directives(), AllowWarnDeny::Warn);
+
+        let options = get_lint_options(
+            "--report-unused-disable-directives --report-unused-disable-directives-severity off",
+        );
+        assert_eq!(options.inline_config_options.report_unused_directives(), AllowWarnDeny::Allow);
+
+        let args = ["--report-unused-disable-directives-severity", "never"];
+        assert!(lint_command().run_inner(args.as_slice()).is_err());
+    }
+
+    #[test]
+    fn baseline() {
+        let options = get_lint_options(".");
+        assert!(options.baseline_options.baseline_file.is_none());
+
+        let options = get_lint_options("--baseline write baseline.json src");
+        let baseline_file = options.baseline_options.baseline_file.unwrap();
+        assert_eq!(baseline_file.mode, BaselineMode::Write);
+        assert_eq!(baseline_file.path, PathBuf::from("baseline.json"));
+        assert_eq!(options.paths, vec![PathBuf::from("src")]);
+
+        let options = get_lint_options("--baseline check baseline.json");
+        assert_eq!(options.baseline_options.baseline_file.unwrap().mode, BaselineMode::Check);
+
+        let args = ["--baseline", "update", "baseline.json"];
+        assert!(lint_command().run_inner(args.as_slice()).is_err());
+    }
+
+    #[test]
+    fn cache() {
+        let options = get_lint_options("--cache --cache-location .cache/oxlint src");
+        assert!(options.cache_options.cache);
+        assert_eq!(options.cache_options.cache_location, Some(PathBuf::from(".cache/oxlint")));
+    }
+
+    #[test]
+    fn stdin() {
+        let options = get_lint_options(".");
+        assert!(!options.stdin_options.stdin);
+        assert!(options.stdin_options.stdin_filename.is_none());
+
+        let options = get_lint_options("--stdin --stdin-filename src/foo.tsx");
+        assert!(options.stdin_options.stdin);
+        assert_eq!(options.stdin_options.stdin_filename, Some(PathBuf::from("src/foo.tsx")));
+        assert!(options.paths.is_empty());
+    }
+
+    #[test]
+    fn timing() {
+        let options = get_lint_options(".");
+        assert!(!options.timing_options.is_enabled());
+
+        let options = get_lint_options("--timing src");
+        assert!(options.timing_options.timing);
+        assert!(options.timing_options.is_enabled());
+
+        let options = get_lint_options("--timing-json timing.json src");
+        assert!(!options.timing_options.timing);
+        assert_eq!(options.timing_options.timing_json, Some(PathBuf::from("timing.json")));
+        assert!(options.timing_options.is_enabled());
+    }
+
     #[test]
     fn list_rules() {
         let options = get_lint_options("--rules");
         assert!(options.list_rules);
     }
+
+    #[test]
+    fn migrate() {
+        let options = get_lint_options("--migrate");
+        assert!(options.migrate);
+        assert!(!get_lint_options(".").migrate);
+    }
 }
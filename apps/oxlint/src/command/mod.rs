@@ -7,7 +7,10 @@ use bpaf::Bpaf;
 
 pub use self::{
     ignore::IgnoreOptions,
-    lint::{lint_command, LintCommand, OutputFormat, OutputOptions, WarningOptions},
+    lint::{
+        lint_command, BaselineFile, GitOptions, LintCommand, OutputFormat, OutputOptions,
+        StdinOptions, TimingOptions, WarningOptions,
+    },
 };
 
 const VERSION: &str = match option_env!("OXC_VERSION") {
@@ -25,6 +28,10 @@ pub struct MiscOptions {
     /// Number of threads to use. Set to 1 for using only 1 CPU core
     #[bpaf(argument("INT"), hide_usage)]
     pub threads: Option<usize>,
+
+    /// Print the configuration applying to the file as JSON, with its rules, env, globals and settings
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub print_config: Option<PathBuf>,
 }
 
 #[allow(clippy::ptr_arg)]
@@ -65,6 +72,8 @@ fn expand_glob(paths: Vec<PathBuf>) -> Vec<PathBuf> {
 
 #[cfg(test)]
 mod misc_options {
+    use std::path::PathBuf;
+
     use super::{lint::lint_command, MiscOptions};
 
     fn get_misc_options(arg: &str) -> MiscOptions {
@@ -76,6 +85,13 @@ mod misc_options {
     fn default() {
         let options = get_misc_options(".");
         assert!(options.threads.is_none());
+        assert!(options.print_config.is_none());
+    }
+
+    #[test]
+    fn print_config() {
+        let options = get_misc_options("--print-config src/foo.js");
+        assert_eq!(options.print_config, Some(PathBuf::from("src/foo.js")));
     }
 
     #[test]
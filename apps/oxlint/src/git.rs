@@ -1,54 +1,207 @@
-use std::path::Path;
+use std::{
+    collections::HashMap,
+    ops::Range,
+    path::{Path, PathBuf},
+};
 
-use git2::Repository;
+use git2::{Delta, Diff, DiffOptions, Patch, Repository};
+use oxc_diagnostics::OxcDiagnostic;
 
-use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
+/// Changed files by absolute path, with the 1-based ranges of their changed lines.
+pub type ChangedFiles = HashMap<PathBuf, Vec<Range<usize>>>;
 
-pub struct Git<'a> {
-    // paths: &'a Vec<Box<Path>>,
-    repos: Vec<Repository>,
+/// Computes the files changed in a Git repository.
+pub struct Git {
+    repo: Repository,
 }
 
-impl<'a> Git<'a> {
-    pub fn new() -> Self {
-        // let repos: Vec<Repository> =
-        // paths.iter().filter_map(|path| Repository::discover(path).ok()).collect();
-        Self { repos }
+impl Git {
+    /// Open the repository containing `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `path` does not belong to a non-bare repository.
+    pub fn discover(path: &Path) -> Result<Self, OxcDiagnostic> {
+        let repo = Repository::discover(path).map_err(|_| {
+            OxcDiagnostic::error("No repository found")
+                .with_help("Ensure target path(s) belong to a Git repository")
+        })?;
+        if repo.workdir().is_none() {
+            return Err(OxcDiagnostic::error("Bare repository found").with_help(
+                "Ensure target path(s) belong to a Git repository with a working tree",
+            ));
+        }
+        Ok(Self { repo })
     }
 
-    pub fn verify(&'a self) -> Result<&'a Repository, Error> {
-        if self.repos.is_empty() {
-            return Err(OxcDiagnostic::warning("No repository found"))
-                .with_help("Ensure target path(s) belong to a Git repository");
-        }
-        match self.is_same_repo() {
-            Ok(repo) => {
-                for path in self.paths {
-                    if Self::is_uncommitted(repo, path) {
-                        return Err(OxcDiagnostic::warning("Uncommitted changes")
-                            .with_help("Commit any changes before linting"));
-                    }
+    /// Files changed in the working tree or the index since the merge base of `rev` and `HEAD`,
+    /// including untracked files.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if `rev` cannot be resolved or the repository cannot be diffed.
+    pub fn changed_since(&self, rev: &str) -> Result<ChangedFiles, OxcDiagnostic> {
+        let resolve_error = |err: git2::Error| {
+            OxcDiagnostic::error(format!("Failed to resolve git ref {rev:?}"))
+                .with_help(err.message().to_string())
+        };
+        let commit = self
+            .repo
+            .revparse_single(rev)
+            .and_then(|object| object.peel_to_commit())
+            .map_err(resolve_error)?;
+        let head =
+            self.repo.head().and_then(|head| head.peel_to_commit()).map_err(resolve_error)?;
+        let tree = self
+            .repo
+            .merge_base(commit.id(), head.id())
+            .and_then(|base| self.repo.find_commit(base))
+            .and_then(|base| base.tree())
+            .map_err(resolve_error)?;
+
+        let mut options = Self::diff_options();
+        options.include_untracked(true).recurse_untracked_dirs(true).show_untracked_content(true);
+        let diff = self
+            .repo
+            .diff_tree_to_workdir_with_index(Some(&tree), Some(&mut options))
+            .map_err(|err| Self::diff_error(&err))?;
+        self.changed_files(&diff)
+    }
+
+    /// Files changed in the index, compared to `HEAD`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the repository cannot be diffed.
+    pub fn staged(&self) -> Result<ChangedFiles, OxcDiagnostic> {
+        // There is no `HEAD` before the first commit, where all staged files are new.
+        let tree = match self.repo.head() {
+            Ok(head) => Some(head.peel_to_tree().map_err(|err| Self::diff_error(&err))?),
+            Err(_) => None,
+        };
+        let diff = self
+            .repo
+            .diff_tree_to_index(tree.as_ref(), None, Some(&mut Self::diff_options()))
+            .map_err(|err| Self::diff_error(&err))?;
+        self.changed_files(&diff)
+    }
+
+    /// Files changed in the working tree, compared to the index.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the repository cannot be diffed.
+    pub fn unstaged(&self) -> Result<ChangedFiles, OxcDiagnostic> {
+        let diff = self
+            .repo
+            .diff_index_to_workdir(None, Some(&mut Self::diff_options()))
+            .map_err(|err| Self::diff_error(&err))?;
+        self.changed_files(&diff)
+    }
+
+    fn diff_options() -> DiffOptions {
+        let mut options = DiffOptions::new();
+        options.context_lines(0);
+        options
+    }
+
+    fn diff_error(err: &git2::Error) -> OxcDiagnostic {
+        OxcDiagnostic::error("Failed to compute changed files").with_help(err.message().to_string())
+    }
+
+    fn changed_files(&self, diff: &Diff) -> Result<ChangedFiles, OxcDiagnostic> {
+        let workdir = self.repo.workdir().unwrap();
+        let mut files = ChangedFiles::default();
+        for (i, delta) in diff.deltas().enumerate() {
+            if delta.status() == Delta::Deleted {
+                continue;
+            }
+            let Some(relative_path) = delta.new_file().path() else {
+                continue;
+            };
+            let lines = files.entry(workdir.join(relative_path)).or_default();
+            // Binary files have no patch.
+            let Some(patch) = Patch::from_diff(diff, i).map_err(|err| Self::diff_error(&err))?
+            else {
+                continue;
+            };
+            for hunk in 0..patch.num_hunks() {
+                let (hunk, _) = patch.hunk(hunk).map_err(|err| Self::diff_error(&err))?;
+                let start = hunk.new_start() as usize;
+                let end = start + hunk.new_lines() as usize;
+                if start < end {
+                    lines.push(start..end);
                 }
-                Ok(repo)
             }
-            err => err,
         }
+        Ok(files)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::Path};
+
+    use git2::{Repository, Signature};
+
+    use super::Git;
+
+    fn commit(repo: &Repository, message: &str) {
+        let mut index = repo.index().unwrap();
+        index.add_all(["*"], git2::IndexAddOption::DEFAULT, None).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = Signature::now("oxc", "oxc@example.com").unwrap();
+        let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+        let parents = parent.iter().collect::<Vec<_>>();
+        repo.commit(Some("HEAD"), &signature, &signature, message, &tree, &parents).unwrap();
     }
 
-    /// Given a list of repositories, verify they're all the same repository.
-    fn is_same_repo(&self) -> Result<&Repository, Error> {
-        assert!(!self.repos.is_empty());
-        let first_repo = self.repos.first().unwrap();
-        for repo in &self.repos[1..] {
-            if repo.path() != first_repo.path() {
-                return Err(OxcDiagnostic::warning("Multiple repositories found")
-                    .with_help("Ensure all paths belong to a single repository"));
-            }
-        }
-        Ok(first_repo)
+    fn stage(repo: &Repository, path: &str) {
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new(path)).unwrap();
+        index.write().unwrap();
+    }
+
+    #[test]
+    fn test_changed_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        let workdir = repo.workdir().unwrap().to_path_buf();
+        fs::write(workdir.join("a.js"), "a;\nb;\nc;\n").unwrap();
+        fs::write(workdir.join("b.js"), "b;\n").unwrap();
+        fs::write(workdir.join("c.js"), "c;\n").unwrap();
+        commit(&repo, "initial");
+
+        fs::write(workdir.join("a.js"), "a;\nchanged;\nc;\nadded;\n").unwrap();
+        fs::remove_file(workdir.join("b.js")).unwrap();
+        fs::write(workdir.join("d.js"), "d;\n").unwrap();
+        stage(&repo, "d.js");
+        fs::write(workdir.join("e.js"), "e;\n").unwrap();
+
+        let git = Git::discover(&workdir).unwrap();
+
+        let changed = git.changed_since("HEAD").unwrap();
+        assert_eq!(changed.len(), 3);
+        assert_eq!(changed[&workdir.join("a.js")], vec![2..3, 4..5]);
+        assert_eq!(changed[&workdir.join("d.js")], vec![1..2]);
+        assert_eq!(changed[&workdir.join("e.js")], vec![1..2]);
+
+        let staged = git.staged().unwrap();
+        assert_eq!(staged.len(), 1);
+        assert_eq!(staged[&workdir.join("d.js")], vec![1..2]);
+
+        // Untracked files are not unstaged changes.
+        let unstaged = git.unstaged().unwrap();
+        assert_eq!(unstaged.len(), 1);
+        assert_eq!(unstaged[&workdir.join("a.js")], vec![2..3, 4..5]);
+
+        assert!(git.changed_since("unknown-ref").is_err());
     }
 
-    fn is_uncommitted(repo: &Repository, path: &Path) -> bool {
-        repo.status_file(path).map_or(true, |status| !matches!(status, git2::Status::CURRENT))
+    #[test]
+    fn test_no_repository() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(Git::discover(dir.path()).is_err());
     }
 }
@@ -1,4 +1,5 @@
 mod command;
+mod git;
 mod lint;
 mod result;
 mod runner;
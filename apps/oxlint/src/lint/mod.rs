@@ -1,14 +1,27 @@
-use std::{env, io::BufWriter, time::Instant};
+use std::{
+    env, fs,
+    io::{self, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Instant,
+};
 
 use ignore::gitignore::Gitignore;
-use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler};
+use oxc_diagnostics::{
+    Baseline, BaselineMode, DiagnosticService, FixedEntry, GraphicalReportHandler, OxcDiagnostic,
+};
 use oxc_linter::{
-    partial_loader::LINT_PARTIAL_LOADER_EXT, LintOptions, LintService, LintServiceOptions, Linter,
+    partial_loader::LINT_PARTIAL_LOADER_EXT, LintCache, LintOptions, LintService,
+    LintServiceOptions, LintTiming, Linter, Migration, ESLINTRC_FILES, OXLINTRC,
 };
 use oxc_span::VALID_EXTENSIONS;
 
 use crate::{
-    command::{LintCommand, OutputFormat, OutputOptions, WarningOptions},
+    command::{
+        BaselineFile, GitOptions, LintCommand, OutputFormat, OutputOptions, StdinOptions,
+        TimingOptions, WarningOptions,
+    },
+    git::{ChangedFiles, Git},
     walk::{Extensions, Walk},
     CliRunResult, LintResult, MiscOptions, Runner,
 };
@@ -31,23 +44,76 @@ impl Runner for LintRunner {
             return CliRunResult::None;
         }
 
+        if self.options.migrate {
+            return Self::migrate();
+        }
+
         let LintCommand {
             paths,
             filter,
             basic_options,
             warning_options,
+            inline_config_options,
+            baseline_options,
             ignore_options,
+            stdin_options,
+            git_options,
             fix_options,
             enable_plugins,
             output_options,
+            cache_options,
+            timing_options,
             misc_options,
             ..
         } = self.options;
 
         let mut paths = paths;
-        let provided_path_count = paths.len();
         let now = Instant::now();
 
+        // Lint the code read from stdin as the file at `--stdin-filename`.
+        let stdin = match Self::read_stdin(&stdin_options, &paths, &git_options) {
+            Ok(stdin) => stdin,
+            Err(message) => return CliRunResult::InvalidOptions { message },
+        };
+        if let (Some(_), Some(stdin_filename)) = (&stdin, stdin_options.stdin_filename) {
+            paths = vec![stdin_filename];
+        }
+        let fix_kind = fix_options.fix_kind();
+        // The fixed code is printed instead of the diagnostics.
+        let print_fixed_stdin = stdin.is_some() && !fix_kind.is_empty();
+
+        // Lint only the files changed in git, within the given paths.
+        let mut changed_lines = None;
+        if git_options.is_enabled() {
+            let changed_files = match Self::changed_files(&git_options, &paths) {
+                Ok(changed_files) => changed_files,
+                Err(message) => return CliRunResult::InvalidOptions { message },
+            };
+            if changed_files.is_empty() {
+                return CliRunResult::LintResult(LintResult {
+                    duration: now.elapsed(),
+                    deny_warnings: warning_options.deny_warnings,
+                    ..LintResult::default()
+                });
+            }
+            if git_options.only_changed_lines {
+                changed_lines = Some(
+                    changed_files
+                        .iter()
+                        .map(|(path, lines)| (path.clone().into_boxed_path(), lines.clone()))
+                        .collect(),
+                );
+            }
+            paths = changed_files.into_keys().collect();
+        } else if git_options.only_changed_lines {
+            return CliRunResult::InvalidOptions {
+                message: "`--only-changed-lines` requires `--changed-since` or `--staged`."
+                    .to_string(),
+            };
+        }
+
+        let provided_path_count = paths.len();
+
         // The ignore crate whitelists explicit paths, but priority
         // should be given to the ignore file. Many users lint
         // automatically and pass a list of changed files explicitly.
@@ -62,6 +128,10 @@ impl Runner for LintRunner {
             // If explicit paths were provided, but all have been
             // filtered, return early.
             if provided_path_count > 0 {
+                // An ignored file is printed unchanged.
+                if let Some(source_text) = stdin.as_deref().filter(|_| print_fixed_stdin) {
+                    Self::print_stdin(source_text);
+                }
                 return CliRunResult::LintResult(LintResult {
                     duration: now.elapsed(),
                     deny_warnings: warning_options.deny_warnings,
@@ -84,8 +154,15 @@ impl Runner for LintRunner {
             .copied()
             .collect::<Vec<&'static str>>();
 
-        let paths =
-            Walk::new(&paths, &ignore_options).with_extensions(Extensions(extensions)).paths();
+        let paths = if misc_options.print_config.is_some() {
+            // Only the configuration is printed, no file is linted.
+            vec![]
+        } else if stdin.is_some() {
+            // The file may not exist, so it is not walked.
+            paths.into_iter().map(PathBuf::into_boxed_path).collect()
+        } else {
+            Walk::new(&paths, &ignore_options).with_extensions(Extensions(extensions)).paths()
+        };
 
         let number_of_files = paths.len();
 
@@ -93,7 +170,10 @@ impl Runner for LintRunner {
         let lint_options = LintOptions::default()
             .with_filter(filter)
             .with_config_path(basic_options.config)
-            .with_fix(fix_options.fix)
+            .with_fix(fix_kind)
+            .with_report_fixes(output_options.format == OutputFormat::Sarif)
+            .with_report_unused_directives(inline_config_options.report_unused_directives())
+            .with_timing(timing_options.is_enabled())
             .with_react_plugin(enable_plugins.react_plugin)
             .with_unicorn_plugin(enable_plugins.unicorn_plugin)
             .with_typescript_plugin(enable_plugins.typescript_plugin)
@@ -113,11 +193,15 @@ impl Runner for LintRunner {
                 let mut err = String::new();
                 handler.render_report(&mut err, diagnostic.as_ref()).unwrap();
                 return CliRunResult::InvalidOptions {
-                    message: "Failed to parse configuration file.\n{err}".to_string(),
+                    message: format!("Failed to parse configuration file.\n{err}"),
                 };
             }
         };
 
+        if let Some(path) = &misc_options.print_config {
+            return Self::print_config(linter, cwd, path);
+        }
+
         let tsconfig = basic_options.tsconfig;
         if let Some(path) = tsconfig.as_ref() {
             if !path.is_file() {
@@ -130,10 +214,43 @@ impl Runner for LintRunner {
             }
         }
 
-        let options = LintServiceOptions { cwd, paths, tsconfig };
+        let cache = cache_options.cache.then(|| {
+            let location =
+                cache_options.cache_location.unwrap_or_else(|| PathBuf::from(".oxlintcache"));
+            Arc::new(LintCache::new(location, env!("CARGO_PKG_VERSION")))
+        });
+
+        let baseline = match baseline_options.baseline_file {
+            Some(BaselineFile { mode, path, .. }) => match Baseline::new(path, mode) {
+                Ok(baseline) => Some(baseline),
+                Err(diagnostic) => {
+                    let handler = GraphicalReportHandler::new();
+                    let mut err = String::new();
+                    handler.render_report(&mut err, &diagnostic).unwrap();
+                    return CliRunResult::InvalidOptions { message: err };
+                }
+            },
+            None => None,
+        };
+        // Baseline entries are only fixed in the files linted, relative to the current working directory
+        // like the paths of the diagnostics.
+        let linted_paths = if baseline.is_some() {
+            paths
+                .iter()
+                .map(|path| path.strip_prefix(&cwd).unwrap_or(path).to_path_buf())
+                .collect::<Vec<_>>()
+        } else {
+            vec![]
+        };
+
+        let stdin_source = if print_fixed_stdin { stdin.clone() } else { None };
+        let options =
+            LintServiceOptions { cwd, paths, tsconfig, cache: cache.clone(), changed_lines, stdin };
         let lint_service = LintService::new(linter, options);
         let mut diagnostic_service =
-            Self::get_diagnostic_service(&warning_options, &output_options, &misc_options);
+            Self::get_diagnostic_service(&warning_options, &output_options, &misc_options)
+                .with_silent(misc_options.silent || print_fixed_stdin)
+                .with_baseline(baseline);
 
         // Spawn linting in another thread so diagnostics can be printed immediately from diagnostic_service.run.
         rayon::spawn({
@@ -145,6 +262,27 @@ impl Runner for LintRunner {
         });
         diagnostic_service.run();
 
+        if let Some(source_text) = stdin_source {
+            Self::print_stdin(&lint_service.fixed_stdin().unwrap_or(source_text));
+        }
+
+        if let Some(baseline) = diagnostic_service.baseline() {
+            Self::finish_baseline(baseline, &linted_paths, misc_options.silent);
+        }
+
+        if let Some(timing) = lint_service.linter().timing() {
+            Self::finish_timing(timing, &timing_options);
+        }
+
+        if let Some(cache) = cache {
+            if let Err(err) = cache.save() {
+                #[allow(clippy::print_stderr)]
+                {
+                    eprintln!("Failed to write the cache file {:?}: {err}", cache.location());
+                }
+            }
+        }
+
         CliRunResult::LintResult(LintResult {
             duration: now.elapsed(),
             number_of_rules: lint_service.linter().number_of_rules(),
@@ -159,6 +297,239 @@ impl Runner for LintRunner {
 }
 
 impl LintRunner {
+    /// Number of rules printed by `--timing`, like ESLint's `TIMING=1`.
+    const TIMING_RULES: usize = 10;
+
+    /// The code provided on stdin with `--stdin`, or `None` when linting files.
+    fn read_stdin(
+        stdin_options: &StdinOptions,
+        paths: &[PathBuf],
+        git_options: &GitOptions,
+    ) -> Result<Option<String>, String> {
+        if !stdin_options.stdin {
+            if stdin_options.stdin_filename.is_some() {
+                return Err("`--stdin-filename` requires `--stdin`.".to_string());
+            }
+            return Ok(None);
+        }
+        if stdin_options.stdin_filename.is_none() {
+            return Err("`--stdin` requires `--stdin-filename`.".to_string());
+        }
+        if !paths.is_empty() {
+            return Err("Paths cannot be given with `--stdin`.".to_string());
+        }
+        if git_options.is_enabled() {
+            return Err(
+                "`--stdin` cannot be used with `--changed-since` or `--staged`.".to_string()
+            );
+        }
+        let mut source_text = String::new();
+        io::stdin()
+            .read_to_string(&mut source_text)
+            .map_err(|err| format!("Failed to read from stdin: {err}"))?;
+        Ok(Some(source_text))
+    }
+
+    fn print_stdin(source_text: &str) {
+        let mut stdout = io::stdout().lock();
+        stdout.write_all(source_text.as_bytes()).and_then(|()| stdout.flush()).unwrap();
+    }
+
+    /// Migrate the ESLint configuration of the current directory into `.oxlintrc.json`,
+    /// and list what could not be migrated.
+    #[allow(clippy::print_stdout)]
+    fn migrate() -> CliRunResult {
+        let Ok(cwd) = env::current_dir() else {
+            return CliRunResult::InvalidOptions {
+                message: "Failed to get current working directory.".to_string(),
+            };
+        };
+        let Some(eslint_config) = Migration::find_eslint_config(&cwd) else {
+            // Flat configurations are javascript modules, which cannot be evaluated.
+            let flat_config = ["eslint.config.js", "eslint.config.mjs", "eslint.config.cjs"]
+                .into_iter()
+                .find(|name| cwd.join(name).is_file());
+            if let Some(flat_config) = flat_config {
+                return CliRunResult::InvalidOptions {
+                    message: format!(
+                        "`{flat_config}` cannot be migrated, only {} are supported.",
+                        ESLINTRC_FILES.join(", ")
+                    ),
+                };
+            }
+            return CliRunResult::InvalidOptions {
+                message: format!(
+                    "No ESLint configuration found, expected one of {}.",
+                    ESLINTRC_FILES.join(", ")
+                ),
+            };
+        };
+        let oxlintrc = cwd.join(OXLINTRC);
+        if oxlintrc.exists() {
+            return CliRunResult::InvalidOptions {
+                message: format!("`{OXLINTRC}` already exists, remove it to migrate again."),
+            };
+        }
+
+        let migration = match Migration::from_file(&eslint_config) {
+            Ok(migration) => migration,
+            Err(diagnostic) => {
+                let handler = GraphicalReportHandler::new();
+                let mut err = String::new();
+                handler.render_report(&mut err, &diagnostic).unwrap();
+                return CliRunResult::InvalidOptions { message: err };
+            }
+        };
+        if let Err(err) = fs::write(&oxlintrc, format!("{:#}\n", migration.config)) {
+            return CliRunResult::InvalidOptions {
+                message: format!("Failed to write {oxlintrc:?}: {err}"),
+            };
+        }
+
+        let eslint_config = eslint_config.strip_prefix(&cwd).unwrap_or(&eslint_config);
+        println!("Migrated {} into {OXLINTRC}.", eslint_config.display());
+        if !migration.unsupported_rules.is_empty() {
+            println!("\nRules not supported by oxlint, which were removed:");
+            for rule in &migration.unsupported_rules {
+                println!("  {rule}");
+            }
+        }
+        if !migration.unsupported_extends.is_empty() {
+            println!("\nConfigurations extended, which were removed:");
+            for name in &migration.unsupported_extends {
+                println!("  {name}");
+            }
+        }
+        if !migration.plugins.is_empty() {
+            println!("\nPlugins not fully covered by oxlint:");
+            for plugin in &migration.plugins {
+                let total = plugin.supported + plugin.unsupported;
+                println!("  {}: {} of {total} configured rules", plugin.name, plugin.supported);
+            }
+        }
+        CliRunResult::None
+    }
+
+    /// Print the configuration applying to `path` as JSON.
+    #[allow(clippy::print_stdout)]
+    fn print_config(linter: Linter, cwd: Box<Path>, path: &Path) -> CliRunResult {
+        let options = LintServiceOptions {
+            cwd,
+            paths: vec![],
+            tsconfig: None,
+            cache: None,
+            changed_lines: None,
+            stdin: None,
+        };
+        match LintService::new(linter, options).resolved_config(path) {
+            Ok(config) => {
+                println!("{config:#}");
+                CliRunResult::None
+            }
+            Err(error) => {
+                let handler = GraphicalReportHandler::new();
+                let mut err = String::new();
+                handler.render_report(&mut err, error.as_ref()).unwrap();
+                CliRunResult::InvalidOptions { message: err }
+            }
+        }
+    }
+
+    /// Files changed in git within `paths`, or within the current working directory without paths.
+    /// Paths are relative to the current working directory.
+    fn changed_files(git_options: &GitOptions, paths: &[PathBuf]) -> Result<ChangedFiles, String> {
+        if git_options.changed_since.is_some() && git_options.staged {
+            return Err("`--changed-since` and `--staged` cannot be used together.".to_string());
+        }
+        let render_report = |diagnostic: OxcDiagnostic| {
+            let handler = GraphicalReportHandler::new();
+            let mut err = String::new();
+            handler.render_report(&mut err, &diagnostic).unwrap();
+            err
+        };
+
+        let cwd = env::current_dir()
+            .map_err(|_| "Failed to get current working directory.".to_string())?;
+        let roots = if paths.is_empty() {
+            vec![cwd.clone()]
+        } else {
+            paths.iter().map(|path| cwd.join(path)).collect()
+        };
+        let start = if roots[0].is_file() { roots[0].parent().unwrap() } else { &roots[0] };
+        let git = Git::discover(start).map_err(render_report)?;
+        let changed_files = match &git_options.changed_since {
+            Some(rev) => git.changed_since(rev),
+            None => git.staged(),
+        }
+        .map_err(render_report)?;
+        let changed_files = changed_files
+            .into_iter()
+            .filter(|(path, _)| path.is_file() && roots.iter().any(|root| path.starts_with(root)))
+            .collect::<ChangedFiles>();
+
+        // Files are read from the working tree, which must have the staged content.
+        if git_options.staged {
+            let unstaged = git.unstaged().map_err(render_report)?;
+            let mut paths = changed_files
+                .keys()
+                .filter(|path| unstaged.contains_key(*path))
+                .map(|path| path.strip_prefix(&cwd).unwrap_or(path).display().to_string())
+                .collect::<Vec<_>>();
+            if !paths.is_empty() {
+                paths.sort_unstable();
+                let diagnostic = OxcDiagnostic::error(format!(
+                    "Staged files have unstaged changes: {}",
+                    paths.join(", ")
+                ))
+                .with_help("Stage or stash the unstaged changes, which would be linted instead of the staged content");
+                return Err(render_report(diagnostic));
+            }
+        }
+
+        Ok(changed_files
+            .into_iter()
+            .map(|(path, lines)| {
+                (path.strip_prefix(&cwd).map(Path::to_path_buf).unwrap_or(path), lines)
+            })
+            .collect())
+    }
+
+    /// Write the baseline file, or report its entries which are fixed.
+    #[allow(clippy::print_stderr)]
+    fn finish_baseline(baseline: &Baseline, linted_paths: &[PathBuf], silent: bool) {
+        if baseline.mode() == BaselineMode::Write {
+            if let Err(err) = baseline.save() {
+                eprintln!("Failed to write the baseline file {:?}: {err}", baseline.path());
+            }
+            return;
+        }
+        let linted_paths = linted_paths.iter().map(PathBuf::as_path).collect::<Vec<_>>();
+        let fixed_entries = baseline.fixed_entries(&linted_paths);
+        if silent || fixed_entries.is_empty() {
+            return;
+        }
+        eprintln!(
+            "Fixed baseline entries, remove them with `--baseline write {}`:",
+            baseline.path().display()
+        );
+        for FixedEntry { file, rule, count } in fixed_entries {
+            eprintln!("  {file}: {rule} ({count})");
+        }
+    }
+
+    /// Print the slowest rules, and write all timings to the `--timing-json` file.
+    #[allow(clippy::print_stdout, clippy::print_stderr)]
+    fn finish_timing(timing: &LintTiming, timing_options: &TimingOptions) {
+        if timing_options.timing {
+            println!("{}", timing.render_table(Self::TIMING_RULES));
+        }
+        if let Some(path) = &timing_options.timing_json {
+            if let Err(err) = fs::write(path, format!("{:#}\n", timing.to_json())) {
+                eprintln!("Failed to write the timing file {path:?}: {err}");
+            }
+        }
+    }
+
     fn get_diagnostic_service(
         warning_options: &WarningOptions,
         output_options: &OutputOptions,
@@ -175,6 +546,9 @@ impl LintRunner {
             OutputFormat::Unix => diagnostic_service.set_unix_reporter(),
             OutputFormat::Checkstyle => diagnostic_service.set_checkstyle_reporter(),
             OutputFormat::Github => diagnostic_service.set_github_reporter(),
+            OutputFormat::Sarif => diagnostic_service.set_sarif_reporter(Linter::rules_metadata()),
+            OutputFormat::Junit => diagnostic_service.set_junit_reporter(),
+            OutputFormat::Gitlab => diagnostic_service.set_gitlab_reporter(),
         }
         diagnostic_service
     }
@@ -182,6 +556,8 @@ impl LintRunner {
 
 #[cfg(all(test, not(target_os = "windows")))]
 mod test {
+    use std::{fs, path::Path};
+
     use super::LintRunner;
     use crate::{lint_command, CliRunResult, LintResult, Runner};
 
@@ -334,6 +710,18 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn eslintrc_invalid_rule_options() {
+        let message = test_invalid_options(&[
+            "-c",
+            "fixtures/invalid_rule_options/eslintrc.json",
+            "fixtures/linter/debugger.js",
+        ]);
+        assert!(message.starts_with("Failed to parse configuration file.\n"));
+        assert!(message.contains("Invalid options for rule `eqeqeq`"));
+        assert!(message.contains("rules.eqeqeq[1]"));
+    }
+
     #[test]
     fn eslintrc_no_undef() {
         let args = &[
@@ -417,6 +805,24 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn eslintrc_overrides() {
+        let args = &["-c", "fixtures/overrides/eslintrc.json", "fixtures/overrides"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 1);
+    }
+
+    #[test]
+    fn nested_config() {
+        let args = &["fixtures/nested_config"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
     #[test]
     fn typescript_eslint() {
         let args = &[
@@ -453,6 +859,21 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn fix_vue_file() {
+        // Fixes of both `<script>` blocks are written back to the file at once.
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("debugger.vue");
+        fs::copy("fixtures/vue/debugger.vue", &path).unwrap();
+        let result = test(&["--fix", path.to_str().unwrap()]);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            fs::read_to_string("fixtures/vue/debugger.vue").unwrap().replace("debugger", "")
+        );
+    }
+
     #[test]
     fn lint_empty_vue_file() {
         let args = &["fixtures/vue/empty.vue"];
@@ -511,4 +932,232 @@ mod test {
         assert_eq!(result.number_of_files, 1);
         assert_eq!(result.number_of_errors, 1);
     }
+
+    #[test]
+    fn test_unused_exports() {
+        let args = &[
+            "--import-plugin",
+            "-c",
+            "fixtures/unused_exports/eslintrc.json",
+            "fixtures/unused_exports",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 8);
+        // `unusedA`, `unusedC`, `unusedD` and `unusedVue`. The exports of the `main` entry point,
+        // of `ignored.js` and disabled by a directive are not reported.
+        assert_eq!(result.number_of_warnings, 4);
+        assert_eq!(result.number_of_errors, 0);
+
+        // The directive of `d.js` only suppresses a diagnostic once all files are linted.
+        let mut args_with_unused_directives = vec!["--report-unused-disable-directives"];
+        args_with_unused_directives.extend(args);
+        let result = test(&args_with_unused_directives);
+        assert_eq!(result.number_of_warnings, 4);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn test_unused_exports_dot_path() {
+        // Linted as `./fixtures/unused_exports/a.js`, but imported from `index.js` with its absolute path,
+        // like the files of `oxlint --import-plugin .`.
+        let args = &[
+            "--import-plugin",
+            "-c",
+            "fixtures/unused_exports/eslintrc.json",
+            "./fixtures/unused_exports",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 8);
+        assert_eq!(result.number_of_warnings, 4);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn test_unused_exports_overrides() {
+        let args = &[
+            "--import-plugin",
+            "-c",
+            "fixtures/unused_exports_overrides/eslintrc.json",
+            "fixtures/unused_exports_overrides",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 3);
+        // `unusedA` is a warning, `unusedEntry` an error and `generated/b.js` is not checked.
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 1);
+    }
+
+    #[test]
+    fn test_unused_exports_nested_config() {
+        let args = &["--import-plugin", "fixtures/unused_exports_nested"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        // The rule is turned off for `nested/b.js`.
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn test_report_unused_disable_directives() {
+        let args = &["fixtures/unused_disable_directives"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 0);
+
+        let args = &["--report-unused-disable-directives", "fixtures/unused_disable_directives"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 1);
+
+        let args = &[
+            "--report-unused-disable-directives-severity",
+            "warn",
+            "fixtures/unused_disable_directives",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn test_git_options() {
+        assert_eq!(
+            test_invalid_options(&["--changed-since", "HEAD", "--staged"]),
+            "`--changed-since` and `--staged` cannot be used together."
+        );
+        assert_eq!(
+            test_invalid_options(&["--only-changed-lines", "fixtures/linter"]),
+            "`--only-changed-lines` requires `--changed-since` or `--staged`."
+        );
+
+        // A temporary repository, so that the test does not depend on the checkout of oxc.
+        let dir = tempfile::tempdir().unwrap();
+        let repo = git2::Repository::init(dir.path()).unwrap();
+        let workdir = repo.workdir().unwrap().to_path_buf();
+        fs::write(workdir.join("committed.js"), "debugger;\n").unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_all(["*"], git2::IndexAddOption::DEFAULT, None).unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = git2::Signature::now("oxc", "oxc@example.com").unwrap();
+        repo.commit(Some("HEAD"), &signature, &signature, "initial", &tree, &[]).unwrap();
+        fs::write(workdir.join("changed.js"), "debugger;\n").unwrap();
+        let workdir = workdir.to_str().unwrap();
+
+        assert!(test_invalid_options(&["--changed-since", "not-a-git-ref", workdir])
+            .contains("Failed to resolve git ref"));
+        let result = test(&["--changed-since", "HEAD", workdir]);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+
+        // The working tree must have the staged content of the files.
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("changed.js")).unwrap();
+        index.write().unwrap();
+        let result = test(&["--staged", workdir]);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        fs::write(Path::new(workdir).join("changed.js"), "debugger;\ndebugger;\n").unwrap();
+        assert!(test_invalid_options(&["--staged", workdir])
+            .contains("Staged files have unstaged changes"));
+    }
+
+    #[test]
+    fn test_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache_location = dir.path().join(".oxlintcache");
+        let cache_location = cache_location.to_str().unwrap();
+        let args = &[
+            "--cache",
+            "--cache-location",
+            cache_location,
+            "fixtures/linter/debugger.js",
+            "fixtures/linter/nan.js",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 2);
+        assert!(dir.path().join(".oxlintcache").is_file());
+
+        // Diagnostics are replayed from the cache.
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+
+        // The cache is not reused for another configuration.
+        let result = test(&[
+            "--cache",
+            "--cache-location",
+            cache_location,
+            "-D",
+            "correctness",
+            "fixtures/linter/debugger.js",
+        ]);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 1);
+    }
+
+    #[test]
+    fn test_baseline() {
+        let dir = tempfile::tempdir().unwrap();
+        let baseline_file = dir.path().join("baseline.json");
+        let baseline_file = baseline_file.to_str().unwrap();
+        let result = test(&["--baseline", "write", baseline_file, "fixtures/linter/debugger.js"]);
+        assert_eq!(result.number_of_warnings, 0);
+        assert!(dir.path().join("baseline.json").is_file());
+
+        // Only the diagnostics of files missing from the baseline are reported.
+        let result = test(&[
+            "--baseline",
+            "check",
+            baseline_file,
+            "fixtures/linter/debugger.js",
+            "fixtures/linter/nan.js",
+        ]);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn test_stdin_invalid_options() {
+        let message = test_invalid_options(&["--stdin"]);
+        assert_eq!(message, "`--stdin` requires `--stdin-filename`.");
+
+        let message = test_invalid_options(&["--stdin-filename", "foo.js"]);
+        assert_eq!(message, "`--stdin-filename` requires `--stdin`.");
+
+        let message =
+            test_invalid_options(&["--stdin", "--stdin-filename", "foo.js", "fixtures/linter"]);
+        assert_eq!(message, "Paths cannot be given with `--stdin`.");
+    }
+
+    #[test]
+    fn test_print_config() {
+        let args = ["--print-config", "fixtures/linter/debugger.js"];
+        let options = lint_command().run_inner(args.as_slice()).unwrap();
+        assert!(matches!(LintRunner::new(options).run(), CliRunResult::None));
+    }
+
+    #[test]
+    fn test_timing_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let timing_file = dir.path().join("timing.json");
+        let result =
+            test(&["--timing-json", timing_file.to_str().unwrap(), "fixtures/linter/debugger.js"]);
+        assert_eq!(result.number_of_warnings, 1);
+        let json = std::fs::read_to_string(timing_file).unwrap();
+        assert!(json.contains("\"no-debugger\""));
+        assert!(json.contains("debugger.js"));
+    }
+
+    #[test]
+    fn test_baseline_missing_file() {
+        let message = test_invalid_options(&[
+            "--baseline",
+            "check",
+            "fixtures/linter/missing-baseline.json",
+            "fixtures/linter/debugger.js",
+        ]);
+        assert!(message.contains("Failed to read baseline file"));
+    }
 }
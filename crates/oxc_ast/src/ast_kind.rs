@@ -5,16 +5,30 @@ use crate::ast::*;
 
 macro_rules! ast_kinds {
     { $($ident:ident($type:ty),)* } => (
-        #[derive(Debug, Clone, Copy)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
         pub enum AstType {
             $($ident,)*
         }
 
+        impl AstType {
+            /// Number of AST node types, for tables indexed by `AstType as usize`.
+            pub const COUNT: usize = [$(Self::$ident),*].len();
+        }
+
         /// Untyped AST Node Kind
         #[derive(Debug, Clone, Copy)]
         pub enum AstKind<'a> {
             $($ident($type),)*
         }
+
+        impl<'a> AstKind<'a> {
+            /// The type of this node, without its data.
+            pub fn ty(&self) -> AstType {
+                match self {
+                    $(Self::$ident(_) => AstType::$ident,)*
+                }
+            }
+        }
     )
 }
 
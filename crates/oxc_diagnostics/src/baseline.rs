@@ -0,0 +1,235 @@
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    reporter::{fingerprint, source_snippet},
+    Error, OxcDiagnostic,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BaselineMode {
+    /// Record all diagnostics into the baseline file instead of reporting them.
+    Write,
+    /// Only report the diagnostics missing from the baseline file.
+    Check,
+}
+
+impl FromStr for BaselineMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "write" => Ok(Self::Write),
+            "check" => Ok(Self::Check),
+            _ => Err(format!("'{s}' is not a baseline mode, expected `write` or `check`")),
+        }
+    }
+}
+
+/// Diagnostics accepted by a baseline file, e.g. the violations existing when a rule is turned on.
+///
+/// Diagnostics are identified by their file, rule and a fingerprint of their message and source code,
+/// so that they are still matched when code is added or removed around them.
+#[derive(Debug)]
+pub struct Baseline {
+    path: PathBuf,
+    mode: BaselineMode,
+    /// Number of occurrences of each entry, recorded in write mode,
+    /// or remaining to be accepted in check mode.
+    entries: HashMap<BaselineKey, usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+struct BaselineKey {
+    file: String,
+    rule: String,
+    fingerprint: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BaselineEntry {
+    #[serde(flatten)]
+    key: BaselineKey,
+    count: usize,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BaselineFile {
+    entries: Vec<BaselineEntry>,
+}
+
+/// A baseline entry with fewer diagnostics reported than accepted, which can be removed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedEntry {
+    pub file: String,
+    pub rule: String,
+    /// Number of occurrences not reported anymore.
+    pub count: usize,
+}
+
+impl Baseline {
+    /// A baseline at `path`, which is read in check mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the baseline file cannot be read or parsed in check mode.
+    pub fn new(path: PathBuf, mode: BaselineMode) -> Result<Self, OxcDiagnostic> {
+        let entries = match mode {
+            BaselineMode::Write => HashMap::new(),
+            BaselineMode::Check => {
+                let read_error = |err: String| {
+                    OxcDiagnostic::error(format!("Failed to read baseline file {path:?}"))
+                        .with_help(err)
+                };
+                let json = fs::read_to_string(&path).map_err(|err| read_error(err.to_string()))?;
+                let baseline_file = serde_json::from_str::<BaselineFile>(&json)
+                    .map_err(|err| read_error(err.to_string()))?;
+                baseline_file.entries.into_iter().map(|entry| (entry.key, entry.count)).collect()
+            }
+        };
+        Ok(Self { path, mode, entries })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn mode(&self) -> BaselineMode {
+        self.mode
+    }
+
+    /// Whether `diagnostic` of the file at `path` is accepted, and therefore not reported.
+    /// Every diagnostic is accepted and recorded in write mode.
+    pub(crate) fn accept(&mut self, path: &Path, diagnostic: &Error) -> bool {
+        let key = BaselineKey::new(path, diagnostic);
+        match self.mode {
+            BaselineMode::Write => {
+                *self.entries.entry(key).or_default() += 1;
+                true
+            }
+            BaselineMode::Check => match self.entries.get_mut(&key) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    true
+                }
+                _ => false,
+            },
+        }
+    }
+
+    /// Entries of the linted `paths` with fewer diagnostics reported than accepted.
+    /// Always empty in write mode.
+    pub fn fixed_entries(&self, paths: &[&Path]) -> Vec<FixedEntry> {
+        if self.mode == BaselineMode::Write {
+            return vec![];
+        }
+        let files = paths.iter().map(|path| normalize(path)).collect::<Vec<_>>();
+        let mut fixed = self
+            .entries
+            .iter()
+            .filter(|(key, count)| **count > 0 && files.contains(&key.file))
+            .map(|(key, count)| FixedEntry {
+                file: key.file.clone(),
+                rule: key.rule.clone(),
+                count: *count,
+            })
+            .collect::<Vec<_>>();
+        fixed.sort_by(|a, b| (&a.file, &a.rule).cmp(&(&b.file, &b.rule)));
+        fixed
+    }
+
+    /// Write the recorded entries to the baseline file, sorted for stable diffs.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the baseline file cannot be written.
+    pub fn save(&self) -> io::Result<()> {
+        let mut entries = self
+            .entries
+            .iter()
+            .map(|(key, count)| BaselineEntry { key: key.clone(), count: *count })
+            .collect::<Vec<_>>();
+        entries.sort_by(|a, b| a.key.cmp(&b.key));
+        let json =
+            serde_json::to_string_pretty(&BaselineFile { entries }).map_err(io::Error::from)?;
+        if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, json + "\n")
+    }
+}
+
+impl BaselineKey {
+    fn new(path: &Path, diagnostic: &Error) -> Self {
+        let message = diagnostic.to_string();
+        // Our messages usually comes with `eslint(rule): message`
+        let (rule, message) = match message.split_once(':') {
+            Some((id, message)) if id.ends_with(')') && !id.contains(' ') => (id, message.trim()),
+            _ => ("", message.as_str()),
+        };
+        let hash = fingerprint(&[rule, message, &source_snippet(diagnostic)]);
+        Self { file: normalize(path), rule: rule.to_string(), fingerprint: format!("{hash:016x}") }
+    }
+}
+
+/// Paths are written with `/` separators, so that baseline files can be shared across platforms.
+fn normalize(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, sync::Arc};
+
+    use miette::{LabeledSpan, NamedSource};
+
+    use super::{Baseline, BaselineMode, FixedEntry};
+    use crate::{Error, OxcDiagnostic};
+
+    fn debugger(source_text: &str, offset: usize) -> Error {
+        OxcDiagnostic::warn("eslint(no-debugger): `debugger` statement is not allowed")
+            .with_label(LabeledSpan::new(None, offset, 8))
+            .with_source_code(Arc::new(NamedSource::new("test.js", source_text.to_string())))
+    }
+
+    #[test]
+    fn test_baseline() {
+        let dir = tempfile::tempdir().unwrap();
+        let location = dir.path().join("baseline.json");
+        let path = Path::new("src/test.js");
+
+        let mut baseline = Baseline::new(location.clone(), BaselineMode::Write).unwrap();
+        assert!(baseline.accept(path, &debugger("debugger;\ndebugger;\n", 0)));
+        assert!(baseline.accept(path, &debugger("debugger;\ndebugger;\n", 10)));
+        baseline.save().unwrap();
+
+        // Existing diagnostics are accepted after moving, new ones are reported.
+        let mut baseline = Baseline::new(location.clone(), BaselineMode::Check).unwrap();
+        let source_text = "let a;\ndebugger;\ndebugger;\ndebugger;\n";
+        assert!(baseline.accept(path, &debugger(source_text, 7)));
+        assert!(baseline.accept(path, &debugger(source_text, 17)));
+        assert!(!baseline.accept(path, &debugger(source_text, 27)));
+        assert!(!baseline.accept(Path::new("src/other.js"), &debugger(source_text, 7)));
+        assert!(baseline.fixed_entries(&[path]).is_empty());
+
+        let mut baseline = Baseline::new(location, BaselineMode::Check).unwrap();
+        assert!(baseline.accept(path, &debugger("debugger;\n", 0)));
+        assert!(baseline.fixed_entries(&[Path::new("src/other.js")]).is_empty());
+        assert_eq!(
+            baseline.fixed_entries(&[path]),
+            vec![FixedEntry {
+                file: "src/test.js".to_string(),
+                rule: "eslint(no-debugger)".to_string(),
+                count: 1
+            }]
+        );
+
+        assert!(Baseline::new(dir.path().join("missing.json"), BaselineMode::Check).is_err());
+    }
+}
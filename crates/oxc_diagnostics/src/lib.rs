@@ -1,6 +1,7 @@
 //! Diagnostics Wrapper
 //! Exports `miette`
 
+mod baseline;
 mod graphic_reporter;
 mod graphical_theme;
 mod reporter;
@@ -12,8 +13,10 @@ use std::{
 };
 
 pub use crate::{
+    baseline::{Baseline, BaselineMode, FixedEntry},
     graphic_reporter::GraphicalReportHandler,
     graphical_theme::GraphicalTheme,
+    reporter::RuleMetadata,
     service::{DiagnosticSender, DiagnosticService, DiagnosticTuple},
 };
 
@@ -46,6 +49,22 @@ pub struct OxcDiagnosticInner {
     pub labels: Option<Vec<LabeledSpan>>,
     pub help: Option<String>,
     pub severity: Severity,
+    /// Edits of a fix of the problem, applied all together.
+    pub fix: Option<Vec<Replacement>>,
+}
+
+/// An edit replacing `len` bytes at `offset` of the source code with `content`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replacement {
+    pub offset: usize,
+    pub len: usize,
+    pub content: String,
+}
+
+impl Replacement {
+    pub fn new<T: Into<String>>(offset: usize, len: usize, content: T) -> Self {
+        Self { offset, len, content: content.into() }
+    }
 }
 
 impl fmt::Display for OxcDiagnostic {
@@ -83,6 +102,7 @@ impl OxcDiagnostic {
                 labels: None,
                 help: None,
                 severity: Severity::Error,
+                fix: None,
             }),
         }
     }
@@ -95,6 +115,7 @@ impl OxcDiagnostic {
                 labels: None,
                 help: None,
                 severity: Severity::Warning,
+                fix: None,
             }),
         }
     }
@@ -111,6 +132,12 @@ impl OxcDiagnostic {
         self
     }
 
+    #[must_use]
+    pub fn with_fix<T: IntoIterator<Item = Replacement>>(mut self, fix: T) -> Self {
+        self.inner.fix = Some(fix.into_iter().collect());
+        self
+    }
+
     #[must_use]
     pub fn with_label<T: Into<LabeledSpan>>(mut self, label: T) -> Self {
         self.inner.labels = Some(vec![label.into()]);
@@ -141,6 +168,49 @@ impl OxcDiagnostic {
 
     #[must_use]
     pub fn with_source_code<T: SourceCode + Send + Sync + 'static>(self, code: T) -> Error {
-        Error::from(self).with_source_code(code)
+        Error::new(WithSourceCode { diagnostic: self, source_code: Box::new(code) })
+    }
+}
+
+/// An [`OxcDiagnostic`] with the source code its labels point to.
+///
+/// Unlike [`Error::with_source_code`], this keeps the diagnostic reachable with
+/// [`Error::downcast_ref`], which reporters use to read its fix.
+pub(crate) struct WithSourceCode {
+    pub(crate) diagnostic: OxcDiagnostic,
+    source_code: Box<dyn SourceCode>,
+}
+
+impl fmt::Debug for WithSourceCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WithSourceCode")
+            .field("diagnostic", &self.diagnostic)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Display for WithSourceCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.diagnostic, f)
+    }
+}
+
+impl std::error::Error for WithSourceCode {}
+
+impl Diagnostic for WithSourceCode {
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.help()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.diagnostic.severity()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.diagnostic.labels()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&*self.source_code)
     }
 }
@@ -52,7 +52,7 @@ fn format_checkstyle(diagnostics: &[Error]) {
 }
 
 /// <https://github.com/tafia/quick-xml/blob/6e34a730853fe295d68dc28460153f08a5a12955/src/escapei.rs#L84-L86>
-fn xml_escape(raw: &str) -> Cow<str> {
+pub(super) fn xml_escape(raw: &str) -> Cow<str> {
     xml_escape_impl(raw, |ch| matches!(ch, b'<' | b'>' | b'&' | b'\'' | b'\"'))
 }
 
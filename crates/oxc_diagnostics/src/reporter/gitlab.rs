@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use super::{fingerprint, source_snippet, DiagnosticReporter, Info};
+use crate::{Error, Severity};
+
+/// GitLab Code Quality report.
+/// <https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool>
+#[derive(Default)]
+pub struct GitlabReporter {
+    diagnostics: Vec<Error>,
+}
+
+impl DiagnosticReporter for GitlabReporter {
+    fn finish(&mut self) {
+        format_gitlab(&self.diagnostics);
+    }
+
+    fn render_diagnostics(&mut self, _s: &[u8]) {}
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        self.diagnostics.push(error);
+        None
+    }
+}
+
+#[allow(clippy::print_stdout)]
+fn format_gitlab(diagnostics: &[Error]) {
+    println!("{}", code_quality_report(diagnostics));
+}
+
+fn code_quality_report(diagnostics: &[Error]) -> Value {
+    // Number of previous issues with the same fingerprint, to tell identical issues apart.
+    let mut occurrences = HashMap::new();
+    let issues = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let Info { line, filename, message, severity, rule_id, .. } = Info::new(diagnostic);
+            let check_name = rule_id.unwrap_or_else(|| "oxlint".to_string());
+            let snippet = source_snippet(diagnostic);
+            let hash = fingerprint(&[&filename, &check_name, &message, &snippet]);
+            let occurrence = occurrences.entry(hash).and_modify(|n| *n += 1).or_insert(0_usize);
+            let hash = if *occurrence == 0 {
+                hash
+            } else {
+                fingerprint(&[&hash.to_string(), &occurrence.to_string()])
+            };
+            let severity = match severity {
+                Severity::Error => "major",
+                Severity::Warning => "minor",
+                Severity::Advice => "info",
+            };
+            json!({
+                "description": message,
+                "check_name": check_name,
+                "fingerprint": format!("{hash:016x}"),
+                "severity": severity,
+                "location": { "path": filename, "lines": { "begin": line } },
+            })
+        })
+        .collect::<Vec<_>>();
+    Value::Array(issues)
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use miette::{LabeledSpan, NamedSource};
+    use serde_json::json;
+
+    use super::code_quality_report;
+    use crate::OxcDiagnostic;
+
+    #[test]
+    fn test_code_quality_report() {
+        let debugger = |source_text: &str, offset: usize| {
+            let source = NamedSource::new("test.js", source_text.to_string());
+            OxcDiagnostic::warn("eslint(no-debugger): `debugger` statement is not allowed")
+                .with_label(LabeledSpan::new(None, offset, 8))
+                .with_source_code(Arc::new(source))
+        };
+
+        let report = code_quality_report(&[debugger("debugger;\ndebugger;\n", 0)]);
+        let issue = &report[0];
+        assert_eq!(issue["description"], "`debugger` statement is not allowed");
+        assert_eq!(issue["check_name"], "eslint(no-debugger)");
+        assert_eq!(issue["severity"], "minor");
+        assert_eq!(issue["location"], json!({ "path": "test.js", "lines": { "begin": 1 } }));
+
+        // Fingerprints are kept when the issue moves, and unique for identical issues.
+        let report = code_quality_report(&[
+            debugger("\n\ndebugger;\ndebugger;\n", 2),
+            debugger("\n\ndebugger;\ndebugger;\n", 12),
+        ]);
+        assert_eq!(report[0]["fingerprint"], issue["fingerprint"]);
+        assert_eq!(report[0]["location"]["lines"]["begin"], 3);
+        assert_ne!(report[1]["fingerprint"], issue["fingerprint"]);
+    }
+}
@@ -0,0 +1,97 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use super::{checkstyle::xml_escape, DiagnosticReporter, Info};
+use crate::{Error, Severity};
+
+/// JUnit XML, with a test suite per file and a failed test case per diagnostic.
+/// <https://github.com/testmoapp/junitxml>
+#[derive(Default)]
+pub struct JunitReporter {
+    diagnostics: Vec<Error>,
+}
+
+impl DiagnosticReporter for JunitReporter {
+    fn finish(&mut self) {
+        format_junit(&self.diagnostics);
+    }
+
+    fn render_diagnostics(&mut self, _s: &[u8]) {}
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        self.diagnostics.push(error);
+        None
+    }
+}
+
+#[allow(clippy::print_stdout)]
+fn format_junit(diagnostics: &[Error]) {
+    println!("{}", junit_xml(diagnostics));
+}
+
+fn junit_xml(diagnostics: &[Error]) -> String {
+    let mut grouped: BTreeMap<String, Vec<Info>> = BTreeMap::new();
+    for info in diagnostics.iter().map(Info::new) {
+        grouped.entry(info.filename.clone()).or_default().push(info);
+    }
+    // Writing to a `String` can't fail.
+    let test_suites = grouped.iter().fold(String::new(), |mut test_suites, (filename, infos)| {
+        let filename = xml_escape(filename);
+        let test_cases = infos.iter().fold(String::new(), |mut test_cases, info| {
+            let Info { line, column, message, severity, rule_id, .. } = info;
+            let severity = match severity {
+                Severity::Error => "error",
+                _ => "warning",
+            };
+            let name = xml_escape(rule_id.as_deref().unwrap_or("oxlint"));
+            let message = xml_escape(message);
+            let _ = write!(
+                test_cases,
+                r#"<testcase classname="{filename}" name="{name}"><failure message="{message}" type="{severity}">line {line}, column {column}: {message}</failure></testcase>"#
+            );
+            test_cases
+        });
+        let tests = infos.len();
+        let _ = write!(
+            test_suites,
+            r#"<testsuite name="{filename}" tests="{tests}" failures="{tests}" errors="0">{test_cases}</testsuite>"#
+        );
+        test_suites
+    });
+    let tests = diagnostics.len();
+    format!(
+        r#"<?xml version="1.0" encoding="utf-8"?><testsuites name="oxlint" tests="{tests}" failures="{tests}" errors="0">{test_suites}</testsuites>"#
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use miette::{LabeledSpan, NamedSource};
+
+    use super::junit_xml;
+    use crate::OxcDiagnostic;
+
+    #[test]
+    fn test_junit_xml() {
+        let source = Arc::new(NamedSource::new("test.js", "debugger;\na < b;\n".to_string()));
+        let diagnostics = vec![
+            OxcDiagnostic::warn("eslint(no-debugger): `debugger` statement is not allowed")
+                .with_label(LabeledSpan::new(None, 0, 8))
+                .with_source_code(Arc::clone(&source)),
+            OxcDiagnostic::error("oxc(misrefactored-assign-op): Unexpected `<`")
+                .with_label(LabeledSpan::new(None, 12, 1))
+                .with_source_code(source),
+        ];
+        assert_eq!(
+            junit_xml(&diagnostics),
+            concat!(
+                r#"<?xml version="1.0" encoding="utf-8"?><testsuites name="oxlint" tests="2" failures="2" errors="0">"#,
+                r#"<testsuite name="test.js" tests="2" failures="2" errors="0">"#,
+                r#"<testcase classname="test.js" name="eslint(no-debugger)"><failure message="`debugger` statement is not allowed" type="warning">line 1, column 1: `debugger` statement is not allowed</failure></testcase>"#,
+                r#"<testcase classname="test.js" name="oxc(misrefactored-assign-op)"><failure message="Unexpected `&lt;`" type="error">line 2, column 3: Unexpected `&lt;`</failure></testcase>"#,
+                "</testsuite></testsuites>",
+            )
+        );
+    }
+}
@@ -1,14 +1,23 @@
 mod checkstyle;
 mod github;
+mod gitlab;
 mod graphical;
 mod json;
+mod junit;
+mod sarif;
 mod unix;
 
 use std::io::{BufWriter, Stdout};
 
 pub use self::{
-    checkstyle::CheckstyleReporter, github::GithubReporter, graphical::GraphicalReporter,
-    json::JsonReporter, unix::UnixReporter,
+    checkstyle::CheckstyleReporter,
+    github::GithubReporter,
+    gitlab::GitlabReporter,
+    graphical::GraphicalReporter,
+    json::JsonReporter,
+    junit::JunitReporter,
+    sarif::{RuleMetadata, SarifReporter},
+    unix::UnixReporter,
 };
 use crate::{Error, Severity};
 
@@ -66,3 +75,32 @@ impl Info {
         Self { line, column, filename, message, severity, rule_id }
     }
 }
+
+/// The source code of the first label of `diagnostic`.
+///
+/// Fingerprints identify an issue by it instead of its line, which changes whenever
+/// code is added above it.
+pub(crate) fn source_snippet(diagnostic: &Error) -> String {
+    diagnostic
+        .source_code()
+        .zip(diagnostic.labels().and_then(|mut labels| labels.next()))
+        .and_then(|(source, label)| source.read_span(label.inner(), 0, 0).ok())
+        .map(|contents| String::from_utf8_lossy(contents.data()).into_owned())
+        .unwrap_or_default()
+}
+
+/// 64-bit FNV-1a hash of `parts`, which is stable across platforms and releases,
+/// unlike the hashers of the standard library.
+pub(crate) fn fingerprint(parts: &[&str]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+    let mut hash = OFFSET_BASIS;
+    for part in parts {
+        // Separate the parts so that moving bytes between them changes the hash.
+        for byte in part.bytes().chain([0]) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(PRIME);
+        }
+    }
+    hash
+}
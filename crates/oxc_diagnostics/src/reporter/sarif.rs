@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+
+use miette::{SourceCode, SourceSpan};
+use serde_json::{json, Value};
+
+use super::DiagnosticReporter;
+use crate::{Error, Replacement, Severity, WithSourceCode};
+
+/// Metadata of a rule, described in the SARIF output for the rules with results.
+#[derive(Debug, Clone)]
+pub struct RuleMetadata {
+    /// Identifier prefixing the messages of the rule, e.g. `eslint(no-debugger)`.
+    pub id: String,
+    pub category: String,
+    /// Link to the documentation of the rule.
+    pub help_uri: String,
+    /// Documentation of the rule, in markdown.
+    pub documentation: Option<String>,
+}
+
+/// Static Analysis Results Interchange Format, read by code scanning tools.
+/// <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>
+#[derive(Default)]
+pub struct SarifReporter {
+    rules: Vec<RuleMetadata>,
+    diagnostics: Vec<Error>,
+}
+
+impl SarifReporter {
+    pub fn new(rules: Vec<RuleMetadata>) -> Self {
+        Self { rules, diagnostics: vec![] }
+    }
+}
+
+impl DiagnosticReporter for SarifReporter {
+    fn finish(&mut self) {
+        format_sarif(&self.rules, &self.diagnostics);
+    }
+
+    fn render_diagnostics(&mut self, _s: &[u8]) {}
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        self.diagnostics.push(error);
+        None
+    }
+}
+
+#[allow(clippy::print_stdout)]
+fn format_sarif(rules: &[RuleMetadata], diagnostics: &[Error]) {
+    println!("{}", sarif_log(rules, diagnostics));
+}
+
+fn sarif_log(rules: &[RuleMetadata], diagnostics: &[Error]) -> Value {
+    // Rules are described once, in the order of their first result.
+    let mut reported_rules = vec![];
+    let mut rule_indices = HashMap::new();
+    let results = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let message = diagnostic.to_string();
+            // Our messages usually comes with `eslint(rule): message`
+            let (rule_id, text) = match message.split_once(':') {
+                Some((id, text)) if id.ends_with(')') && !id.contains(' ') => {
+                    (Some(id), text.trim())
+                }
+                _ => (None, message.as_str()),
+            };
+            let mut result = json!({
+                "level": level(diagnostic.severity()),
+                "message": { "text": text },
+            });
+            if let Some(rule_id) = rule_id {
+                let index = *rule_indices.entry(rule_id.to_string()).or_insert_with(|| {
+                    reported_rules.push(rule_descriptor(rules, rule_id));
+                    reported_rules.len() - 1
+                });
+                result["ruleId"] = json!(rule_id);
+                result["ruleIndex"] = json!(index);
+            }
+            if let Some(source) = diagnostic.source_code() {
+                let label = diagnostic.labels().and_then(|mut labels| labels.next());
+                if let Some((uri, region)) = label.and_then(|label| region(source, *label.inner()))
+                {
+                    result["locations"] = json!([{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": uri },
+                            "region": region,
+                        },
+                    }]);
+                }
+                let fix = diagnostic
+                    .downcast_ref::<WithSourceCode>()
+                    .and_then(|diagnostic| diagnostic.diagnostic.fix.as_deref());
+                if let Some(fix) = fix.and_then(|fix| artifact_change(source, fix)) {
+                    result["fixes"] = json!([{ "artifactChanges": [fix] }]);
+                }
+            }
+            result
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "oxlint",
+                    "informationUri": "https://oxc.rs",
+                    "rules": reported_rules,
+                },
+            },
+            "results": results,
+        }],
+    })
+}
+
+fn level(severity: Option<Severity>) -> &'static str {
+    match severity {
+        Some(Severity::Warning) => "warning",
+        Some(Severity::Advice) => "note",
+        Some(Severity::Error) | None => "error",
+    }
+}
+
+fn rule_descriptor(rules: &[RuleMetadata], id: &str) -> Value {
+    let mut descriptor = json!({ "id": id });
+    if let Some(rule) = rules.iter().find(|rule| rule.id == id) {
+        descriptor["helpUri"] = json!(rule.help_uri);
+        descriptor["properties"] = json!({ "category": rule.category, "tags": [rule.category] });
+        if let Some(documentation) = &rule.documentation {
+            descriptor["help"] = json!({ "text": documentation, "markdown": documentation });
+        }
+    }
+    descriptor
+}
+
+/// The file name of `span` and its 1-based region.
+fn region(source: &dyn SourceCode, span: SourceSpan) -> Option<(String, Value)> {
+    let start = source.read_span(&span, 0, 0).ok()?;
+    let end = source.read_span(&(span.offset() + span.len(), 0).into(), 0, 0).ok()?;
+    let uri = start.name().unwrap_or_default().replace('\\', "/");
+    let region = json!({
+        "startLine": start.line() + 1,
+        "startColumn": start.column() + 1,
+        "endLine": end.line() + 1,
+        "endColumn": end.column() + 1,
+    });
+    Some((uri, region))
+}
+
+fn artifact_change(source: &dyn SourceCode, fix: &[Replacement]) -> Option<Value> {
+    let mut uri = String::new();
+    let replacements = fix
+        .iter()
+        .map(|replacement| {
+            let (name, region) = region(source, (replacement.offset, replacement.len).into())?;
+            uri = name;
+            Some(json!({
+                "deletedRegion": region,
+                "insertedContent": { "text": replacement.content },
+            }))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(json!({ "artifactLocation": { "uri": uri }, "replacements": replacements }))
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use miette::{LabeledSpan, NamedSource};
+    use serde_json::json;
+
+    use super::{sarif_log, RuleMetadata};
+    use crate::{OxcDiagnostic, Replacement};
+
+    #[test]
+    fn test_sarif_log() {
+        let source = Arc::new(NamedSource::new("src/test.js", "let a;\ndebugger;\n".to_string()));
+        let diagnostics = vec![
+            OxcDiagnostic::warn("eslint(no-debugger): `debugger` statement is not allowed")
+                .with_label(LabeledSpan::new(None, 7, 9))
+                .with_fix([Replacement::new(7, 9, "")])
+                .with_source_code(Arc::clone(&source)),
+            OxcDiagnostic::error("Unexpected token")
+                .with_label(LabeledSpan::new(None, 4, 1))
+                .with_source_code(source),
+        ];
+        let rules = vec![RuleMetadata {
+            id: "eslint(no-debugger)".to_string(),
+            category: "correctness".to_string(),
+            help_uri: "https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-debugger.html"
+                .to_string(),
+            documentation: None,
+        }];
+        let log = sarif_log(&rules, &diagnostics);
+        let run = &log["runs"][0];
+
+        assert_eq!(
+            run["tool"]["driver"]["rules"],
+            json!([{
+                "id": "eslint(no-debugger)",
+                "helpUri": "https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-debugger.html",
+                "properties": { "category": "correctness", "tags": ["correctness"] },
+            }])
+        );
+        let region = json!({ "startLine": 2, "startColumn": 1, "endLine": 2, "endColumn": 10 });
+        assert_eq!(
+            run["results"][0],
+            json!({
+                "level": "warning",
+                "message": { "text": "`debugger` statement is not allowed" },
+                "ruleId": "eslint(no-debugger)",
+                "ruleIndex": 0,
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": "src/test.js" },
+                        "region": region,
+                    },
+                }],
+                "fixes": [{
+                    "artifactChanges": [{
+                        "artifactLocation": { "uri": "src/test.js" },
+                        "replacements": [{
+                            "deletedRegion": region,
+                            "insertedContent": { "text": "" },
+                        }],
+                    }],
+                }],
+            })
+        );
+        assert_eq!(run["results"][1]["level"], "error");
+        assert_eq!(run["results"][1]["message"]["text"], "Unexpected token");
+        assert!(run["results"][1].get("ruleId").is_none());
+    }
+}
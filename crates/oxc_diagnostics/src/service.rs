@@ -5,11 +5,12 @@ use std::{
 };
 
 use crate::{
+    baseline::Baseline,
     reporter::{
-        CheckstyleReporter, DiagnosticReporter, GithubReporter, GraphicalReporter, JsonReporter,
-        UnixReporter,
+        CheckstyleReporter, DiagnosticReporter, GithubReporter, GitlabReporter, GraphicalReporter,
+        JsonReporter, JunitReporter, SarifReporter, UnixReporter,
     },
-    Error, NamedSource, OxcDiagnostic, Severity,
+    Error, NamedSource, OxcDiagnostic, RuleMetadata, Severity,
 };
 
 pub type DiagnosticTuple = (PathBuf, Vec<Error>);
@@ -29,6 +30,9 @@ pub struct DiagnosticService {
     /// which can be used to force exit with an error status if there are too many warning-level rule violations in your project
     max_warnings: Option<usize>,
 
+    /// Diagnostics accepted by a baseline are not reported
+    baseline: Option<Baseline>,
+
     /// Total number of warnings received
     warnings_count: Cell<usize>,
 
@@ -47,6 +51,7 @@ impl Default for DiagnosticService {
             quiet: false,
             silent: false,
             max_warnings: None,
+            baseline: None,
             warnings_count: Cell::new(0),
             errors_count: Cell::new(0),
             sender,
@@ -72,6 +77,19 @@ impl DiagnosticService {
         self.reporter = Box::<GithubReporter>::default();
     }
 
+    pub fn set_junit_reporter(&mut self) {
+        self.reporter = Box::<JunitReporter>::default();
+    }
+
+    pub fn set_gitlab_reporter(&mut self) {
+        self.reporter = Box::<GitlabReporter>::default();
+    }
+
+    /// Report in the SARIF format, describing the rules with results from `rules`.
+    pub fn set_sarif_reporter(&mut self, rules: Vec<RuleMetadata>) {
+        self.reporter = Box::new(SarifReporter::new(rules));
+    }
+
     #[must_use]
     pub fn with_quiet(mut self, yes: bool) -> Self {
         self.quiet = yes;
@@ -90,6 +108,16 @@ impl DiagnosticService {
         self
     }
 
+    #[must_use]
+    pub fn with_baseline(mut self, baseline: Option<Baseline>) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    pub fn baseline(&self) -> Option<&Baseline> {
+        self.baseline.as_ref()
+    }
+
     pub fn sender(&self) -> &DiagnosticSender {
         &self.sender
     }
@@ -130,6 +158,13 @@ impl DiagnosticService {
                 let is_warning = severity == Some(Severity::Warning);
                 let is_error = severity == Some(Severity::Error) || severity.is_none();
                 if is_warning || is_error {
+                    if self
+                        .baseline
+                        .as_mut()
+                        .is_some_and(|baseline| baseline.accept(&path, &diagnostic))
+                    {
+                        continue;
+                    }
                     if is_warning {
                         let warnings_count = self.warnings_count() + 1;
                         self.warnings_count.set(warnings_count);
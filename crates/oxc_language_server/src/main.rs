@@ -7,7 +7,7 @@ use futures::future::join_all;
 use globset::Glob;
 use ignore::gitignore::Gitignore;
 use log::{debug, error, info};
-use oxc_linter::{LintOptions, Linter};
+use oxc_linter::{FixKind, LintOptions, Linter};
 use serde::{Deserialize, Serialize};
 use tokio::sync::{Mutex, OnceCell, RwLock, SetError};
 use tower_lsp::{
@@ -18,7 +18,7 @@ use tower_lsp::{
         DidChangeConfigurationParams, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
         DidOpenTextDocumentParams, DidSaveTextDocumentParams, InitializeParams, InitializeResult,
         InitializedParams, OneOf, ServerCapabilities, ServerInfo, TextDocumentSyncCapability,
-        TextDocumentSyncKind, TextEdit, Url, WorkDoneProgressOptions, WorkspaceEdit,
+        TextDocumentSyncKind, Url, WorkDoneProgressOptions, WorkspaceEdit,
         WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
     },
     Client, LanguageServer, LspService, Server,
@@ -235,42 +235,54 @@ impl LanguageServer for Backend {
     async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
         let uri = params.text_document.uri;
 
-        if let Some(value) = self.diagnostics_report_map.get(&uri.to_string()) {
-            if let Some(report) = value
-                .iter()
-                .find(|r| r.diagnostic.range == params.range && r.fixed_content.is_some())
-            {
-                let title =
-                    report.diagnostic.message.split(':').next().map_or_else(
+        let Some(value) = self.diagnostics_report_map.get(&uri.to_string()) else {
+            return Ok(None);
+        };
+
+        // Suggestions and dangerous fixes are offered as separate, non-preferred code actions.
+        let code_actions = value
+            .iter()
+            .filter(|r| r.diagnostic.range == params.range)
+            .filter_map(|report| {
+                let fixed_content = report.fixed_content.clone()?;
+                let rule = report.diagnostic.message.split(':').next();
+                let title = if fixed_content.kind == FixKind::Suggestion {
+                    rule.map_or_else(
+                        || "Apply suggestion".into(),
+                        |s| format!("Apply suggestion for this {s} problem"),
+                    )
+                } else if fixed_content.kind == FixKind::DangerousFix {
+                    rule.map_or_else(
+                        || "Fix this problem (may change behavior)".into(),
+                        |s| format!("Fix this {s} problem (may change behavior)"),
+                    )
+                } else {
+                    rule.map_or_else(
                         || "Fix this problem".into(),
                         |s| format!("Fix this {s} problem"),
-                    );
-
-                let fixed_content = report.fixed_content.clone().unwrap();
+                    )
+                };
 
-                return Ok(Some(vec![CodeActionOrCommand::CodeAction(CodeAction {
+                Some(CodeActionOrCommand::CodeAction(CodeAction {
                     title,
                     kind: Some(CodeActionKind::QUICKFIX),
-                    is_preferred: Some(true),
+                    is_preferred: Some(fixed_content.kind == FixKind::SafeFix),
                     edit: Some(WorkspaceEdit {
-                        changes: Some(HashMap::from([(
-                            uri,
-                            vec![TextEdit {
-                                range: fixed_content.range,
-                                new_text: fixed_content.code,
-                            }],
-                        )])),
+                        changes: Some(HashMap::from([(uri.clone(), fixed_content.edits)])),
                         ..WorkspaceEdit::default()
                     }),
                     disabled: None,
                     data: None,
                     diagnostics: None,
                     command: None,
-                })]));
-            }
-        }
+                }))
+            })
+            .collect::<Vec<_>>();
 
-        Ok(None)
+        if code_actions.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(code_actions))
     }
 }
 
@@ -345,7 +357,9 @@ impl Backend {
             let mut linter = self.server_linter.write().await;
             *linter = ServerLinter::new_with_linter(
                 Linter::from_options(
-                    LintOptions::default().with_fix(true).with_config_path(Some(config_path)),
+                    LintOptions::default()
+                        .with_fix(FixKind::all())
+                        .with_config_path(Some(config_path)),
                 )
                 .expect("should have initialized linter with new options"),
             );
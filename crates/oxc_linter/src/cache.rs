@@ -0,0 +1,279 @@
+use std::{
+    fs,
+    hash::{Hash, Hasher},
+    io,
+    path::{Path, PathBuf},
+};
+
+use dashmap::DashMap;
+use oxc_diagnostics::{LabeledSpan, OxcDiagnostic, Replacement, Severity};
+use rustc_hash::{FxHashMap, FxHasher};
+use serde::{Deserialize, Serialize};
+
+/// Lint results of previous runs, keyed by file path.
+///
+/// A file's results are reused when its content, the configuration linting it and,
+/// with the import plugin, the content of the modules it depends on are unchanged.
+/// The whole cache is discarded when it was written by another version.
+pub struct LintCache {
+    location: PathBuf,
+    version: String,
+    /// Entries loaded from `location`.
+    previous: FxHashMap<PathBuf, CacheEntry>,
+    /// Entries of this run, written to `location` by [`LintCache::save`].
+    current: DashMap<PathBuf, CacheEntry>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheFile {
+    version: String,
+    files: FxHashMap<PathBuf, CacheEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CacheEntry {
+    /// Hash of the file content and of the configuration linting it.
+    hash: u64,
+    /// Modules the file depends on, with the hash of their content.
+    dependencies: Vec<(PathBuf, u64)>,
+    /// Diagnostics of each javascript source of the file.
+    pub(crate) sources: Vec<CachedSource>,
+}
+
+/// Diagnostics of a javascript source, located at `start..end` in the file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CachedSource {
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+    diagnostics: Vec<CachedDiagnostic>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedDiagnostic {
+    message: String,
+    help: Option<String>,
+    severity: CachedSeverity,
+    labels: Vec<CachedLabel>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fix: Option<Vec<CachedReplacement>>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum CachedSeverity {
+    Error,
+    Warning,
+    Advice,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedLabel {
+    label: Option<String>,
+    offset: usize,
+    len: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedReplacement {
+    offset: usize,
+    len: usize,
+    content: String,
+}
+
+impl LintCache {
+    /// Load the cache at `location`.
+    /// A missing, unreadable or outdated cache file starts an empty cache.
+    pub fn new(location: PathBuf, version: &str) -> Self {
+        let previous = fs::read_to_string(&location)
+            .ok()
+            .and_then(|json| serde_json::from_str::<CacheFile>(&json).ok())
+            .filter(|cache_file| cache_file.version == version)
+            .map(|cache_file| cache_file.files)
+            .unwrap_or_default();
+        Self { location, version: version.to_string(), previous, current: DashMap::default() }
+    }
+
+    pub fn location(&self) -> &Path {
+        &self.location
+    }
+
+    /// Write the entries of this run, and the previous ones of files still existing, to the cache file.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the cache file cannot be written.
+    pub fn save(&self) -> io::Result<()> {
+        let mut files = self
+            .previous
+            .iter()
+            .filter(|(path, _)| path.is_file())
+            .map(|(path, entry)| (path.clone(), entry.clone()))
+            .collect::<FxHashMap<_, _>>();
+        files.extend(self.current.iter().map(|e| (e.key().clone(), e.value().clone())));
+        let cache_file = CacheFile { version: self.version.clone(), files };
+        let json = serde_json::to_string(&cache_file).map_err(io::Error::from)?;
+        if let Some(dir) = self.location.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.location, json)
+    }
+
+    /// The cached entry of `path` if it is still valid for `hash`,
+    /// with the current content hash of each dependency given by `dependency_hash`.
+    pub(crate) fn get<F>(&self, path: &Path, hash: u64, dependency_hash: F) -> Option<&CacheEntry>
+    where
+        F: Fn(&Path) -> Option<u64>,
+    {
+        let entry = self.previous.get(path)?;
+        let is_valid = entry.hash == hash
+            && entry
+                .dependencies
+                .iter()
+                .all(|(dependency, hash)| dependency_hash(dependency) == Some(*hash));
+        if !is_valid {
+            return None;
+        }
+        self.current.insert(path.to_path_buf(), entry.clone());
+        Some(entry)
+    }
+
+    pub(crate) fn insert(
+        &self,
+        path: &Path,
+        hash: u64,
+        dependencies: Vec<(PathBuf, u64)>,
+        sources: Vec<CachedSource>,
+    ) {
+        self.current.insert(path.to_path_buf(), CacheEntry { hash, dependencies, sources });
+    }
+}
+
+/// Hash of the content of a file.
+pub(crate) fn content_hash(source_text: &str) -> u64 {
+    let mut hasher = FxHasher::default();
+    source_text.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl CachedSource {
+    pub(crate) fn new(start: usize, end: usize, diagnostics: &[OxcDiagnostic]) -> Self {
+        let diagnostics = diagnostics
+            .iter()
+            .map(|diagnostic| CachedDiagnostic {
+                message: diagnostic.message.clone(),
+                help: diagnostic.help.clone(),
+                severity: match diagnostic.severity {
+                    Severity::Error => CachedSeverity::Error,
+                    Severity::Warning => CachedSeverity::Warning,
+                    Severity::Advice => CachedSeverity::Advice,
+                },
+                labels: diagnostic
+                    .labels
+                    .iter()
+                    .flatten()
+                    .map(|label| CachedLabel {
+                        label: label.label().map(ToString::to_string),
+                        offset: label.offset(),
+                        len: label.len(),
+                    })
+                    .collect(),
+                fix: diagnostic.fix.as_ref().map(|fix| {
+                    fix.iter()
+                        .map(|replacement| CachedReplacement {
+                            offset: replacement.offset,
+                            len: replacement.len,
+                            content: replacement.content.clone(),
+                        })
+                        .collect()
+                }),
+            })
+            .collect();
+        Self { start, end, diagnostics }
+    }
+
+    pub(crate) fn diagnostics(&self) -> Vec<OxcDiagnostic> {
+        self.diagnostics
+            .iter()
+            .map(|cached| {
+                let severity = match cached.severity {
+                    CachedSeverity::Error => Severity::Error,
+                    CachedSeverity::Warning => Severity::Warning,
+                    CachedSeverity::Advice => Severity::Advice,
+                };
+                let mut diagnostic =
+                    OxcDiagnostic::error(cached.message.clone()).with_severity(severity);
+                if let Some(help) = &cached.help {
+                    diagnostic = diagnostic.with_help(help.clone());
+                }
+                if !cached.labels.is_empty() {
+                    diagnostic = diagnostic.with_labels(cached.labels.iter().map(|label| {
+                        LabeledSpan::new(label.label.clone(), label.offset, label.len)
+                    }));
+                }
+                if let Some(fix) = &cached.fix {
+                    diagnostic = diagnostic.with_fix(fix.iter().map(|replacement| {
+                        Replacement::new(replacement.offset, replacement.len, &replacement.content)
+                    }));
+                }
+                diagnostic
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_diagnostics::{OxcDiagnostic, Severity};
+    use oxc_span::Span;
+
+    use super::{content_hash, CachedSource, LintCache};
+
+    #[test]
+    fn test_cache_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let location = dir.path().join("cache/.oxlintcache");
+        let path = Path::new("fixtures/cache/foo.js");
+        let hash = content_hash("debugger;");
+
+        let cache = LintCache::new(location.clone(), "1.0.0");
+        assert!(cache.get(path, hash, |_| None).is_none());
+        let diagnostic = OxcDiagnostic::warn("`debugger` statement is not allowed")
+            .with_help("Delete this code.")
+            .with_label(Span::new(0, 8));
+        cache.insert(path, hash, vec![], vec![CachedSource::new(0, 9, &[diagnostic])]);
+        cache.save().unwrap();
+
+        let cache = LintCache::new(location.clone(), "1.0.0");
+        assert!(cache.get(path, content_hash("debugger"), |_| None).is_none());
+        let entry = cache.get(path, hash, |_| None).unwrap();
+        let diagnostics = entry.sources[0].diagnostics();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "`debugger` statement is not allowed");
+        assert_eq!(diagnostics[0].help.as_deref(), Some("Delete this code."));
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        let label = &diagnostics[0].labels.as_ref().unwrap()[0];
+        assert_eq!((label.offset(), label.len()), (0, 8));
+
+        let cache = LintCache::new(location, "2.0.0");
+        assert!(cache.get(path, hash, |_| None).is_none());
+    }
+
+    #[test]
+    fn test_cache_dependencies() {
+        let dir = tempfile::tempdir().unwrap();
+        let location = dir.path().join(".oxlintcache");
+        let path = Path::new("foo.js");
+        let dependency = Path::new("bar.js");
+
+        let cache = LintCache::new(location.clone(), "1.0.0");
+        cache.insert(path, 1, vec![(dependency.to_path_buf(), 2)], vec![]);
+        cache.save().unwrap();
+
+        let cache = LintCache::new(location, "1.0.0");
+        assert!(cache.get(path, 1, |_| Some(3)).is_none());
+        assert!(cache.get(path, 1, |_| None).is_none());
+        assert!(cache.get(path, 1, |_| Some(2)).is_some());
+    }
+}
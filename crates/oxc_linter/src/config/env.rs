@@ -1,11 +1,11 @@
 use rustc_hash::FxHashMap;
 use schemars::JsonSchema;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Predefine global variables.
 // TODO: list the keys we support
 // <https://eslint.org/docs/v8.x/use/configure/language-options#specifying-environments>
-#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct OxlintEnv(FxHashMap<String, bool>);
 
 impl OxlintEnv {
@@ -19,6 +19,13 @@ impl OxlintEnv {
         // Filter out false values
         self.0.iter().filter(|(_, v)| **v).map(|(k, _)| k.as_str())
     }
+
+    /// Merge `other` into `self`, with values in `other` taking precedence.
+    pub fn override_envs(&mut self, other: &Self) {
+        for (env, enabled) in &other.0 {
+            self.0.insert(env.clone(), *enabled);
+        }
+    }
 }
 
 impl Default for OxlintEnv {
@@ -0,0 +1,320 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use oxc_diagnostics::OxcDiagnostic;
+use saphyr::{Yaml, YamlLoader};
+use serde_json::{Map, Value};
+
+use super::{rules::parse_rule_key, transform_rule_and_plugin_name};
+use crate::rules::RULES;
+
+/// ESLint configuration files read by [`Migration::from_file`], in order of precedence.
+/// <https://eslint.org/docs/v8.x/use/configure/configuration-files#configuration-file-formats>
+pub const ESLINTRC_FILES: [&str; 5] =
+    [".eslintrc.json", ".eslintrc.yaml", ".eslintrc.yml", ".eslintrc", "package.json"];
+
+/// An ESLint configuration translated into an `.oxlintrc.json` configuration.
+///
+/// Rules not implemented by oxlint are dropped, and so are `extends` other than `eslint:recommended`,
+/// which becomes `oxlint:recommended`. `env`, `globals` and `settings` are kept as is.
+#[derive(Debug)]
+pub struct Migration {
+    /// The oxlint configuration.
+    pub config: Value,
+    /// Rules dropped from the configuration, including the rules of `overrides`.
+    pub unsupported_rules: Vec<String>,
+    /// `extends` dropped from the configuration.
+    pub unsupported_extends: Vec<String>,
+    /// Coverage of the plugins with rules dropped from the configuration.
+    pub plugins: Vec<PluginCoverage>,
+}
+
+/// Rules of an ESLint plugin configured in the migrated configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginCoverage {
+    /// Name of the plugin as prefixed in ESLint rule names, e.g. `@typescript-eslint`,
+    /// or `eslint` for core rules.
+    pub name: String,
+    /// Number of configured rules implemented by oxlint.
+    pub supported: usize,
+    /// Number of configured rules not implemented by oxlint.
+    pub unsupported: usize,
+}
+
+impl PluginCoverage {
+    /// Whether some rules of the plugin are implemented by oxlint, but not all.
+    pub fn is_partial(&self) -> bool {
+        self.supported > 0 && self.unsupported > 0
+    }
+}
+
+impl Migration {
+    /// The first ESLint configuration file of [`ESLINTRC_FILES`] in `dir`.
+    /// `package.json` is only used when it has an `eslintConfig` field.
+    pub fn find_eslint_config(dir: &Path) -> Option<PathBuf> {
+        ESLINTRC_FILES.iter().map(|name| dir.join(name)).find(|path| {
+            path.is_file()
+                && (!path.ends_with("package.json")
+                    || read_eslint_config(path).is_ok_and(|json| !json.is_null()))
+        })
+    }
+
+    /// Migrate the ESLint configuration file at `path`,
+    /// either json, yaml, or the `eslintConfig` field of a `package.json`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the file cannot be read or parsed, or is not an object.
+    pub fn from_file(path: &Path) -> Result<Self, OxcDiagnostic> {
+        let eslint_config = read_eslint_config(path)?;
+        let Value::Object(eslint_config) = eslint_config else {
+            return Err(OxcDiagnostic::error(format!(
+                "Failed to migrate {path:?}, expected an ESLint configuration object"
+            )));
+        };
+        Ok(Self::from_eslint_config(&eslint_config))
+    }
+
+    fn from_eslint_config(eslint_config: &Map<String, Value>) -> Self {
+        let mut migration = Self {
+            config: Value::Null,
+            unsupported_rules: vec![],
+            unsupported_extends: vec![],
+            plugins: vec![],
+        };
+        let mut plugins = BTreeMap::<String, PluginCoverage>::new();
+
+        let mut config = migration.migrate_section(eslint_config, &mut plugins);
+        if let Some(extends) = eslint_config.get("extends") {
+            let extends = match extends {
+                Value::Array(extends) => extends.iter().filter_map(Value::as_str).collect(),
+                Value::String(extends) => vec![extends.as_str()],
+                _ => vec![],
+            };
+            let extends = extends
+                .into_iter()
+                .filter_map(|name| {
+                    if name == "eslint:recommended" {
+                        return Some(Value::from("oxlint:recommended"));
+                    }
+                    migration.unsupported_extends.push(name.to_string());
+                    None
+                })
+                .collect::<Vec<_>>();
+            if !extends.is_empty() {
+                config.insert("extends".to_string(), Value::Array(extends));
+            }
+        }
+        if eslint_config.get("root").and_then(Value::as_bool) == Some(true) {
+            config.insert("root".to_string(), Value::Bool(true));
+        }
+        if let Some(Value::Array(overrides)) = eslint_config.get("overrides") {
+            let overrides = overrides
+                .iter()
+                .filter_map(Value::as_object)
+                .map(|eslint_override| {
+                    let mut config = migration.migrate_section(eslint_override, &mut plugins);
+                    for key in ["files", "excludedFiles"] {
+                        if let Some(files) = eslint_override.get(key) {
+                            let files = match files {
+                                Value::String(file) => Value::Array(vec![file.clone().into()]),
+                                files => files.clone(),
+                            };
+                            config.insert(key.to_string(), files);
+                        }
+                    }
+                    Value::Object(config)
+                })
+                .collect::<Vec<_>>();
+            if !overrides.is_empty() {
+                config.insert("overrides".to_string(), Value::Array(overrides));
+            }
+        }
+
+        migration.config = Value::Object(config);
+        migration.unsupported_rules.sort_unstable();
+        migration.unsupported_rules.dedup();
+        migration.plugins = plugins.into_values().filter(|plugin| plugin.unsupported > 0).collect();
+        migration
+    }
+
+    /// Migrate the `env`, `globals`, `settings` and `rules` of the configuration or of an override.
+    fn migrate_section(
+        &mut self,
+        eslint_config: &Map<String, Value>,
+        plugins: &mut BTreeMap<String, PluginCoverage>,
+    ) -> Map<String, Value> {
+        let mut config = Map::new();
+        for key in ["env", "globals", "settings"] {
+            if let Some(value) = eslint_config.get(key) {
+                config.insert(key.to_string(), value.clone());
+            }
+        }
+        let Some(Value::Object(eslint_rules)) = eslint_config.get("rules") else {
+            return config;
+        };
+        let mut rules = Map::new();
+        for (key, value) in eslint_rules {
+            let plugin = key.rsplit_once('/').map_or("eslint", |(plugin, _)| plugin);
+            let coverage = plugins.entry(plugin.to_string()).or_insert_with(|| PluginCoverage {
+                name: plugin.to_string(),
+                supported: 0,
+                unsupported: 0,
+            });
+            if is_supported(key) {
+                coverage.supported += 1;
+                rules.insert(key.clone(), value.clone());
+            } else {
+                coverage.unsupported += 1;
+                self.unsupported_rules.push(key.clone());
+            }
+        }
+        config.insert("rules".to_string(), Value::Object(rules));
+        config
+    }
+}
+
+/// Whether the ESLint rule named `key` is implemented by oxlint,
+/// with the same plugin name mapping as when reading `.oxlintrc.json`.
+fn is_supported(key: &str) -> bool {
+    let (plugin_name, rule_name) = parse_rule_key(key);
+    let (rule_name, plugin_name) = transform_rule_and_plugin_name(&rule_name, &plugin_name);
+    RULES.iter().any(|rule| rule.name() == rule_name && rule.plugin_name() == plugin_name)
+}
+
+fn read_eslint_config(path: &Path) -> Result<Value, OxcDiagnostic> {
+    let parse_error = |err: String| {
+        OxcDiagnostic::error(format!("Failed to parse ESLint config {path:?}")).with_help(err)
+    };
+    let mut string = std::fs::read_to_string(path).map_err(|err| parse_error(err.to_string()))?;
+    let is_yaml = path.extension().is_some_and(|ext| ext == "yaml" || ext == "yml");
+    if is_yaml {
+        return parse_yaml(&string).map_err(parse_error);
+    }
+    json_strip_comments::strip(&mut string).map_err(|err| parse_error(err.to_string()))?;
+    let json = serde_json::from_str::<Value>(&string);
+    if path.ends_with("package.json") {
+        let mut json = json.map_err(|err| parse_error(err.to_string()))?;
+        return Ok(json.get_mut("eslintConfig").map(Value::take).unwrap_or_default());
+    }
+    // A legacy `.eslintrc` is either json or yaml.
+    match json {
+        Ok(json) => Ok(json),
+        Err(err) if path.extension().is_none() => {
+            parse_yaml(&string).map_err(|_| parse_error(err.to_string()))
+        }
+        Err(err) => Err(parse_error(err.to_string())),
+    }
+}
+
+fn parse_yaml(source: &str) -> Result<Value, String> {
+    let documents = YamlLoader::load_from_str(source).map_err(|err| err.to_string())?;
+    Ok(documents.first().map(yaml_to_json).unwrap_or_default())
+}
+
+fn yaml_to_json(yaml: &Yaml) -> Value {
+    match yaml {
+        Yaml::Real(_) => yaml.as_f64().map(Value::from).unwrap_or_default(),
+        Yaml::Integer(n) => Value::from(*n),
+        Yaml::String(s) => Value::from(s.as_str()),
+        Yaml::Boolean(b) => Value::Bool(*b),
+        Yaml::Array(items) => Value::Array(items.iter().map(yaml_to_json).collect()),
+        Yaml::Hash(entries) => Value::Object(
+            entries
+                .iter()
+                .filter_map(|(key, value)| {
+                    let key = match key {
+                        Yaml::String(key) => key.clone(),
+                        Yaml::Integer(key) => key.to_string(),
+                        _ => return None,
+                    };
+                    Some((key, yaml_to_json(value)))
+                })
+                .collect(),
+        ),
+        _ => Value::Null,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::{parse_yaml, Migration, PluginCoverage};
+
+    #[test]
+    fn test_migrate() {
+        let eslint_config = json!({
+            "root": true,
+            "extends": ["eslint:recommended", "plugin:react/recommended"],
+            "plugins": ["react", "@typescript-eslint"],
+            "env": { "browser": true },
+            "globals": { "foo": "readonly" },
+            "rules": {
+                "eqeqeq": ["error", "always"],
+                "no-unknown-rule": "warn",
+                "@typescript-eslint/no-explicit-any": "error",
+                "@typescript-eslint/no-unknown-rule": "error",
+                "react-hooks/rules-of-hooks": "error",
+            },
+            "overrides": [{
+                "files": "*.test.js",
+                "env": { "jest": true },
+                "rules": { "no-console": "off", "jest/no-unknown-rule": 2 },
+            }],
+        });
+        let migration = Migration::from_eslint_config(eslint_config.as_object().unwrap());
+        assert_eq!(
+            migration.config,
+            json!({
+                "root": true,
+                "extends": ["oxlint:recommended"],
+                "env": { "browser": true },
+                "globals": { "foo": "readonly" },
+                "rules": {
+                    "eqeqeq": ["error", "always"],
+                    "@typescript-eslint/no-explicit-any": "error",
+                    "react-hooks/rules-of-hooks": "error",
+                },
+                "overrides": [{
+                    "files": ["*.test.js"],
+                    "env": { "jest": true },
+                    "rules": { "no-console": "off" },
+                }],
+            })
+        );
+        assert_eq!(
+            migration.unsupported_rules,
+            vec!["@typescript-eslint/no-unknown-rule", "jest/no-unknown-rule", "no-unknown-rule"]
+        );
+        assert_eq!(migration.unsupported_extends, vec!["plugin:react/recommended"]);
+        assert_eq!(
+            migration.plugins,
+            vec![
+                PluginCoverage {
+                    name: "@typescript-eslint".to_string(),
+                    supported: 1,
+                    unsupported: 1
+                },
+                PluginCoverage { name: "eslint".to_string(), supported: 2, unsupported: 1 },
+                PluginCoverage { name: "jest".to_string(), supported: 0, unsupported: 1 },
+            ]
+        );
+        assert!(migration.plugins[0].is_partial());
+        assert!(!migration.plugins[2].is_partial());
+    }
+
+    #[test]
+    fn test_parse_yaml() {
+        let yaml = "env:\n  browser: true\nrules:\n  eqeqeq: [error, always]\n  no-debugger: 1\n";
+        assert_eq!(
+            parse_yaml(yaml).unwrap(),
+            json!({
+                "env": { "browser": true },
+                "rules": { "eqeqeq": ["error", "always"], "no-debugger": 1 },
+            })
+        );
+    }
+}
@@ -1,31 +1,46 @@
 mod env;
 mod globals;
+mod migrate;
+mod overrides;
 mod rules;
 mod settings;
+mod validate;
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use oxc_diagnostics::OxcDiagnostic;
 use rustc_hash::FxHashSet;
 use schemars::JsonSchema;
 use serde::Deserialize;
+use serde_json::{Map, Value};
 
 pub use self::{
     env::OxlintEnv,
     globals::OxlintGlobals,
+    migrate::{Migration, PluginCoverage, ESLINTRC_FILES},
+    overrides::{Globs, OxlintOverride, OxlintOverrides},
     rules::OxlintRules,
     settings::{jsdoc::JSDocPluginSettings, OxlintSettings},
 };
 use crate::{
-    rules::RuleEnum, utils::is_jest_rule_adapted_to_vitest, AllowWarnDeny, RuleWithSeverity,
+    rules::{RuleEnum, RULES},
+    utils::is_jest_rule_adapted_to_vitest,
+    RuleCategory, RuleWithSeverity,
 };
 
+/// File name of the configuration files discovered next to the linted files.
+pub const OXLINTRC: &str = ".oxlintrc.json";
+
 /// Oxlint Configuration File
 ///
 /// This configuration is aligned with ESLint v8's configuration schema (`eslintrc.json`).
 ///
 /// Usage: `oxlint -c oxlintrc.json`
 ///
+/// Without `-c`, each file is linted with the `.oxlintrc.json` files found in its directory
+/// and its ancestors, where nearer files take precedence. Set `"root": true` to stop looking
+/// in ancestor directories.
+///
 /// ::: danger NOTE
 ///
 /// Only the `.json` format is supported.
@@ -38,6 +53,7 @@ use crate::{
 ///
 /// ```json
 /// {
+///   "extends": ["oxlint:recommended", "./base.json"],
 ///   "env": {
 ///       "browser": true
 ///   },
@@ -48,127 +64,108 @@ use crate::{
 ///   },
 ///   "rules": {
 ///       "eqeqeq": "warn"
-///   }
+///   },
+///   "overrides": [
+///     {
+///       "files": ["*.test.js"],
+///       "env": { "jest": true },
+///       "rules": { "no-console": "off" }
+///     }
+///   ]
 ///  }
 /// ```
-#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
 #[serde(default)]
 pub struct OxlintConfig {
+    /// Configurations this configuration is merged over, in order.
+    ///
+    /// Either paths relative to this file, or one of the presets
+    /// `oxlint:recommended` (correctness rules) and `oxlint:all` (all rules except nursery).
+    pub(crate) extends: Vec<String>,
+    /// Stop looking for `.oxlintrc.json` files in ancestor directories.
+    pub(crate) root: bool,
     /// See [Oxlint Rules](./rules)
     pub(crate) rules: OxlintRules,
     pub(crate) settings: OxlintSettings,
     pub(crate) env: OxlintEnv,
     pub(crate) globals: OxlintGlobals,
+    /// Rules, env and globals for files matching glob patterns, applied in order over the base configuration.
+    pub(crate) overrides: OxlintOverrides,
 }
 
 impl OxlintConfig {
     /// # Errors
     ///
     /// * Parse Failure
+    /// * Unsupported or circular `extends`
+    /// * Rule options not matching the schema of the rule
     pub fn from_file(path: &Path) -> Result<Self, OxcDiagnostic> {
-        let mut string = std::fs::read_to_string(path).map_err(|e| {
-            OxcDiagnostic::error(format!("Failed to parse config {path:?} with error {e:?}"))
-        })?;
+        Self::from_files(&[path.to_path_buf()])
+    }
 
-        // jsonc support
-        json_strip_comments::strip(&mut string).map_err(|err| {
-            OxcDiagnostic::error(format!("Failed to parse jsonc file {path:?}: {err:?}"))
-        })?;
+    /// Load the configuration from nested configuration files, ordered from the nearest
+    /// to the farthest one. Nearer files take precedence, and files after one with
+    /// `"root": true` are ignored.
+    ///
+    /// # Errors
+    ///
+    /// * Parse Failure
+    /// * Unsupported or circular `extends`
+    /// * Rule options not matching the schema of the rule
+    pub fn from_files(paths: &[PathBuf]) -> Result<Self, OxcDiagnostic> {
+        let mut chain = vec![];
+        for path in paths {
+            let mut overrides = vec![];
+            let json = load_json(path, &mut overrides, &mut vec![])?;
+            let is_root = json.get("root").and_then(Value::as_bool).unwrap_or(false);
+            chain.push((json, overrides));
+            if is_root {
+                break;
+            }
+        }
 
-        let json = serde_json::from_str::<serde_json::Value>(&string).map_err(|err| {
-            let guess = mime_guess::from_path(path);
-            let err = match guess.first() {
-                // syntax error
-                Some(mime) if mime.subtype() == "json" => err.to_string(),
-                Some(_) => "Only json configuration is supported".to_string(),
-                None => {
-                    format!(
-                        "{err}, if the configuration is not a json file, please use json instead."
-                    )
-                }
-            };
-            OxcDiagnostic::error(format!("Failed to parse eslint config {path:?}.\n{err}"))
-        })?;
+        let mut json = Map::default();
+        let mut overrides = vec![];
+        for (file_json, file_overrides) in chain.into_iter().rev() {
+            merge_config(&mut json, file_json);
+            overrides.extend(file_overrides);
+        }
 
-        let config = Self::deserialize(&json).map_err(|err| {
+        let mut config = Self::deserialize(&Value::Object(json)).map_err(|err| {
             OxcDiagnostic::error(format!("Failed to parse config with error {err:?}"))
         })?;
+        config.overrides = OxlintOverrides::from(overrides);
+
+        config.rules.validate("rules")?;
+        for (i, config_override) in config.overrides.iter().enumerate() {
+            config_override.rules.validate(&format!("overrides[{i}].rules"))?;
+        }
 
         Ok(config)
     }
 
-    #[allow(clippy::option_if_let_else)]
     pub fn override_rules(
         &self,
         rules_for_override: &mut FxHashSet<RuleWithSeverity>,
         all_rules: &[RuleEnum],
     ) {
-        use itertools::Itertools;
-        let mut rules_to_replace: Vec<RuleWithSeverity> = vec![];
-        let mut rules_to_remove: Vec<RuleWithSeverity> = vec![];
-
-        // Rules can have the same name but different plugin names
-        let lookup = self.rules.iter().into_group_map_by(|r| r.rule_name.as_str());
-
-        for (name, rule_configs) in &lookup {
-            match rule_configs.len() {
-                0 => unreachable!(),
-                1 => {
-                    let rule_config = &rule_configs[0];
-                    let (rule_name, plugin_name) = transform_rule_and_plugin_name(
-                        &rule_config.rule_name,
-                        &rule_config.plugin_name,
-                    );
-                    let severity = rule_config.severity;
-                    match severity {
-                        AllowWarnDeny::Warn | AllowWarnDeny::Deny => {
-                            if let Some(rule) = all_rules
-                                .iter()
-                                .find(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
-                            {
-                                let config = rule_config.config.clone().unwrap_or_default();
-                                let rule = rule.read_json(config);
-                                rules_to_replace.push(RuleWithSeverity::new(rule, severity));
-                            }
-                        }
-                        AllowWarnDeny::Allow => {
-                            if let Some(rule) = rules_for_override
-                                .iter()
-                                .find(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
-                            {
-                                let rule = rule.clone();
-                                rules_to_remove.push(rule);
-                            }
-                        }
-                    }
-                }
-                _ => {
-                    // For overlapping rule names, use the "error" one
-                    // "no-loss-of-precision": "off",
-                    // "@typescript-eslint/no-loss-of-precision": "error"
-                    if let Some(rule_config) =
-                        rule_configs.iter().find(|r| r.severity.is_warn_deny())
-                    {
-                        if let Some(rule) = rules_for_override.iter().find(|r| r.name() == *name) {
-                            let config = rule_config.config.clone().unwrap_or_default();
-                            rules_to_replace
-                                .push(RuleWithSeverity::new(rule.read_json(config), rule.severity));
-                        }
-                    } else if rule_configs.iter().all(|r| r.severity.is_allow()) {
-                        if let Some(rule) = rules_for_override.iter().find(|r| r.name() == *name) {
-                            rules_to_remove.push(rule.clone());
-                        }
-                    }
-                }
-            }
-        }
+        self.rules.override_rules(rules_for_override, all_rules);
+    }
 
-        for rule in rules_to_remove {
-            rules_for_override.remove(&rule);
-        }
-        for rule in rules_to_replace {
-            rules_for_override.replace(rule);
+    /// Create the configuration for a file matched by the `overrides` at the given indices.
+    /// The `env`, `globals` and `rules` of each override are merged in order over this configuration.
+    pub(crate) fn apply_overrides(&self, indices: &[usize]) -> Self {
+        let mut config = self.clone();
+        for override_config in indices.iter().map(|i| &self.overrides[*i]) {
+            if let Some(env) = &override_config.env {
+                config.env.override_envs(env);
+            }
+            if let Some(globals) = &override_config.globals {
+                config.globals.override_globals(globals);
+            }
+            config.rules.extend(&override_config.rules);
         }
+        config
     }
 }
 
@@ -183,14 +180,183 @@ fn transform_rule_and_plugin_name<'a>(
     (rule_name, plugin_name)
 }
 
+/// Read a configuration file and resolve its `extends`.
+///
+/// `overrides` are removed from the returned value and collected into `overrides`,
+/// with glob patterns relative to the directory of the file declaring them.
+fn load_json(
+    path: &Path,
+    overrides: &mut Vec<OxlintOverride>,
+    visited: &mut Vec<PathBuf>,
+) -> Result<Map<String, Value>, OxcDiagnostic> {
+    if visited.iter().any(|visited| visited == path) {
+        return Err(OxcDiagnostic::error(format!("Circular `extends` in config {path:?}")));
+    }
+    visited.push(path.to_path_buf());
+
+    let Value::Object(mut json) = read_json(path)? else {
+        return Err(OxcDiagnostic::error(format!(
+            "Failed to parse config {path:?}, expected a json object"
+        )));
+    };
+
+    let extends = match json.remove("extends") {
+        None => vec![],
+        Some(Value::String(name)) => vec![name],
+        Some(Value::Array(names)) => names
+            .into_iter()
+            .map(|name| match name {
+                Value::String(name) => Ok(name),
+                name => Err(OxcDiagnostic::error(format!(
+                    "Failed to parse `extends` in config {path:?}, expected a string but got {name}"
+                ))),
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        Some(value) => {
+            return Err(OxcDiagnostic::error(format!(
+                "Failed to parse `extends` in config {path:?}, expected a string or an array of strings but got {value}"
+            )))
+        }
+    };
+
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+    let mut config = Map::default();
+    for name in &extends {
+        let extended = if let Some(preset) = name.strip_prefix("oxlint:") {
+            preset_json(preset).ok_or_else(|| {
+                OxcDiagnostic::error(format!("Unknown preset {name:?} in config {path:?}"))
+            })?
+        } else if name.starts_with('.') || Path::new(name).is_absolute() {
+            load_json(&dir.join(name), overrides, visited)?
+        } else {
+            return Err(OxcDiagnostic::error(format!(
+                "Unsupported `extends` value {name:?} in config {path:?}, only relative paths and `oxlint:` presets are supported"
+            )));
+        };
+        merge_config(&mut config, extended);
+    }
+
+    if let Some(value) = json.remove("overrides") {
+        let file_overrides = Vec::<OxlintOverride>::deserialize(&value).map_err(|err| {
+            OxcDiagnostic::error(format!("Failed to parse config with error {err:?}"))
+        })?;
+        let base_dir =
+            std::env::current_dir().map_or_else(|_| dir.to_path_buf(), |cwd| cwd.join(dir));
+        overrides.extend(
+            file_overrides.into_iter().map(|config| config.with_base_dir(base_dir.clone())),
+        );
+    }
+
+    merge_config(&mut config, json);
+    visited.pop();
+
+    Ok(config)
+}
+
+fn read_json(path: &Path) -> Result<Value, OxcDiagnostic> {
+    let mut string = std::fs::read_to_string(path).map_err(|e| {
+        OxcDiagnostic::error(format!("Failed to parse config {path:?} with error {e:?}"))
+    })?;
+
+    // jsonc support
+    json_strip_comments::strip(&mut string).map_err(|err| {
+        OxcDiagnostic::error(format!("Failed to parse jsonc file {path:?}: {err:?}"))
+    })?;
+
+    serde_json::from_str::<Value>(&string).map_err(|err| {
+        let guess = mime_guess::from_path(path);
+        let err = match guess.first() {
+            // syntax error
+            Some(mime) if mime.subtype() == "json" => err.to_string(),
+            Some(_) => "Only json configuration is supported".to_string(),
+            None => {
+                format!("{err}, if the configuration is not a json file, please use json instead.")
+            }
+        };
+        OxcDiagnostic::error(format!("Failed to parse eslint config {path:?}.\n{err}"))
+    })
+}
+
+/// Built-in configurations, usable as `"extends": ["oxlint:recommended"]`.
+///
+/// * `recommended`: all rules in the `correctness` category
+/// * `all`: all rules except the ones in the `nursery` category
+fn preset_json(name: &str) -> Option<Map<String, Value>> {
+    let filter: fn(&RuleEnum) -> bool = match name {
+        "recommended" => |rule| rule.category() == RuleCategory::Correctness,
+        "all" => |rule| rule.category() != RuleCategory::Nursery,
+        _ => return None,
+    };
+    let rules = RULES
+        .iter()
+        .filter(|rule| filter(rule))
+        .map(|rule| {
+            let key = match rule.plugin_name() {
+                "eslint" => rule.name().to_string(),
+                plugin_name => format!("{plugin_name}/{}", rule.name()),
+            };
+            (key, Value::String("warn".to_string()))
+        })
+        .collect::<Map<_, _>>();
+    let mut config = Map::default();
+    config.insert("rules".to_string(), Value::Object(rules));
+    Some(config)
+}
+
+/// Merge the configuration `source` over `target`.
+///
+/// Objects are merged recursively, except for `rules` where each rule replaces the previous one.
+/// A rule configured with a severity only keeps the options of the rule it replaces.
+fn merge_config(target: &mut Map<String, Value>, source: Map<String, Value>) {
+    for (key, value) in source {
+        match (key.as_str(), target.get_mut(&key), value) {
+            ("rules", Some(Value::Object(target_rules)), Value::Object(rules)) => {
+                for (name, rule) in rules {
+                    match (target_rules.get_mut(&name), rule) {
+                        (
+                            Some(Value::Array(target_rule)),
+                            rule @ (Value::String(_) | Value::Number(_)),
+                        ) if !target_rule.is_empty() => {
+                            target_rule[0] = rule;
+                        }
+                        (_, rule) => {
+                            target_rules.insert(name, rule);
+                        }
+                    }
+                }
+            }
+            (_, Some(target_value), value) => merge_json(target_value, value),
+            (_, None, value) => {
+                target.insert(key, value);
+            }
+        }
+    }
+}
+
+fn merge_json(target: &mut Value, source: Value) {
+    match (target, source) {
+        (Value::Object(target), Value::Object(source)) => {
+            for (key, value) in source {
+                match target.get_mut(&key) {
+                    Some(target_value) => merge_json(target_value, value),
+                    None => {
+                        target.insert(key, value);
+                    }
+                }
+            }
+        }
+        (target, source) => *target = source,
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use std::env;
+    use std::{env, path::Path};
 
     use rustc_hash::FxHashSet;
     use serde::Deserialize;
 
-    use crate::rules::RULES;
+    use crate::{rules::RULES, AllowWarnDeny};
 
     use super::OxlintConfig;
 
@@ -201,6 +367,41 @@ mod test {
         assert!(!config.rules.is_empty());
     }
 
+    #[test]
+    fn test_from_file_extends() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends/eslint_config.json");
+        let config = OxlintConfig::from_file(&fixture_path).unwrap();
+
+        let eqeqeq = config.rules.iter().find(|rule| rule.rule_name == "eqeqeq").unwrap();
+        assert_eq!(eqeqeq.severity, AllowWarnDeny::Warn);
+        assert_eq!(eqeqeq.config, Some(serde_json::json!(["always"])));
+        // from `oxlint:recommended`
+        assert!(config.rules.iter().any(|rule| rule.rule_name == "no-debugger"));
+        assert!(config.env.iter().any(|env| env == "browser"));
+        assert!(config.globals.is_enabled("foo"));
+
+        assert_eq!(config.overrides.len(), 1);
+        let base_dir = config.overrides[0].base_dir.as_deref().unwrap();
+        assert!(base_dir.ends_with("fixtures/extends"));
+    }
+
+    #[test]
+    fn test_from_file_extends_circular() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends/circular_a.json");
+        assert!(OxlintConfig::from_file(&fixture_path).is_err());
+    }
+
+    #[test]
+    fn test_from_file_invalid_rule_options() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/invalid_rule_options.json");
+        let err = OxlintConfig::from_file(&fixture_path).unwrap_err();
+        assert_eq!(err.message, "Invalid options for rule `no-console`");
+        assert_eq!(
+            err.help.as_deref(),
+            Some(r#"overrides[0].rules.no-console[1].allow: expected an array, but got "info""#)
+        );
+    }
+
     #[test]
     fn test_deserialize() {
         let config = OxlintConfig::deserialize(&serde_json::json!({
@@ -233,11 +434,51 @@ mod test {
         }));
         assert!(config.is_ok());
 
-        let OxlintConfig { rules, settings, env, globals } = config.unwrap();
+        let OxlintConfig { rules, settings, env, globals, overrides, .. } = config.unwrap();
         assert!(!rules.is_empty());
         assert_eq!(settings.jsx_a11y.polymorphic_prop_name, Some("role".to_string()));
         assert_eq!(env.iter().count(), 1);
         assert!(globals.is_enabled("foo"));
+        assert!(overrides.is_empty());
+    }
+
+    #[test]
+    fn test_apply_overrides() {
+        let config = OxlintConfig::deserialize(&serde_json::json!({
+            "rules": { "no-console": "error" },
+            "env": { "browser": true },
+            "overrides": [
+                {
+                    "files": ["*.test.js"],
+                    "rules": { "no-console": "off", "no-debugger": "error" },
+                    "env": { "jest": true },
+                    "globals": { "foo": "readonly" }
+                },
+                {
+                    "files": ["*.js"],
+                    "env": { "browser": false }
+                }
+            ]
+        }))
+        .unwrap();
+
+        let mut rules = FxHashSet::default();
+        config.override_rules(&mut rules, &RULES);
+        assert_eq!(rules.len(), 1);
+
+        let base_dir = Path::new("/project");
+        assert!(config.overrides.matching(Path::new("/project/src/foo.ts"), base_dir).is_empty());
+        let indices = config.overrides.matching(Path::new("/project/src/foo.test.js"), base_dir);
+        assert_eq!(indices, vec![0, 1]);
+        let resolved = config.apply_overrides(&indices);
+        assert_eq!(resolved.env.iter().collect::<Vec<_>>(), vec!["jest"]);
+        assert!(resolved.globals.is_enabled("foo"));
+        for override_config in indices.iter().map(|i| &config.overrides[*i]) {
+            override_config.rules.override_rules(&mut rules, &RULES);
+        }
+        let mut names = rules.iter().map(|rule| rule.name()).collect::<Vec<_>>();
+        names.sort_unstable();
+        assert_eq!(names, vec!["no-debugger"]);
     }
 
     #[test]
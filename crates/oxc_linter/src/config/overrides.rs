@@ -0,0 +1,219 @@
+use std::{
+    ops::Deref,
+    path::{Path, PathBuf},
+};
+
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::{de, Deserialize, Deserializer};
+
+use super::{OxlintEnv, OxlintGlobals, OxlintRules};
+
+/// Configure different rules, env and globals for different files.
+#[derive(Debug, Clone, Default, Deserialize, JsonSchema)]
+pub struct OxlintOverrides(Vec<OxlintOverride>);
+
+impl Deref for OxlintOverrides {
+    type Target = Vec<OxlintOverride>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Vec<OxlintOverride>> for OxlintOverrides {
+    fn from(overrides: Vec<OxlintOverride>) -> Self {
+        Self(overrides)
+    }
+}
+
+impl OxlintOverrides {
+    /// Indices of all overrides matching `path`, in the order they are declared.
+    ///
+    /// Glob patterns of overrides without a base directory are relative to `base_dir`.
+    pub fn matching(&self, path: &Path, base_dir: &Path) -> Vec<usize> {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|(_, config)| {
+                let base_dir = config.base_dir.as_deref().unwrap_or(base_dir);
+                config.is_match(path.strip_prefix(base_dir).unwrap_or(path))
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
+/// A set of configurations applied to files matching `files` but not `excludedFiles`.
+///
+/// Glob patterns are relative to the directory of the configuration file.
+/// Patterns without a slash match the file name in any directory, e.g. `*.test.js`.
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OxlintOverride {
+    /// Glob patterns of files this override applies to.
+    pub files: Globs,
+
+    /// Glob patterns of files this override does not apply to, even if matched by `files`.
+    #[serde(default)]
+    pub excluded_files: Globs,
+
+    #[serde(default)]
+    pub rules: OxlintRules,
+
+    pub env: Option<OxlintEnv>,
+
+    pub globals: Option<OxlintGlobals>,
+
+    /// Directory of the configuration file declaring this override.
+    #[serde(skip)]
+    pub base_dir: Option<PathBuf>,
+}
+
+impl OxlintOverride {
+    #[must_use]
+    pub fn with_base_dir(mut self, base_dir: PathBuf) -> Self {
+        self.base_dir = Some(base_dir);
+        self
+    }
+
+    pub fn is_match(&self, path: &Path) -> bool {
+        self.files.is_match(path) && !self.excluded_files.is_match(path)
+    }
+}
+
+/// A list of glob patterns compiled into a single matcher.
+#[derive(Debug, Clone)]
+pub struct Globs {
+    patterns: Vec<String>,
+    set: GlobSet,
+}
+
+impl Default for Globs {
+    fn default() -> Self {
+        Self { patterns: vec![], set: GlobSet::empty() }
+    }
+}
+
+impl Globs {
+    /// # Errors
+    ///
+    /// Returns `Err` if any of the patterns is an invalid glob.
+    pub fn new(patterns: Vec<String>) -> Result<Self, globset::Error> {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in &patterns {
+            // Follow ESLint's `matchBase` behavior, where patterns without slashes are
+            // matched against the base name of the file.
+            let pattern = if pattern.contains('/') {
+                pattern.trim_start_matches("./").to_string()
+            } else {
+                format!("**/{pattern}")
+            };
+            builder.add(GlobBuilder::new(&pattern).literal_separator(true).build()?);
+        }
+        Ok(Self { patterns, set: builder.build()? })
+    }
+
+    pub fn patterns(&self) -> &[String] {
+        &self.patterns
+    }
+
+    pub fn is_match(&self, path: &Path) -> bool {
+        self.set.is_match(path)
+    }
+}
+
+impl<'de> Deserialize<'de> for Globs {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let patterns = Vec::<String>::deserialize(deserializer)?;
+        Self::new(patterns).map_err(de::Error::custom)
+    }
+}
+
+impl JsonSchema for Globs {
+    fn schema_name() -> String {
+        "Globs".to_owned()
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        gen.subschema_for::<Vec<String>>()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use serde::Deserialize;
+
+    use super::{OxlintOverride, OxlintOverrides};
+
+    #[test]
+    fn test_parse_overrides() {
+        let overrides = OxlintOverrides::deserialize(&serde_json::json!([
+            {
+                "files": ["*.test.js", "src/**/*.spec.ts"],
+                "excludedFiles": ["src/legacy/**"],
+                "rules": { "no-console": "off" },
+                "env": { "jest": true },
+                "globals": { "foo": "readonly" }
+            },
+            {
+                "files": ["src/**"]
+            }
+        ]))
+        .unwrap();
+        assert_eq!(overrides.len(), 2);
+
+        let first = &overrides[0];
+        assert_eq!(first.rules.len(), 1);
+        assert!(first.env.as_ref().is_some_and(|env| env.iter().any(|env| env == "jest")));
+        assert!(first.globals.as_ref().is_some_and(|globals| globals.is_enabled("foo")));
+
+        assert!(first.is_match(Path::new("foo.test.js")));
+        assert!(first.is_match(Path::new("test/foo.test.js")));
+        assert!(first.is_match(Path::new("src/a/b.spec.ts")));
+        assert!(!first.is_match(Path::new("src/legacy/b.spec.ts")));
+        assert!(!first.is_match(Path::new("foo.js")));
+
+        let base_dir = Path::new("/project");
+        assert_eq!(overrides.matching(Path::new("/project/src/foo.test.js"), base_dir), vec![0, 1]);
+        assert_eq!(overrides.matching(Path::new("/project/src/foo.js"), base_dir), vec![1]);
+        assert!(overrides.matching(Path::new("/project/foo.js"), base_dir).is_empty());
+    }
+
+    #[test]
+    fn test_overrides_base_dir() {
+        let overrides = OxlintOverrides::from(vec![OxlintOverride::deserialize(
+            &serde_json::json!({ "files": ["src/**"] }),
+        )
+        .unwrap()
+        .with_base_dir(PathBuf::from("/project/packages/a"))]);
+
+        let base_dir = Path::new("/project");
+        assert_eq!(
+            overrides.matching(Path::new("/project/packages/a/src/foo.js"), base_dir),
+            vec![0]
+        );
+        assert!(overrides.matching(Path::new("/project/src/foo.js"), base_dir).is_empty());
+    }
+
+    #[test]
+    fn test_parse_overrides_invalid_glob() {
+        let overrides = OxlintOverrides::deserialize(&serde_json::json!([{ "files": ["a/**["] }]));
+        assert!(overrides.is_err());
+    }
+
+    #[test]
+    fn test_parse_overrides_default() {
+        let overrides = OxlintOverrides::default();
+        assert!(overrides.is_empty());
+    }
+}
@@ -1,14 +1,24 @@
 use std::{borrow::Cow, fmt, ops::Deref};
 
 use oxc_diagnostics::{Error, OxcDiagnostic};
-use rustc_hash::FxHashMap;
-use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use rustc_hash::{FxHashMap, FxHashSet};
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{
+        ArrayValidation, InstanceType, Schema, SchemaObject, SingleOrVec, SubschemaValidation,
+    },
+    JsonSchema,
+};
 use serde::{
     de::{self, Deserializer, Visitor},
     Deserialize,
 };
 
-use crate::AllowWarnDeny;
+use super::{transform_rule_and_plugin_name, validate::validate};
+use crate::{
+    rules::{RuleEnum, RULES},
+    AllowWarnDeny, RuleWithSeverity,
+};
 
 // TS type is `Record<string, RuleConf>`
 //   - type SeverityConf = 0 | 1 | 2 | "off" | "warn" | "error";
@@ -44,7 +54,45 @@ impl JsonSchema for OxlintRules {
             String(String),
             Array(Vec<serde_json::Value>),
         }
-        gen.subschema_for::<FxHashMap<String, DummyRule>>()
+        #[allow(unused)]
+        #[derive(Debug, Clone, JsonSchema)]
+        #[serde(untagged)]
+        enum RuleSeverity {
+            #[schemars(range(min = 0, max = 2.0))]
+            Number(usize),
+            String(String),
+        }
+
+        let mut schema = gen.subschema_for::<FxHashMap<String, DummyRule>>().into_object();
+        // Rules with options schemas get their options shape, e.g. for autocompletion in editors.
+        for rule in RULES.iter() {
+            let Some(options) = rule.options_schema(gen) else { continue };
+            let severity = gen.subschema_for::<RuleSeverity>();
+            let with_options = SchemaObject {
+                instance_type: Some(InstanceType::Array.into()),
+                array: Some(Box::new(ArrayValidation {
+                    items: Some(SingleOrVec::Vec(
+                        std::iter::once(severity.clone()).chain(options).collect(),
+                    )),
+                    additional_items: Some(Box::new(Schema::Bool(false))),
+                    min_items: Some(1),
+                    ..ArrayValidation::default()
+                })),
+                ..SchemaObject::default()
+            };
+            let rule_schema = SchemaObject {
+                subschemas: Some(Box::new(SubschemaValidation {
+                    any_of: Some(vec![severity, with_options.into()]),
+                    ..SubschemaValidation::default()
+                })),
+                ..SchemaObject::default()
+            };
+            schema
+                .object()
+                .properties
+                .insert(rule_key(rule.plugin_name(), rule.name()), rule_schema.into());
+        }
+        schema.into()
     }
 }
 
@@ -85,7 +133,7 @@ impl<'de> Deserialize<'de> for OxlintRules {
     }
 }
 
-fn parse_rule_key(name: &str) -> (String, String) {
+pub(super) fn parse_rule_key(name: &str) -> (String, String) {
     let Some((plugin_name, rule_name)) = name.split_once('/') else {
         return ("eslint".to_string(), name.to_string());
     };
@@ -106,6 +154,19 @@ fn parse_rule_key(name: &str) -> (String, String) {
     (oxlint_plugin_name.to_string(), rule_name.to_string())
 }
 
+/// The configuration key of a rule, the reverse of [`parse_rule_key`], e.g. `@typescript-eslint/no-explicit-any`.
+fn rule_key(plugin_name: &str, rule_name: &str) -> String {
+    let plugin_name = match plugin_name {
+        "eslint" => return rule_name.to_string(),
+        "typescript" => "@typescript-eslint",
+        "jsx_a11y" => "jsx-a11y",
+        "react_perf" => "react-perf",
+        "nextjs" => "@next/next",
+        plugin_name => plugin_name,
+    };
+    format!("{plugin_name}/{rule_name}")
+}
+
 fn parse_rule_value(
     value: &serde_json::Value,
 ) -> Result<(AllowWarnDeny, Option<serde_json::Value>), Error> {
@@ -145,6 +206,141 @@ fn parse_rule_value(
     }
 }
 
+impl OxlintRules {
+    #[allow(clippy::option_if_let_else)]
+    pub fn override_rules(
+        &self,
+        rules_for_override: &mut FxHashSet<RuleWithSeverity>,
+        all_rules: &[RuleEnum],
+    ) {
+        use itertools::Itertools;
+        let mut rules_to_replace: Vec<RuleWithSeverity> = vec![];
+        let mut rules_to_remove: Vec<RuleWithSeverity> = vec![];
+
+        // Rules can have the same name but different plugin names
+        let lookup = self.iter().into_group_map_by(|r| r.rule_name.as_str());
+
+        for (name, rule_configs) in &lookup {
+            match rule_configs.len() {
+                0 => unreachable!(),
+                1 => {
+                    let rule_config = &rule_configs[0];
+                    let (rule_name, plugin_name) = transform_rule_and_plugin_name(
+                        &rule_config.rule_name,
+                        &rule_config.plugin_name,
+                    );
+                    let severity = rule_config.severity;
+                    match severity {
+                        AllowWarnDeny::Warn | AllowWarnDeny::Deny => {
+                            if let Some(rule) = all_rules
+                                .iter()
+                                .find(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
+                            {
+                                let config = rule_config.config.clone().unwrap_or_default();
+                                let rule = rule.read_json(config);
+                                rules_to_replace.push(RuleWithSeverity::new(rule, severity));
+                            }
+                        }
+                        AllowWarnDeny::Allow => {
+                            if let Some(rule) = rules_for_override
+                                .iter()
+                                .find(|r| r.name() == rule_name && r.plugin_name() == plugin_name)
+                            {
+                                let rule = rule.clone();
+                                rules_to_remove.push(rule);
+                            }
+                        }
+                    }
+                }
+                _ => {
+                    // For overlapping rule names, use the "error" one
+                    // "no-loss-of-precision": "off",
+                    // "@typescript-eslint/no-loss-of-precision": "error"
+                    if let Some(rule_config) =
+                        rule_configs.iter().find(|r| r.severity.is_warn_deny())
+                    {
+                        if let Some(rule) = rules_for_override.iter().find(|r| r.name() == *name) {
+                            let config = rule_config.config.clone().unwrap_or_default();
+                            rules_to_replace
+                                .push(RuleWithSeverity::new(rule.read_json(config), rule.severity));
+                        }
+                    } else if rule_configs.iter().all(|r| r.severity.is_allow()) {
+                        if let Some(rule) = rules_for_override.iter().find(|r| r.name() == *name) {
+                            rules_to_remove.push(rule.clone());
+                        }
+                    }
+                }
+            }
+        }
+
+        for rule in rules_to_remove {
+            rules_for_override.remove(&rule);
+        }
+        for rule in rules_to_replace {
+            rules_for_override.replace(rule);
+        }
+    }
+
+    /// Options of the rule `plugin_name/rule_name`, from the last configuration enabling it.
+    pub(crate) fn options(&self, plugin_name: &str, rule_name: &str) -> Option<&serde_json::Value> {
+        let mut enabling = self
+            .iter()
+            .rev()
+            .filter(|rule| rule.rule_name == rule_name && rule.severity.is_warn_deny());
+        enabling
+            .clone()
+            .find(|rule| {
+                transform_rule_and_plugin_name(&rule.rule_name, &rule.plugin_name).1 == plugin_name
+            })
+            // Rules with the same name in different plugins share their options, see `override_rules`.
+            .or_else(|| enabling.next())
+            .and_then(|rule| rule.config.as_ref())
+    }
+
+    /// Validate the options of the enabled rules against their [`Rule::options_schema`],
+    /// `path` being the path of these rules in the configuration, e.g. `overrides[0].rules`.
+    ///
+    /// [`Rule::options_schema`]: crate::rule::Rule::options_schema
+    pub(super) fn validate(&self, path: &str) -> Result<(), OxcDiagnostic> {
+        let mut gen = SchemaGenerator::default();
+        for rule_config in self.iter().filter(|rule| rule.severity.is_warn_deny()) {
+            let Some(serde_json::Value::Array(options)) = &rule_config.config else { continue };
+            let (rule_name, plugin_name) =
+                transform_rule_and_plugin_name(&rule_config.rule_name, &rule_config.plugin_name);
+            let Some(schemas) = RULES
+                .iter()
+                .find(|rule| rule.name() == rule_name && rule.plugin_name() == plugin_name)
+                .and_then(|rule| rule.options_schema(&mut gen))
+            else {
+                continue;
+            };
+            let key = rule_key(&rule_config.plugin_name, &rule_config.rule_name);
+            let invalid_options = |err: String| {
+                OxcDiagnostic::error(format!("Invalid options for rule `{key}`")).with_help(err)
+            };
+            if options.len() > schemas.len() {
+                return Err(invalid_options(format!(
+                    "{path}.{key}: expected at most {} options, but got {}",
+                    schemas.len(),
+                    options.len()
+                )));
+            }
+            for (i, (schema, option)) in schemas.iter().zip(options).enumerate() {
+                // Options follow the severity.
+                let option_path = format!("{path}.{key}[{}]", i + 1);
+                validate(schema, option, gen.definitions(), &option_path)
+                    .map_err(|err| invalid_options(err.to_string()))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Append all rule configurations from `other`, so they take precedence over existing ones.
+    pub fn extend(&mut self, other: &Self) {
+        self.0.extend(other.0.iter().cloned());
+    }
+}
+
 impl Deref for OxlintRules {
     type Target = Vec<ESLintRule>;
 
@@ -199,6 +395,84 @@ mod test {
         assert!(r4.config.is_none());
     }
 
+    #[test]
+    fn test_validate_rules() {
+        let validate = |rules: serde_json::Value| {
+            OxlintRules::deserialize(&rules)
+                .unwrap()
+                .validate("rules")
+                .map_err(|err| (err.message.clone(), err.help.clone().unwrap_or_default()))
+        };
+
+        assert!(validate(serde_json::json!({
+            "eqeqeq": ["error", "smart", { "null": "ignore" }],
+            "no-console": ["warn", { "allow": ["info"] }],
+            "no-debugger": ["error", "any", "options"],
+        }))
+        .is_ok());
+        // Options of disabled rules are not validated.
+        assert!(validate(serde_json::json!({ "eqeqeq": ["off", "sometimes"] })).is_ok());
+
+        assert_eq!(
+            validate(serde_json::json!({ "eqeqeq": ["error", "sometimes"] })),
+            Err((
+                "Invalid options for rule `eqeqeq`".to_string(),
+                r#"rules.eqeqeq[1]: expected one of "always", "smart", but got "sometimes""#
+                    .to_string()
+            ))
+        );
+        assert_eq!(
+            validate(serde_json::json!({ "eqeqeq": ["error", "always", { "null": "alwys" }] }))
+                .unwrap_err()
+                .1,
+            r#"rules.eqeqeq[2].null: expected one of "always", "never", "ignore", but got "alwys""#
+        );
+        assert_eq!(
+            validate(serde_json::json!({ "no-console": ["error", { "allow": "info" }] }))
+                .unwrap_err()
+                .1,
+            r#"rules.no-console[1].allow: expected an array, but got "info""#
+        );
+        // Unknown properties are accepted, e.g. the options of ESLint which are not supported yet.
+        assert!(validate(serde_json::json!({ "no-console": ["error", { "allows": ["info"] }] }))
+            .is_ok());
+        assert!(validate(serde_json::json!({
+            "no-unused-vars": ["error", {
+                "destructuredArrayIgnorePattern": "^_",
+                "reportUsedIgnorePattern": true,
+            }],
+        }))
+        .is_ok());
+        assert!(validate(serde_json::json!({
+            "complexity": ["error", { "max": 10, "variant": "modified" }],
+        }))
+        .is_ok());
+        assert_eq!(
+            validate(serde_json::json!({ "eqeqeq": ["error", "always", {}, "smart"] }))
+                .unwrap_err()
+                .1,
+            "rules.eqeqeq: expected at most 2 options, but got 3"
+        );
+
+        assert!(validate(serde_json::json!({
+            "import/extensions": ["error", "never", { "json": "always" }],
+            "import/order": ["error", {
+                "groups": ["builtin", ["parent", "sibling"], "type"],
+                "newlines-between": "always",
+                "alphabetize": { "order": "asc", "caseInsensitive": true },
+            }],
+            "no-use-before-define": ["error", "nofunc"],
+            "prefer-const": ["error", { "destructuring": "all" }],
+        }))
+        .is_ok());
+        assert_eq!(
+            validate(serde_json::json!({ "import/order": ["error", { "groups": ["builtins"] }] }))
+                .unwrap_err()
+                .1,
+            r#"rules.import/order[1].groups[0]: expected one of "builtin", "external", "internal", "unknown", "parent", "sibling", "index", "object", "type", but got "builtins""#
+        );
+    }
+
     #[test]
     fn test_parse_rules_default() {
         let rules = OxlintRules::default();
@@ -0,0 +1,295 @@
+use std::fmt;
+
+use schemars::{
+    schema::{InstanceType, Schema, SchemaObject, SingleOrVec},
+    Map,
+};
+use serde_json::Value;
+
+/// A value not matching its JSON schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct ValidationError {
+    /// Path of the invalid value, e.g. `rules.eqeqeq[2].null`.
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Validate `value` at `path` against `schema`, resolving references into `definitions`.
+///
+/// Only the subset of JSON schema generated by `schemars` for rule options is supported:
+/// `$ref`, `type`, `enum`, `const`, `allOf`, `anyOf`, `oneOf`, array items, object properties
+/// and numeric ranges. String patterns and formats are not checked.
+pub(super) fn validate(
+    schema: &Schema,
+    value: &Value,
+    definitions: &Map<String, Schema>,
+    path: &str,
+) -> Result<(), ValidationError> {
+    Validator { definitions }.validate(schema, value, path)
+}
+
+struct Validator<'a> {
+    definitions: &'a Map<String, Schema>,
+}
+
+impl Validator<'_> {
+    fn validate(&self, schema: &Schema, value: &Value, path: &str) -> Result<(), ValidationError> {
+        match schema {
+            Schema::Bool(true) => Ok(()),
+            Schema::Bool(false) => Err(error(path, format!("unexpected {}", describe(value)))),
+            Schema::Object(schema) => self.validate_object(schema, value, path),
+        }
+    }
+
+    fn validate_object(
+        &self,
+        schema: &SchemaObject,
+        value: &Value,
+        path: &str,
+    ) -> Result<(), ValidationError> {
+        if let Some(reference) = &schema.reference {
+            let name = reference.trim_start_matches("#/definitions/");
+            if let Some(definition) = self.definitions.get(name) {
+                self.validate(definition, value, path)?;
+            }
+        }
+
+        if let Some(instance_type) = &schema.instance_type {
+            let types = match instance_type {
+                SingleOrVec::Single(instance_type) => std::slice::from_ref(&**instance_type),
+                SingleOrVec::Vec(types) => types.as_slice(),
+            };
+            if !types.iter().any(|instance_type| has_type(*instance_type, value)) {
+                let expected = types.iter().map(|t| type_name(*t)).collect::<Vec<_>>().join(" or ");
+                return Err(error(
+                    path,
+                    format!("expected {expected}, but got {}", describe(value)),
+                ));
+            }
+        }
+
+        if let Some(enum_values) = &schema.enum_values {
+            if !enum_values.contains(value) {
+                let expected = enum_values.iter().map(Value::to_string).collect::<Vec<_>>();
+                return Err(error(
+                    path,
+                    format!("expected one of {}, but got {}", expected.join(", "), describe(value)),
+                ));
+            }
+        }
+
+        if let Some(const_value) = &schema.const_value {
+            if const_value != value {
+                return Err(error(
+                    path,
+                    format!("expected {const_value}, but got {}", describe(value)),
+                ));
+            }
+        }
+
+        if let Some(subschemas) = &schema.subschemas {
+            for subschema in subschemas.all_of.iter().flatten() {
+                self.validate(subschema, value, path)?;
+            }
+            // `oneOf` is treated like `anyOf`, `schemars` uses it for mutually exclusive enum variants.
+            for any_of in [&subschemas.any_of, &subschemas.one_of].into_iter().flatten() {
+                let mut errors = vec![];
+                for subschema in any_of {
+                    match self.validate(subschema, value, path) {
+                        Ok(()) => break,
+                        Err(err) => errors.push(err),
+                    }
+                }
+                if errors.len() == any_of.len() {
+                    // Report the error found the deepest into the value, which is the most precise.
+                    if let Some(err) = errors.into_iter().rev().max_by_key(|err| err.path.len()) {
+                        return Err(err);
+                    }
+                }
+            }
+        }
+
+        if let (Some(number), Some(n)) = (&schema.number, value.as_f64()) {
+            if number.minimum.is_some_and(|min| n < min)
+                || number.exclusive_minimum.is_some_and(|min| n <= min)
+                || number.maximum.is_some_and(|max| n > max)
+                || number.exclusive_maximum.is_some_and(|max| n >= max)
+            {
+                return Err(error(path, format!("{} is out of range", describe(value))));
+            }
+        }
+
+        if let (Some(array), Value::Array(items)) = (&schema.array, value) {
+            if let Some(min_items) = array.min_items.filter(|min| items.len() < *min as usize) {
+                return Err(error(
+                    path,
+                    format!("expected at least {min_items} items, but got {}", items.len()),
+                ));
+            }
+            if let Some(max_items) = array.max_items.filter(|max| items.len() > *max as usize) {
+                return Err(error(
+                    path,
+                    format!("expected at most {max_items} items, but got {}", items.len()),
+                ));
+            }
+            let item_path = |i: usize| format!("{path}[{i}]");
+            match &array.items {
+                Some(SingleOrVec::Single(item_schema)) => {
+                    for (i, item) in items.iter().enumerate() {
+                        self.validate(item_schema, item, &item_path(i))?;
+                    }
+                }
+                Some(SingleOrVec::Vec(item_schemas)) => {
+                    for (i, (item_schema, item)) in item_schemas.iter().zip(items).enumerate() {
+                        self.validate(item_schema, item, &item_path(i))?;
+                    }
+                    if let Some(additional_items) = &array.additional_items {
+                        for (i, item) in items.iter().enumerate().skip(item_schemas.len()) {
+                            self.validate(additional_items, item, &item_path(i))?;
+                        }
+                    }
+                }
+                None => {}
+            }
+        }
+
+        if let (Some(object), Value::Object(properties)) = (&schema.object, value) {
+            if let Some(name) = object.required.iter().find(|name| !properties.contains_key(*name))
+            {
+                return Err(error(path, format!("missing property `{name}`")));
+            }
+            for (name, property) in properties {
+                let property_path = format!("{path}.{name}");
+                if let Some(property_schema) = object.properties.get(name) {
+                    self.validate(property_schema, property, &property_path)?;
+                } else if let Some(additional_properties) = &object.additional_properties {
+                    if matches!(**additional_properties, Schema::Bool(false)) {
+                        let expected = object
+                            .properties
+                            .keys()
+                            .map(|name| format!("`{name}`"))
+                            .collect::<Vec<_>>();
+                        return Err(error(
+                            path,
+                            format!(
+                                "unknown property `{name}`, expected one of {}",
+                                expected.join(", ")
+                            ),
+                        ));
+                    }
+                    self.validate(additional_properties, property, &property_path)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn error(path: &str, message: String) -> ValidationError {
+    ValidationError { path: path.to_string(), message }
+}
+
+fn has_type(instance_type: InstanceType, value: &Value) -> bool {
+    match instance_type {
+        InstanceType::Null => value.is_null(),
+        InstanceType::Boolean => value.is_boolean(),
+        InstanceType::Object => value.is_object(),
+        InstanceType::Array => value.is_array(),
+        InstanceType::Number => value.is_number(),
+        InstanceType::String => value.is_string(),
+        InstanceType::Integer => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+    }
+}
+
+fn type_name(instance_type: InstanceType) -> &'static str {
+    match instance_type {
+        InstanceType::Null => "null",
+        InstanceType::Boolean => "a boolean",
+        InstanceType::Object => "an object",
+        InstanceType::Array => "an array",
+        InstanceType::Number => "a number",
+        InstanceType::String => "a string",
+        InstanceType::Integer => "an integer",
+    }
+}
+
+fn describe(value: &Value) -> String {
+    match value {
+        Value::Array(_) => "an array".to_string(),
+        Value::Object(_) => "an object".to_string(),
+        value => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use schemars::{gen::SchemaGenerator, JsonSchema};
+    use serde_json::json;
+
+    use super::validate;
+
+    #[allow(dead_code)]
+    #[derive(JsonSchema)]
+    #[schemars(deny_unknown_fields, rename_all = "camelCase")]
+    struct Options {
+        mode: Option<Mode>,
+        allow: Vec<String>,
+        #[schemars(range(min = 1))]
+        max_depth: Option<u32>,
+    }
+
+    #[allow(dead_code)]
+    #[derive(JsonSchema)]
+    #[schemars(rename_all = "lowercase")]
+    enum Mode {
+        Always,
+        Never,
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut gen = SchemaGenerator::default();
+        let schema = gen.subschema_for::<Options>();
+        let validate = |value| {
+            validate(&schema, &value, gen.definitions(), "options").map_err(|err| err.to_string())
+        };
+
+        assert_eq!(validate(json!({ "allow": [], "mode": "never", "maxDepth": 3 })), Ok(()));
+        assert_eq!(validate(json!({ "allow": ["foo"], "mode": null })), Ok(()));
+        assert_eq!(
+            validate(json!({ "allow": [], "mode": "sometimes" })),
+            Err(r#"options.mode: expected one of "always", "never", but got "sometimes""#
+                .to_string())
+        );
+        assert_eq!(
+            validate(json!({ "allow": ["foo", 1] })),
+            Err("options.allow[1]: expected a string, but got 1".to_string())
+        );
+        assert_eq!(
+            validate(json!({ "allow": [], "maxDepth": 0 })),
+            Err("options.maxDepth: 0 is out of range".to_string())
+        );
+        assert_eq!(
+            validate(json!({ "mode": "never" })),
+            Err("options: missing property `allow`".to_string())
+        );
+        assert_eq!(
+            validate(json!({ "allow": [], "mod": "never" })),
+            Err("options: unknown property `mod`, expected one of `allow`, `maxDepth`, `mode`"
+                .to_string())
+        );
+        assert_eq!(
+            validate(json!("always")),
+            Err(r#"options: expected an object, but got "always""#.to_string())
+        );
+    }
+}
@@ -9,7 +9,7 @@ use oxc_syntax::module_record::ModuleRecord;
 use crate::{
     config::OxlintRules,
     disable_directives::{DisableDirectives, DisableDirectivesBuilder},
-    fixer::{Fix, Message, RuleFixer},
+    fixer::{CompositeFix, FixKind, Message, RuleFixer},
     javascript_globals::GLOBALS,
     AllowWarnDeny, OxlintConfig, OxlintEnv, OxlintGlobals, OxlintSettings,
 };
@@ -22,8 +22,8 @@ pub struct LintContext<'a> {
 
     disable_directives: Rc<DisableDirectives<'a>>,
 
-    /// Whether or not to apply code fixes during linting.
-    fix: bool,
+    /// Kinds of code fixes to apply during linting.
+    fix: FixKind,
 
     file_path: Rc<Path>,
 
@@ -52,7 +52,7 @@ impl<'a> LintContext<'a> {
             semantic,
             diagnostics: RefCell::new(vec![]),
             disable_directives: Rc::new(disable_directives),
-            fix: false,
+            fix: FixKind::None,
             file_path: file_path.into(),
             eslint_config: Arc::new(OxlintConfig::default()),
             current_rule_name: "",
@@ -61,7 +61,7 @@ impl<'a> LintContext<'a> {
     }
 
     #[must_use]
-    pub fn with_fix(mut self, fix: bool) -> Self {
+    pub fn with_fix(mut self, fix: FixKind) -> Self {
         self.fix = fix;
         self
     }
@@ -171,14 +171,46 @@ impl<'a> LintContext<'a> {
     }
 
     /// Report a lint rule violation and provide an automatic fix.
-    pub fn diagnostic_with_fix<F: FnOnce(RuleFixer<'_, 'a>) -> Fix<'a>>(
-        &self,
-        diagnostic: OxcDiagnostic,
-        fix: F,
-    ) {
-        if self.fix {
+    ///
+    /// The fix is either a single [`Fix`](crate::fixer::Fix) or a `Vec<Fix>` of edits applied all together.
+    /// It must not change the behavior of the code. Use
+    /// [`LintContext::diagnostic_with_suggestion`] or [`LintContext::diagnostic_with_dangerous_fix`]
+    /// otherwise.
+    pub fn diagnostic_with_fix<C, F>(&self, diagnostic: OxcDiagnostic, fix: F)
+    where
+        C: Into<CompositeFix<'a>>,
+        F: FnOnce(RuleFixer<'_, 'a>) -> C,
+    {
+        self.diagnostic_with_fix_of_kind(diagnostic, FixKind::SafeFix, fix);
+    }
+
+    /// Report a lint rule violation and suggest a change which may change the behavior of the code.
+    pub fn diagnostic_with_suggestion<C, F>(&self, diagnostic: OxcDiagnostic, fix: F)
+    where
+        C: Into<CompositeFix<'a>>,
+        F: FnOnce(RuleFixer<'_, 'a>) -> C,
+    {
+        self.diagnostic_with_fix_of_kind(diagnostic, FixKind::Suggestion, fix);
+    }
+
+    /// Report a lint rule violation and provide an automatic fix which may change the behavior of the code.
+    pub fn diagnostic_with_dangerous_fix<C, F>(&self, diagnostic: OxcDiagnostic, fix: F)
+    where
+        C: Into<CompositeFix<'a>>,
+        F: FnOnce(RuleFixer<'_, 'a>) -> C,
+    {
+        self.diagnostic_with_fix_of_kind(diagnostic, FixKind::DangerousFix, fix);
+    }
+
+    fn diagnostic_with_fix_of_kind<C, F>(&self, diagnostic: OxcDiagnostic, kind: FixKind, fix: F)
+    where
+        C: Into<CompositeFix<'a>>,
+        F: FnOnce(RuleFixer<'_, 'a>) -> C,
+    {
+        if self.fix.contains(kind) {
             let fixer = RuleFixer::new(self);
-            self.add_diagnostic(Message::new(diagnostic, Some(fix(fixer))));
+            let fix = fix(fixer).into();
+            self.add_diagnostic(Message::new(diagnostic, Some(fix)).with_fix_kind(kind));
         } else {
             self.diagnostic(diagnostic);
         }
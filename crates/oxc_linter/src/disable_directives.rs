@@ -1,14 +1,43 @@
-use oxc_ast::Trivias;
+use std::cell::RefCell;
+
+use itertools::Itertools;
+use oxc_ast::{CommentKind, Trivias};
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::Span;
 use rust_lapper::{Interval, Lapper};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    fixer::{Fix, FixKind, Message},
+    AllowWarnDeny,
+};
+
+fn unused_disable_directive_diagnostic(
+    prefix: &str,
+    rules: Option<&str>,
+    span0: Span,
+) -> OxcDiagnostic {
+    let message = rules.map_or_else(
+        || format!("Unused {prefix} directive (no problems were reported)."),
+        |rules| format!("Unused {prefix} directive (no problems were reported from {rules})."),
+    );
+    OxcDiagnostic::warn(message).with_label(span0)
+}
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 enum DisabledRule<'a> {
     All,
     Single(&'a str),
 }
 
+/// A rule disabled by a directive comment
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct DisabledInterval<'a> {
+    rule: DisabledRule<'a>,
+    /// Index of the directive comment disabling the rule
+    directive: usize,
+}
+
 /// A comment which disables one or more specific rules
 pub struct DisableRuleComment<'a> {
     /// Span of the comment
@@ -17,24 +46,47 @@ pub struct DisableRuleComment<'a> {
     pub rules: Vec<&'a str>,
 }
 
+/// A comment which disables all rules or a list of rules
+struct DirectiveComment<'a> {
+    /// Span of the comment, including `//` or `/*` and `*/`
+    span: Span,
+    /// `eslint-disable` or `oxlint-disable`
+    prefix: &'static str,
+    /// Rules disabled by the comment with their spans, empty if all rules are disabled
+    rules: Vec<(&'a str, Span)>,
+}
+
 pub struct DisableDirectives<'a> {
+    source_text: &'a str,
     /// All the disabled rules with their corresponding covering spans
-    intervals: Lapper<u32, DisabledRule<'a>>,
+    intervals: Lapper<u32, DisabledInterval<'a>>,
     /// Spans of comments that disable all rules
     disable_all_comments: Vec<Span>,
     /// All comments that disable one or more specific rules
     disable_rule_comments: Vec<DisableRuleComment<'a>>,
+    /// All directive comments, in source order
+    directives: Vec<DirectiveComment<'a>>,
+    /// Disabled rules of directive comments which suppressed a diagnostic
+    used: RefCell<FxHashSet<(usize, DisabledRule<'a>)>>,
 }
 
 impl<'a> DisableDirectives<'a> {
     pub fn contains(&self, rule_name: &'static str, start: u32) -> bool {
-        self.intervals.find(start, start + 1).any(|interval| {
-            interval.val == DisabledRule::All
+        let mut contains = false;
+        for interval in self.intervals.find(start, start + 1) {
+            let DisabledInterval { rule, directive } = interval.val;
+            let is_disabled = rule == DisabledRule::All
                 // Our rule name currently does not contain the prefix.
                 // For example, this will match `@typescript-eslint/no-var-requires` given
                 // our rule_name is `no-var-requires`.
-                || matches!(interval.val, DisabledRule::Single(name) if name.contains(rule_name))
-        })
+                || matches!(rule, DisabledRule::Single(name) if name.contains(rule_name));
+            if is_disabled {
+                // Keep looking for all the directives suppressing the diagnostic, so none of them is reported as unused.
+                self.used.borrow_mut().insert((directive, rule));
+                contains = true;
+            }
+        }
+        contains
     }
 
     pub fn disable_all_comments(&self) -> &Vec<Span> {
@@ -44,21 +96,101 @@ impl<'a> DisableDirectives<'a> {
     pub fn disable_rule_comments(&self) -> &Vec<DisableRuleComment<'a>> {
         &self.disable_rule_comments
     }
+
+    /// Report the directive comments which did not suppress any diagnostic.
+    ///
+    /// Must be called after all rules have run. The fix deletes the comment,
+    /// or removes the unused rules when some of its rules are used.
+    ///
+    /// The directives which may suppress diagnostics of `pending_rules`, reported later, are not reported.
+    pub fn unused_directive_messages(
+        &self,
+        severity: AllowWarnDeny,
+        fix_kind: FixKind,
+        pending_rules: &[&str],
+    ) -> Vec<Message<'a>> {
+        let used = self.used.borrow();
+        let is_used = |i: usize, name: &str| {
+            used.contains(&(i, DisabledRule::Single(name)))
+                || pending_rules.iter().any(|rule_name| name.contains(rule_name))
+        };
+        let mut messages = vec![];
+        for (i, directive) in self.directives.iter().enumerate() {
+            let (diagnostic, fix) = if directive.rules.is_empty() {
+                if used.contains(&(i, DisabledRule::All)) || !pending_rules.is_empty() {
+                    continue;
+                }
+                (
+                    unused_disable_directive_diagnostic(directive.prefix, None, directive.span),
+                    self.delete(directive),
+                )
+            } else {
+                let (unused, kept): (Vec<_>, Vec<_>) =
+                    directive.rules.iter().partition(|(name, _)| !is_used(i, name));
+                if unused.is_empty() {
+                    continue;
+                }
+                let rules = unused.iter().map(|(name, _)| format!("'{name}'")).join(", ");
+                let fix = if kept.is_empty() {
+                    self.delete(directive)
+                } else {
+                    let start = directive.rules[0].1.start;
+                    let end = directive.rules[directive.rules.len() - 1].1.end;
+                    let rules = kept.iter().map(|(name, _)| name).join(", ");
+                    Fix::new(rules, Span::new(start, end))
+                };
+                (
+                    unused_disable_directive_diagnostic(
+                        directive.prefix,
+                        Some(&rules),
+                        directive.span,
+                    ),
+                    fix,
+                )
+            };
+            let fix = fix_kind.contains(FixKind::SafeFix).then(|| fix.into());
+            let diagnostic = diagnostic.with_severity(severity.into());
+            messages.push(Message::new(diagnostic, fix));
+        }
+        messages
+    }
+
+    /// Delete a directive comment, along with its line when nothing else is on it.
+    #[allow(clippy::cast_possible_truncation)] // for `as u32`
+    fn delete(&self, directive: &DirectiveComment) -> Fix<'a> {
+        let bytes = self.source_text.as_bytes();
+        let (mut start, mut end) = (directive.span.start as usize, directive.span.end as usize);
+        let is_blank = |b: &u8| matches!(b, b' ' | b'\t' | b'\r');
+        let line_start = bytes[..start].iter().rposition(|b| !is_blank(b)).map_or(0, |i| i + 1);
+        let line_end =
+            bytes[end..].iter().position(|b| !is_blank(b)).map_or(bytes.len(), |i| end + i);
+        let starts_line = line_start == 0 || bytes[line_start - 1] == b'\n';
+        let ends_line = line_end == bytes.len() || bytes[line_end] == b'\n';
+        if ends_line {
+            start = line_start;
+            if starts_line {
+                end = (line_end + 1).min(bytes.len());
+            }
+        }
+        Fix::delete(Span::new(start as u32, end as u32))
+    }
 }
 
 pub struct DisableDirectivesBuilder<'a> {
     source_text: &'a str,
     trivias: Trivias,
     /// All the disabled rules with their corresponding covering spans
-    intervals: Lapper<u32, DisabledRule<'a>>,
-    /// Start of `eslint-disable` or `oxlint-disable`
-    disable_all_start: Option<u32>,
-    /// Start of `eslint-disable` or `oxlint-disable` rule_name`
-    disable_start_map: FxHashMap<&'a str, u32>,
+    intervals: Lapper<u32, DisabledInterval<'a>>,
+    /// Start of `eslint-disable` or `oxlint-disable`, with the index of each directive comment
+    disable_all_starts: Vec<(u32, usize)>,
+    /// Start of `eslint-disable` or `oxlint-disable` rule_name`, with the index of each directive comment
+    disable_start_map: FxHashMap<&'a str, Vec<(u32, usize)>>,
     /// Spans of comments that disable all rules
     disable_all_comments: Vec<Span>,
     /// All comments that disable one or more specific rules
     disable_rule_comments: Vec<DisableRuleComment<'a>>,
+    /// All directive comments, in source order
+    directives: Vec<DirectiveComment<'a>>,
 }
 
 impl<'a> DisableDirectivesBuilder<'a> {
@@ -67,24 +199,55 @@ impl<'a> DisableDirectivesBuilder<'a> {
             source_text,
             trivias,
             intervals: Lapper::new(vec![]),
-            disable_all_start: None,
+            disable_all_starts: vec![],
             disable_start_map: FxHashMap::default(),
             disable_all_comments: vec![],
             disable_rule_comments: vec![],
+            directives: vec![],
         }
     }
 
     pub fn build(mut self) -> DisableDirectives<'a> {
         self.build_impl();
         DisableDirectives {
+            source_text: self.source_text,
             intervals: self.intervals,
             disable_all_comments: self.disable_all_comments,
             disable_rule_comments: self.disable_rule_comments,
+            directives: self.directives,
+            used: RefCell::default(),
         }
     }
 
-    fn add_interval(&mut self, start: u32, stop: u32, val: DisabledRule<'a>) {
-        self.intervals.insert(Interval { start, stop, val });
+    fn add_interval(&mut self, start: u32, stop: u32, rule: DisabledRule<'a>, directive: usize) {
+        self.intervals.insert(Interval { start, stop, val: DisabledInterval { rule, directive } });
+    }
+
+    /// Record a directive comment starting with `prefix` disabling `rules`, or all rules if empty.
+    /// Returns its index.
+    #[allow(clippy::cast_possible_truncation)] // for `as u32`
+    fn add_directive(
+        &mut self,
+        kind: CommentKind,
+        span: Span,
+        prefix: &'static str,
+        rules: &[&'a str],
+    ) -> usize {
+        let span = match kind {
+            CommentKind::SingleLine => Span::new(span.start - 2, span.end),
+            CommentKind::MultiLine => Span::new(span.start - 2, span.end + 2),
+        };
+        let rules = rules
+            .iter()
+            .filter(|rule_name| !rule_name.is_empty())
+            .map(|rule_name| {
+                // Rule names are slices of the source text.
+                let start = rule_name.as_ptr() as usize - self.source_text.as_ptr() as usize;
+                (*rule_name, Span::new(start as u32, (start + rule_name.len()) as u32))
+            })
+            .collect();
+        self.directives.push(DirectiveComment { span, prefix, rules });
+        self.directives.len() - 1
     }
 
     #[allow(clippy::cast_possible_truncation)] // for `as u32`
@@ -93,18 +256,18 @@ impl<'a> DisableDirectivesBuilder<'a> {
         // This algorithm iterates through the comments and builds all intervals
         // for matching disable and enable pairs.
         // Wrongly ordered matching pairs are not taken into consideration.
-        for (_, span) in self.trivias.clone().comments() {
+        for (kind, span) in self.trivias.clone().comments() {
             let text = span.source_text(self.source_text);
             let text = text.trim_start();
 
-            if let Some(text) =
-                text.strip_prefix("eslint-disable").or_else(|| text.strip_prefix("oxlint-disable"))
-            {
+            let disable = ["eslint-disable", "oxlint-disable"]
+                .into_iter()
+                .find_map(|prefix| Some((prefix, text.strip_prefix(prefix)?)));
+            if let Some((prefix, text)) = disable {
                 // `eslint-disable`
                 if text.trim().is_empty() {
-                    if self.disable_all_start.is_none() {
-                        self.disable_all_start = Some(span.end);
-                    }
+                    let directive = self.add_directive(kind, span, prefix, &[]);
+                    self.disable_all_starts.push((span.end, directive));
                     self.disable_all_comments.push(span);
                     continue;
                 }
@@ -117,15 +280,17 @@ impl<'a> DisableDirectivesBuilder<'a> {
                         .take(2)
                         .fold(span.end, |acc, line| acc + line.len() as u32);
                     if text.trim().is_empty() {
-                        self.add_interval(span.end, stop, DisabledRule::All);
+                        let directive = self.add_directive(kind, span, prefix, &[]);
+                        self.add_interval(span.end, stop, DisabledRule::All, directive);
                         self.disable_all_comments.push(span);
                     } else {
                         // `eslint-disable-next-line rule_name1, rule_name2`
-                        let mut rules = vec![];
-                        Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(span.end, stop, DisabledRule::Single(rule_name));
-                            rules.push(rule_name);
-                        });
+                        let rules = Self::get_rule_names(text);
+                        let directive = self.add_directive(kind, span, prefix, &rules);
+                        for &rule_name in &rules {
+                            let rule = DisabledRule::Single(rule_name);
+                            self.add_interval(span.end, stop, rule, directive);
+                        }
                         self.disable_rule_comments.push(DisableRuleComment { span, rules });
                     }
                     continue;
@@ -142,26 +307,31 @@ impl<'a> DisableDirectivesBuilder<'a> {
 
                     // `eslint-disable-line`
                     if text.trim().is_empty() {
-                        self.add_interval(start, stop, DisabledRule::All);
+                        let directive = self.add_directive(kind, span, prefix, &[]);
+                        self.add_interval(start, stop, DisabledRule::All, directive);
                         self.disable_all_comments.push(span);
                     } else {
                         // `eslint-disable-line rule-name1, rule-name2`
-                        let mut rules = vec![];
-                        Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(start, stop, DisabledRule::Single(rule_name));
-                            rules.push(rule_name);
-                        });
+                        let rules = Self::get_rule_names(text);
+                        let directive = self.add_directive(kind, span, prefix, &rules);
+                        for &rule_name in &rules {
+                            let rule = DisabledRule::Single(rule_name);
+                            self.add_interval(start, stop, rule, directive);
+                        }
                         self.disable_rule_comments.push(DisableRuleComment { span, rules });
                     }
                     continue;
                 }
 
                 // `eslint-disable rule-name1, rule-name2`
-                let mut rules = vec![];
-                Self::get_rule_names(text, |rule_name| {
-                    self.disable_start_map.entry(rule_name).or_insert(span.end);
-                    rules.push(rule_name);
-                });
+                let rules = Self::get_rule_names(text);
+                let directive = self.add_directive(kind, span, prefix, &rules);
+                for &rule_name in &rules {
+                    self.disable_start_map
+                        .entry(rule_name)
+                        .or_default()
+                        .push((span.end, directive));
+                }
                 self.disable_rule_comments.push(DisableRuleComment { span, rules });
 
                 continue;
@@ -172,37 +342,43 @@ impl<'a> DisableDirectivesBuilder<'a> {
             {
                 // `eslint-enable`
                 if text.trim().is_empty() {
-                    if let Some(start) = self.disable_all_start.take() {
-                        self.add_interval(start, span.start, DisabledRule::All);
+                    for (start, directive) in std::mem::take(&mut self.disable_all_starts) {
+                        self.add_interval(start, span.start, DisabledRule::All, directive);
                     }
                 } else {
                     // `eslint-enable rule-name1, rule-name2`
-                    Self::get_rule_names(text, |rule_name| {
-                        if let Some(start) = self.disable_start_map.remove(rule_name) {
-                            self.add_interval(start, span.start, DisabledRule::Single(rule_name));
+                    for rule_name in Self::get_rule_names(text) {
+                        for (start, directive) in
+                            self.disable_start_map.remove(rule_name).unwrap_or_default()
+                        {
+                            let rule = DisabledRule::Single(rule_name);
+                            self.add_interval(start, span.start, rule, directive);
                         }
-                    });
+                    }
                 }
                 continue;
             }
         }
 
         // Lone `eslint-disable`
-        if let Some(start) = self.disable_all_start {
-            self.add_interval(start, source_len, DisabledRule::All);
+        for (start, directive) in std::mem::take(&mut self.disable_all_starts) {
+            self.add_interval(start, source_len, DisabledRule::All, directive);
         }
 
         // Lone `eslint-disable rule_name`
         let disable_start_map = self.disable_start_map.drain().collect::<Vec<_>>();
-        for (rule_name, start) in disable_start_map {
-            self.add_interval(start, source_len, DisabledRule::Single(rule_name));
+        for (rule_name, starts) in disable_start_map {
+            for (start, directive) in starts {
+                self.add_interval(start, source_len, DisabledRule::Single(rule_name), directive);
+            }
         }
     }
 
-    fn get_rule_names<F: FnMut(&'a str)>(text: &'a str, cb: F) {
-        if let Some(text) = text.split_terminator("--").next() {
-            text.split(',').map(str::trim).for_each(cb);
-        }
+    fn get_rule_names(text: &'a str) -> Vec<&'a str> {
+        text.split_terminator("--")
+            .next()
+            .map(|text| text.split(',').map(str::trim).collect())
+            .unwrap_or_default()
     }
 }
 
@@ -389,3 +565,66 @@ fn test() {
         Tester::new("no-debugger", pass, fail).test();
     }
 }
+
+#[test]
+fn test_unused_directives() {
+    use std::{path::Path, rc::Rc};
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use crate::{fixer::Fixer, LintContext, LintOptions, Linter};
+
+    fn fix(source_text: &str) -> (Vec<String>, String) {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(source_text, source_type)
+            .with_cfg(true)
+            .with_trivias(ret.trivias)
+            .build(program)
+            .semantic;
+        let options = LintOptions::default()
+            .with_filter(vec![(AllowWarnDeny::Deny, "no-bitwise".into())])
+            .with_report_unused_directives(AllowWarnDeny::Warn)
+            .with_fix(FixKind::SafeFix);
+        let ctx = LintContext::new(Path::new("test.js").into(), Rc::new(semantic));
+        let messages = Linter::from_options(options).unwrap().run(ctx);
+        let errors = messages.iter().map(|message| message.error.message.clone()).collect();
+        (errors, Fixer::new(source_text, messages).fix().fixed_code.to_string())
+    }
+
+    for prefix in ["eslint", "oxlint"] {
+        let used = [
+            format!("// {prefix}-disable-next-line no-bitwise\nx = a | b;\n"),
+            format!("x = a | b; // {prefix}-disable-line\n"),
+            format!("/* {prefix}-disable no-bitwise */\nx = a | b;\n/* {prefix}-enable */\n"),
+            format!("/* {prefix}-disable */\nx = a | b;\n/* {prefix}-disable */\nx = a | b;\n"),
+        ];
+        for source_text in used {
+            assert_eq!(fix(&source_text), (vec![], source_text.clone()));
+        }
+
+        let unused = [
+            (
+                format!("// {prefix}-disable-next-line no-bitwise\nx = a + b;\n"),
+                "x = a + b;\n".into(),
+            ),
+            (format!("x = a + b; /* {prefix}-disable-line */\n"), "x = a + b;\n".into()),
+            (format!("  /* {prefix}-disable */\nx = a + b;"), "x = a + b;".into()),
+            (
+                format!("x = a | b; // {prefix}-disable-line no-console, no-bitwise -- reason\n"),
+                format!("x = a | b; // {prefix}-disable-line no-bitwise -- reason\n"),
+            ),
+        ];
+        for (source_text, expected) in unused {
+            let (errors, fixed_code) = fix(&source_text);
+            assert_eq!(errors.len(), 1, "{source_text}");
+            assert!(errors[0].starts_with(&format!("Unused {prefix}-disable directive")));
+            assert_eq!(fixed_code, expected, "{source_text}");
+        }
+    }
+}
@@ -1,11 +1,35 @@
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    hash::{Hash, Hasher},
+};
 
+use bitflags::bitflags;
+use oxc_allocator::Allocator;
 use oxc_codegen::Codegen;
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_diagnostics::{OxcDiagnostic, Replacement};
 use oxc_span::{GetSpan, Span};
+use rustc_hash::{FxHashSet, FxHasher};
 
 use crate::LintContext;
 
+bitflags! {
+    /// Kinds of automatic fixes.
+    ///
+    /// Each fix provided by a rule has exactly one kind,
+    /// while a combination of kinds selects the fixes to apply.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub struct FixKind: u8 {
+        const None         = 0;
+        /// A fix that does not change the behavior of the code, applied with `--fix`.
+        const SafeFix      = 1 << 0;
+        /// A suggested change which may change the behavior of the code,
+        /// applied with `--fix-suggestions`.
+        const Suggestion   = 1 << 1;
+        /// A fix which may change the behavior of the code, applied with `--fix-dangerously`.
+        const DangerousFix = 1 << 2;
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct Fix<'a> {
     pub content: Cow<'a, str>,
@@ -22,6 +46,50 @@ impl<'a> Fix<'a> {
     }
 }
 
+/// A fix made of one or more edits, which are applied all together or not at all.
+///
+/// Rules can return a `Vec<Fix>` from [`LintContext::diagnostic_with_fix`] to edit multiple
+/// disjoint places, e.g. to add an import and rewrite a call.
+#[derive(Debug, Clone, Default)]
+pub struct CompositeFix<'a> {
+    /// Edits sorted by their start position.
+    fixes: Vec<Fix<'a>>,
+}
+
+impl<'a> From<Fix<'a>> for CompositeFix<'a> {
+    fn from(fix: Fix<'a>) -> Self {
+        Self { fixes: vec![fix] }
+    }
+}
+
+impl<'a> From<Vec<Fix<'a>>> for CompositeFix<'a> {
+    fn from(mut fixes: Vec<Fix<'a>>) -> Self {
+        // stable sort to keep the order of insertions at the same position
+        fixes.sort_by_key(|fix| fix.span.start);
+        Self { fixes }
+    }
+}
+
+impl<'a> CompositeFix<'a> {
+    pub fn fixes(&self) -> &[Fix<'a>] {
+        &self.fixes
+    }
+
+    /// The span covering all edits.
+    pub fn span(&self) -> Span {
+        let start = self.fixes.iter().map(|fix| fix.span.start).min().unwrap_or_default();
+        let end = self.fixes.iter().map(|fix| fix.span.end).max().unwrap_or_default();
+        Span::new(start, end)
+    }
+
+    /// A fix can be applied if it has edits with valid ranges, which do not overlap each other.
+    fn is_valid(&self) -> bool {
+        !self.fixes.is_empty()
+            && self.fixes.iter().all(|fix| fix.span.start <= fix.span.end)
+            && self.fixes.windows(2).all(|pair| pair[0].span.end <= pair[1].span.start)
+    }
+}
+
 /// Inspired by ESLint's [`RuleFixer`].
 ///
 /// [`RuleFixer`]: https://github.com/eslint/eslint/blob/main/lib/linter/rule-fixer.js
@@ -62,14 +130,50 @@ impl<'c, 'a: 'c> RuleFixer<'c, 'a> {
         Fix::new(replacement, target)
     }
 
+    /// Insert `text` before the given [`Span`] or AST node.
+    pub fn insert_text_before<T: GetSpan, S: Into<Cow<'a, str>>>(
+        self,
+        target: &T,
+        text: S,
+    ) -> Fix<'a> {
+        self.insert_text_before_range(target.span(), text)
+    }
+
+    #[allow(clippy::unused_self)]
+    pub fn insert_text_before_range<S: Into<Cow<'a, str>>>(self, span: Span, text: S) -> Fix<'a> {
+        Fix::new(text, Span::new(span.start, span.start))
+    }
+
+    /// Insert `text` after the given [`Span`] or AST node.
+    pub fn insert_text_after<T: GetSpan, S: Into<Cow<'a, str>>>(
+        self,
+        target: &T,
+        text: S,
+    ) -> Fix<'a> {
+        self.insert_text_after_range(target.span(), text)
+    }
+
+    #[allow(clippy::unused_self)]
+    pub fn insert_text_after_range<S: Into<Cow<'a, str>>>(self, span: Span, text: S) -> Fix<'a> {
+        Fix::new(text, Span::new(span.end, span.end))
+    }
+
+    /// Combine multiple edits into a [`CompositeFix`], applied all together or not at all.
+    #[allow(clippy::unused_self)]
+    pub fn composite<I: IntoIterator<Item = Fix<'a>>>(self, fixes: I) -> CompositeFix<'a> {
+        CompositeFix::from(fixes.into_iter().collect::<Vec<_>>())
+    }
+
     #[allow(clippy::unused_self)]
     pub fn codegen(self) -> Codegen<'a, false> {
         Codegen::<false>::new()
     }
 }
 
+/// Maximum number of passes of [`Fixer::fix_iteratively`], same as ESLint.
+pub const MAX_FIX_PASSES: usize = 10;
+
 pub struct FixResult<'a> {
-    #[allow(unused)]
     pub fixed: bool,
     pub fixed_code: Cow<'a, str>,
     pub messages: Vec<Message<'a>>,
@@ -80,13 +184,15 @@ pub struct Message<'a> {
     pub error: OxcDiagnostic,
     pub start: u32,
     pub end: u32,
-    pub fix: Option<Fix<'a>>,
+    pub fix: Option<CompositeFix<'a>>,
+    /// Kind of `fix`, [`FixKind::None`] without a fix.
+    pub fix_kind: FixKind,
     fixed: bool,
 }
 
 impl<'a> Message<'a> {
     #[allow(clippy::cast_possible_truncation)] // for `as u32`
-    pub fn new(error: OxcDiagnostic, fix: Option<Fix<'a>>) -> Self {
+    pub fn new(error: OxcDiagnostic, fix: Option<CompositeFix<'a>>) -> Self {
         let (start, end) = if let Some(labels) = &error.labels {
             let start = labels
                 .iter()
@@ -100,7 +206,16 @@ impl<'a> Message<'a> {
         } else {
             (0, 0)
         };
-        Self { error, start, end, fix, fixed: false }
+        let fix_kind = if fix.is_some() { FixKind::SafeFix } else { FixKind::None };
+        Self { error, start, end, fix, fix_kind, fixed: false }
+    }
+
+    #[must_use]
+    pub fn with_fix_kind(mut self, fix_kind: FixKind) -> Self {
+        if self.fix.is_some() {
+            self.fix_kind = fix_kind;
+        }
+        self
     }
 
     pub fn start(&self) -> u32 {
@@ -110,6 +225,16 @@ impl<'a> Message<'a> {
     pub fn end(&self) -> u32 {
         self.end
     }
+
+    /// The diagnostic of this message, carrying its fix for reporters.
+    pub fn into_diagnostic(self) -> OxcDiagnostic {
+        match self.fix {
+            Some(fix) => self.error.with_fix(fix.fixes.into_iter().map(|fix| {
+                Replacement::new(fix.span.start as usize, fix.span.size() as usize, fix.content)
+            })),
+            None => self.error,
+        }
+    }
 }
 
 /// The fixer of the code.
@@ -135,35 +260,103 @@ impl<'a> Fixer<'a> {
             };
         }
 
-        self.messages.sort_by_key(|m| m.fix.as_ref().unwrap_or(&Fix::default()).span);
-        let mut fixed = false;
-        let mut output = String::with_capacity(source_text.len());
-        let mut last_pos: i64 = -1;
-        self.messages.iter_mut().filter(|m| m.fix.is_some()).for_each(|m| {
-            let Fix { content, span } = m.fix.as_ref().unwrap();
-            let start = span.start;
-            let end = span.end;
-            if start > end {
-                return;
+        self.messages
+            .sort_by_key(|m| m.fix.as_ref().map_or_else(Span::default, CompositeFix::span));
+
+        // Spans of the edits to apply, sorted and not overlapping each other.
+        let mut applied: Vec<Span> = vec![];
+        for m in &mut self.messages {
+            let Some(fix) = m.fix.as_ref() else {
+                continue;
+            };
+            if !fix.is_valid() {
+                continue;
             }
-            if i64::from(start) <= last_pos {
-                return;
+            // All edits of a fix are skipped if any of them overlaps with an applied edit,
+            // including edits that merely touch each other.
+            let overlaps = fix.fixes.iter().any(|fix| {
+                let i = applied.partition_point(|span| span.start <= fix.span.end);
+                i > 0 && applied[i - 1].end >= fix.span.start
+            });
+            if overlaps {
+                continue;
+            }
+            for fix in &fix.fixes {
+                let i = applied.partition_point(|span| span.start <= fix.span.start);
+                applied.insert(i, fix.span);
             }
-
             m.fixed = true;
-            fixed = true;
-            let offset = usize::try_from(last_pos.max(0)).ok().unwrap();
-            output.push_str(&source_text[offset..start as usize]);
-            output.push_str(content);
-            last_pos = i64::from(end);
-        });
+        }
 
-        let offset = usize::try_from(last_pos.max(0)).ok().unwrap();
-        output.push_str(&source_text[offset..]);
+        let mut fixes = self
+            .messages
+            .iter()
+            .filter(|m| m.fixed)
+            .flat_map(|m| m.fix.as_ref().unwrap().fixes.iter())
+            .collect::<Vec<_>>();
+        fixes.sort_by_key(|fix| fix.span.start);
+
+        let has_fixes = !fixes.is_empty();
+        let mut output = String::with_capacity(source_text.len());
+        let mut last_pos = 0;
+        for Fix { content, span } in fixes {
+            output.push_str(&source_text[last_pos..span.start as usize]);
+            output.push_str(content);
+            last_pos = span.end as usize;
+        }
+        output.push_str(&source_text[last_pos..]);
 
         let mut messages = self.messages.into_iter().filter(|m| !m.fixed).collect::<Vec<_>>();
         messages.sort_by_key(|m| (m.start, m.end));
-        FixResult { fixed, fixed_code: Cow::Owned(output), messages }
+        FixResult { fixed: has_fixes, fixed_code: Cow::Owned(output), messages }
+    }
+
+    /// Fix `source_text` in multiple passes, re-linting the fixed code with `lint` after each pass,
+    /// since fixes dropped for overlapping with another fix may apply to the new code.
+    ///
+    /// Stops when no more fixes apply, after [`MAX_FIX_PASSES`] passes, or when a pass produces
+    /// code seen in an earlier pass (oscillating fixes).
+    ///
+    /// Returns the fixed code and the diagnostics of linting it.
+    pub fn fix_iteratively<F>(source_text: &str, mut lint: F) -> (String, Vec<OxcDiagnostic>)
+    where
+        F: for<'b> FnMut(&'b Allocator, &'b str) -> Vec<Message<'b>>,
+    {
+        fn hash(source_text: &str) -> u64 {
+            let mut hasher = FxHasher::default();
+            source_text.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let mut source_text = source_text.to_string();
+        let mut seen = FxHashSet::from_iter([hash(&source_text)]);
+        let mut passes = 0;
+        let mut oscillating = false;
+        loop {
+            let allocator = Allocator::default();
+            let messages = lint(&allocator, &source_text);
+            if oscillating || passes == MAX_FIX_PASSES {
+                let errors = messages.into_iter().map(|m| m.error).collect();
+                return (source_text, errors);
+            }
+
+            let FixResult { fixed, fixed_code, messages } =
+                Fixer::new(&source_text, messages).fix();
+            if !fixed {
+                let errors = messages.into_iter().map(|m| m.error).collect();
+                return (source_text, errors);
+            }
+            let fixed_code = fixed_code.into_owned();
+            drop(messages);
+
+            passes += 1;
+            if seen.insert(hash(&fixed_code)) {
+                source_text = fixed_code;
+            } else {
+                // Lint once more without fixing to report the diagnostics of the current code.
+                oscillating = true;
+            }
+        }
     }
 }
 
@@ -174,7 +367,7 @@ mod test {
     use oxc_diagnostics::OxcDiagnostic;
     use oxc_span::Span;
 
-    use super::{Fix, FixResult, Fixer, Message};
+    use super::{CompositeFix, Fix, FixResult, Fixer, Message, MAX_FIX_PASSES};
 
     fn insert_at_end() -> OxcDiagnostic {
         OxcDiagnostic::warn("End")
@@ -245,7 +438,11 @@ mod test {
     }
 
     fn create_message(error: OxcDiagnostic, fix: Option<Fix>) -> Message {
-        Message::new(error, fix)
+        Message::new(error, fix.map(Into::into))
+    }
+
+    fn create_composite_message(error: OxcDiagnostic, fixes: Vec<Fix>) -> Message {
+        Message::new(error, Some(CompositeFix::from(fixes)))
     }
 
     #[test]
@@ -448,4 +645,82 @@ mod test {
         assert_eq!(result.messages[1].error.to_string(), "nofix2");
         assert!(result.fixed);
     }
+
+    #[test]
+    fn apply_composite_fix() {
+        let result = get_fix_result(vec![create_composite_message(
+            replace_var(),
+            vec![REPLACE_NUM, REPLACE_VAR],
+        )]);
+        assert_eq!(result.fixed_code, "let answer = 5 * 7;");
+        assert_eq!(result.messages.len(), 0);
+        assert!(result.fixed);
+    }
+
+    #[test]
+    fn skip_composite_fix_when_any_span_overlaps() {
+        let result = get_fix_result(vec![
+            create_composite_message(
+                remove_middle(Span::default()),
+                vec![REMOVE_MIDDLE, REPLACE_NUM],
+            ),
+            create_message(replace_id(), Some(REPLACE_ID)),
+        ]);
+        assert_eq!(result.fixed_code, TEST_CODE.replace("answer", "foo"));
+        assert_eq!(result.messages.len(), 1);
+        assert_eq!(result.messages[0].error.to_string(), "removemiddle");
+        assert!(result.fixed);
+    }
+
+    #[test]
+    fn ignore_composite_fix_with_overlapping_edits() {
+        let result = get_fix_result(vec![create_composite_message(
+            replace_id(),
+            vec![REPLACE_ID, REMOVE_MIDDLE],
+        )]);
+        assert_eq!(result.fixed_code, TEST_CODE);
+        assert_eq!(result.messages.len(), 1);
+        assert!(!result.fixed);
+    }
+
+    #[test]
+    fn fix_iteratively_overlapping() {
+        // Each pass can only apply the non-overlapping fixes.
+        let (fixed_code, errors) = Fixer::fix_iteratively("aaaa", |_, source_text| {
+            (0..source_text.len().saturating_sub(1))
+                .filter(|&i| &source_text[i..i + 2] == "aa")
+                .map(|i| {
+                    #[allow(clippy::cast_possible_truncation)]
+                    let span = Span::new(i as u32, i as u32 + 2);
+                    create_message(no_fix(span), Some(Fix::new("a", span)))
+                })
+                .collect()
+        });
+        assert_eq!(fixed_code, "a");
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn fix_iteratively_oscillating() {
+        let mut passes = 0;
+        let (fixed_code, errors) = Fixer::fix_iteratively("a", |_, source_text| {
+            passes += 1;
+            let content = if source_text == "a" { "b" } else { "a" };
+            vec![create_message(no_fix(Span::new(0, 1)), Some(Fix::new(content, Span::new(0, 1))))]
+        });
+        assert_eq!(passes, 3);
+        assert_eq!(fixed_code, "b");
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn fix_iteratively_max_passes() {
+        let (fixed_code, errors) = Fixer::fix_iteratively("", |_, source_text| {
+            #[allow(clippy::cast_possible_truncation)]
+            let end = source_text.len() as u32;
+            vec![create_message(insert_at_end(), Some(Fix::new("x", Span::new(end, end))))]
+        });
+        assert_eq!(fixed_code, "x".repeat(MAX_FIX_PASSES));
+        assert_eq!(errors.len(), 1);
+    }
 }
@@ -4,38 +4,59 @@
 mod tester;
 
 mod ast_util;
+mod cache;
 mod config;
 mod context;
 mod disable_directives;
 mod fixer;
 mod globals;
 mod javascript_globals;
+mod module_graph;
 mod options;
 mod rule;
 mod rules;
 mod service;
+mod timing;
 mod utils;
 
 pub mod partial_loader;
 pub mod table;
 
-use std::{io::Write, rc::Rc, sync::Arc};
+use std::{
+    env,
+    hash::{Hash, Hasher},
+    io::Write,
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
-use oxc_diagnostics::Error;
+use dashmap::DashMap;
+use once_cell::sync::OnceCell;
+use oxc_ast::AstType;
+use oxc_diagnostics::{Error, OxcDiagnostic, RuleMetadata, Severity};
 use oxc_semantic::AstNode;
+use rustc_hash::{FxHashSet, FxHasher};
+use serde_json::{json, Map, Value};
 
+use crate::{
+    cache::content_hash,
+    config::{OxlintEnv, OxlintGlobals, OxlintSettings},
+    fixer::{Fix, Fixer, Message},
+    rules::{RuleEnum, RULES},
+    table::RuleTable,
+};
 pub use crate::{
-    config::OxlintConfig,
+    cache::LintCache,
+    config::{Migration, OxlintConfig, PluginCoverage, ESLINTRC_FILES, OXLINTRC},
     context::LintContext,
+    fixer::FixKind,
+    module_graph::ModuleGraph,
     options::{AllowWarnDeny, LintOptions},
     rule::{RuleCategory, RuleMeta, RuleWithSeverity},
     service::{LintService, LintServiceOptions},
-};
-use crate::{
-    config::{OxlintEnv, OxlintGlobals, OxlintSettings},
-    fixer::{Fix, Fixer, Message},
-    rules::RuleEnum,
-    table::RuleTable,
+    timing::{FileTiming, LintTiming},
 };
 
 #[cfg(target_pointer_width = "64")]
@@ -53,6 +74,22 @@ pub struct Linter {
     rules: Vec<RuleWithSeverity>,
     options: LintOptions,
     eslint_config: Arc<OxlintConfig>,
+    cwd: PathBuf,
+    /// Rules and configuration for files matched by `overrides`,
+    /// keyed by the indices of the matching overrides.
+    overrides_cache: DashMap<Box<[usize]>, Arc<ResolvedOverrides>>,
+    /// Hash of the rules and configuration, see [`Linter::config_hash`].
+    config_hash: OnceCell<u64>,
+    /// Shared with the linters of nested configuration files.
+    timing: Option<Arc<LintTiming>>,
+}
+
+/// Rules and configuration after applying `overrides` for a file.
+struct ResolvedOverrides {
+    rules: Vec<RuleWithSeverity>,
+    eslint_config: Arc<OxlintConfig>,
+    /// Hash of the rules and configuration, see [`Linter::config_hash`].
+    config_hash: OnceCell<u64>,
 }
 
 impl Default for Linter {
@@ -67,25 +104,55 @@ impl Linter {
     /// Returns `Err` if there are any errors parsing the configuration file.
     pub fn from_options(options: LintOptions) -> Result<Self, Error> {
         let (rules, eslint_config) = options.derive_rules_and_config()?;
-        Ok(Self { rules, options, eslint_config: Arc::new(eslint_config) })
+        let timing = options.timing.then(Arc::default);
+        Ok(Self {
+            rules,
+            options,
+            eslint_config: Arc::new(eslint_config),
+            cwd: env::current_dir().unwrap_or_default(),
+            overrides_cache: DashMap::default(),
+            config_hash: OnceCell::new(),
+            timing,
+        })
+    }
+
+    /// Create a linter with the same options, using `eslint_config` instead of the configuration file.
+    /// Used for files with nested configuration files.
+    #[must_use]
+    pub fn with_nested_config(&self, eslint_config: OxlintConfig) -> Self {
+        let options = self.options.clone();
+        let rules = options.derive_rules(Some(&eslint_config));
+        Self {
+            rules,
+            options,
+            eslint_config: Arc::new(eslint_config),
+            cwd: self.cwd.clone(),
+            overrides_cache: DashMap::default(),
+            config_hash: OnceCell::new(),
+            timing: self.timing.clone(),
+        }
     }
 
     #[cfg(test)]
     #[must_use]
     pub fn with_rules(mut self, rules: Vec<RuleWithSeverity>) -> Self {
         self.rules = rules;
+        self.config_hash = OnceCell::new();
         self
     }
 
     #[must_use]
     pub fn with_eslint_config(mut self, eslint_config: OxlintConfig) -> Self {
         self.eslint_config = Arc::new(eslint_config);
+        self.overrides_cache.clear();
+        self.config_hash = OnceCell::new();
         self
     }
 
     #[must_use]
-    pub fn with_fix(mut self, yes: bool) -> Self {
-        self.options.fix = yes;
+    pub fn with_fix(mut self, kind: FixKind) -> Self {
+        self.options.fix = kind;
+        self.config_hash = OnceCell::new();
         self
     }
 
@@ -97,35 +164,232 @@ impl Linter {
         self.rules.len()
     }
 
+    /// Time spent in each rule and on each file, measured with [`LintOptions::timing`].
+    pub fn timing(&self) -> Option<&LintTiming> {
+        self.timing.as_deref()
+    }
+
+    /// Hash of the configuration linting `path`: the [resolved configuration](Self::resolved_config)
+    /// and the options changing the reported diagnostics.
+    /// Lint results are only reused from the cache for an identical hash.
+    pub(crate) fn config_hash(&self, path: &Path) -> u64 {
+        // `serde_json` objects are sorted by key, independently of the order of the configuration.
+        let hash_config = || content_hash(&self.resolved_config(path).to_string());
+        let resolved_config_hash = match self.resolve_overrides(path) {
+            Some(resolved) => *resolved.config_hash.get_or_init(hash_config),
+            None => *self.config_hash.get_or_init(hash_config),
+        };
+        let mut hasher = FxHasher::default();
+        resolved_config_hash.hash(&mut hasher);
+        self.options.fix.bits().hash(&mut hasher);
+        self.options.report_fixes.hash(&mut hasher);
+        self.options.report_unused_directives.as_str().hash(&mut hasher);
+        hasher.finish()
+    }
+
     pub fn run<'a>(&self, ctx: LintContext<'a>) -> Vec<Message<'a>> {
         let semantic = Rc::clone(ctx.semantic());
 
-        let ctx = ctx.with_fix(self.options.fix).with_eslint_config(&self.eslint_config);
-        let rules = self
-            .rules
+        let overrides = self.resolve_overrides(ctx.file_path());
+        let (rules, eslint_config) =
+            overrides.as_deref().map_or((&self.rules, &self.eslint_config), |resolved| {
+                (&resolved.rules, &resolved.eslint_config)
+            });
+
+        // Fixes are only reported, not applied, when not fixing.
+        let fix = if self.options.fix.is_empty() && self.options.report_fixes {
+            FixKind::all()
+        } else {
+            self.options.fix
+        };
+        let ctx = ctx.with_fix(fix).with_eslint_config(eslint_config);
+        let rules = rules
             .iter()
+            .filter(|rule| rule.should_run(&ctx))
             .map(|rule| {
                 (rule, ctx.clone().with_rule_name(rule.name()).with_severity(rule.severity))
             })
             .collect::<Vec<_>>();
 
-        for (rule, ctx) in &rules {
-            rule.run_once(ctx);
+        // Time spent in each rule, only measured with `LintOptions::timing`.
+        let mut durations = self.timing.as_ref().map(|_| vec![Duration::ZERO; rules.len()]);
+
+        for (i, (rule, ctx)) in rules.iter().enumerate() {
+            Self::timed(&mut durations, i, || rule.run_once(ctx));
         }
 
         for symbol in semantic.symbols().iter() {
-            for (rule, ctx) in &rules {
-                rule.run_on_symbol(symbol, ctx);
+            for (i, (rule, ctx)) in rules.iter().enumerate() {
+                Self::timed(&mut durations, i, || rule.run_on_symbol(symbol, ctx));
+            }
+        }
+
+        // Dispatch each node only to the rules interested in its type.
+        let mut rules_by_type = vec![vec![]; AstType::COUNT];
+        let mut rules_for_all_types = vec![];
+        for (i, (rule, ctx)) in rules.iter().enumerate() {
+            match rule.ast_types() {
+                Some(ast_types) => {
+                    for ty in ast_types {
+                        rules_by_type[*ty as usize].push((i, rule, ctx));
+                    }
+                }
+                None => rules_for_all_types.push((i, rule, ctx)),
             }
         }
 
         for node in semantic.nodes().iter() {
-            for (rule, ctx) in &rules {
-                rule.run(node, ctx);
+            for (i, rule, ctx) in &rules_by_type[node.kind().ty() as usize] {
+                Self::timed(&mut durations, *i, || rule.run(node, ctx));
+            }
+            for (i, rule, ctx) in &rules_for_all_types {
+                Self::timed(&mut durations, *i, || rule.run(node, ctx));
+            }
+        }
+
+        if let (Some(timing), Some(durations)) = (&self.timing, durations) {
+            for ((rule, _), duration) in rules.iter().zip(durations) {
+                timing.add_rule(rule.plugin_name(), rule.name(), duration);
             }
         }
 
-        rules.into_iter().flat_map(|(_, ctx)| ctx.into_message()).collect::<Vec<_>>()
+        // Diagnostics on the module graph are only known once all files are linted.
+        let module_graph_rules = rules
+            .iter()
+            .filter(|(rule, _)| rule.runs_on_module_graph())
+            .map(|(rule, _)| rule.name())
+            .collect::<Vec<_>>();
+        let mut messages =
+            rules.into_iter().flat_map(|(_, ctx)| ctx.into_message()).collect::<Vec<_>>();
+
+        if self.options.report_unused_directives.is_warn_deny() {
+            messages.extend(ctx.disable_directives().unused_directive_messages(
+                self.options.report_unused_directives,
+                fix,
+                &module_graph_rules,
+            ));
+        }
+
+        messages
+    }
+
+    /// Run the rules on the module graph built by the import plugin, after all files are linted.
+    ///
+    /// Only the diagnostics of `paths`, the linted files this linter applies to, are kept,
+    /// each reported by the rules applying to its file after `overrides`.
+    /// Returns them with the path of their file and the name of their rule,
+    /// for checking the disable directives of the file.
+    pub(crate) fn run_on_module_graph<'a>(
+        &self,
+        graph: &ModuleGraph<'a>,
+        paths: &[&'a Path],
+    ) -> Vec<(&'a Path, &'static str, OxcDiagnostic)> {
+        // The paths grouped by the overrides applying to them, which are cached and shared.
+        let mut groups = Vec::<(Option<Arc<ResolvedOverrides>>, FxHashSet<&Path>)>::new();
+        for path in paths {
+            let overrides = self.resolve_overrides(path);
+            let group = groups.iter_mut().find(|(resolved, _)| match (resolved, &overrides) {
+                (Some(resolved), Some(overrides)) => Arc::ptr_eq(resolved, overrides),
+                (None, None) => true,
+                _ => false,
+            });
+            match group {
+                Some((_, group_paths)) => {
+                    group_paths.insert(path);
+                }
+                None => groups.push((overrides, FxHashSet::from_iter([*path]))),
+            }
+        }
+
+        let mut diagnostics = vec![];
+        for (overrides, group_paths) in &groups {
+            let rules = overrides.as_deref().map_or(&self.rules, |resolved| &resolved.rules);
+            for rule in rules.iter().filter(|rule| rule.runs_on_module_graph()) {
+                let severity = Severity::from(rule.severity);
+                for (path, diagnostic) in rule.run_on_module_graph(graph) {
+                    let Some(path) =
+                        graph.linted_path(&path).filter(|path| group_paths.contains(path))
+                    else {
+                        continue;
+                    };
+                    diagnostics.push((path, rule.name(), diagnostic.with_severity(severity)));
+                }
+            }
+        }
+        diagnostics
+    }
+
+    /// The rules with their severity and options, and the env, globals and settings applying to `path`,
+    /// after `overrides`.
+    pub fn resolved_config(&self, path: &Path) -> Value {
+        let overrides = self.resolve_overrides(path);
+        let (rules, eslint_config) =
+            overrides.as_deref().map_or((&self.rules, &self.eslint_config), |resolved| {
+                (&resolved.rules, &resolved.eslint_config)
+            });
+        let rules = rules
+            .iter()
+            .map(|rule| {
+                let name = match rule.plugin_name() {
+                    "eslint" => rule.name().to_string(),
+                    plugin_name => format!("{plugin_name}/{}", rule.name()),
+                };
+                let options = eslint_config.rules.options(rule.plugin_name(), rule.name());
+                (name, json!({ "severity": rule.severity.as_str(), "options": options }))
+            })
+            .collect::<Map<_, _>>();
+        json!({
+            "rules": rules,
+            "env": eslint_config.env,
+            "globals": eslint_config.globals,
+            "settings": eslint_config.settings,
+        })
+    }
+
+    /// Run `f` for the rule at index `i`, adding its time to `durations` when measured.
+    #[inline]
+    fn timed<F: FnOnce()>(durations: &mut Option<Vec<Duration>>, i: usize, f: F) {
+        if let Some(durations) = durations {
+            let start = Instant::now();
+            f();
+            durations[i] += start.elapsed();
+        } else {
+            f();
+        }
+    }
+
+    /// Resolve the rules and configuration for `path` from the `overrides` matching it.
+    /// Returns `None` when no override applies, in which case the base rules and configuration are used.
+    fn resolve_overrides(&self, path: &Path) -> Option<Arc<ResolvedOverrides>> {
+        let overrides = &self.eslint_config.overrides;
+        if overrides.is_empty() {
+            return None;
+        }
+
+        let path = self.cwd.join(path);
+        let indices = overrides.matching(&path, &self.cwd).into_boxed_slice();
+        if indices.is_empty() {
+            return None;
+        }
+
+        if let Some(resolved) = self.overrides_cache.get(&indices) {
+            return Some(Arc::clone(resolved.value()));
+        }
+
+        let all_rules = self.options.get_filtered_rules();
+        let mut rules = self.rules.iter().cloned().collect::<FxHashSet<_>>();
+        for i in indices.iter() {
+            overrides[*i].rules.override_rules(&mut rules, &all_rules);
+        }
+        let mut rules = rules.into_iter().collect::<Vec<_>>();
+        // for stable diagnostics output ordering
+        rules.sort_unstable_by_key(|rule| rule.id());
+
+        let eslint_config = Arc::new(self.eslint_config.apply_overrides(&indices));
+        let resolved =
+            Arc::new(ResolvedOverrides { rules, eslint_config, config_hash: OnceCell::new() });
+        self.overrides_cache.insert(indices, Arc::clone(&resolved));
+        Some(resolved)
     }
 
     /// # Panics
@@ -137,11 +401,41 @@ impl Linter {
         writeln!(writer, "Default: {}", table.turned_on_by_default_count).unwrap();
         writeln!(writer, "Total: {}", table.total).unwrap();
     }
+
+    /// Metadata of all rules, for reporters describing the rules of the diagnostics.
+    pub fn rules_metadata() -> Vec<RuleMetadata> {
+        RULES
+            .iter()
+            .map(|rule| {
+                let plugin = rule.plugin_name();
+                // The prefix of the diagnostics of the rule, e.g. `eslint-plugin-react(jsx-key)`.
+                let prefix = match plugin {
+                    "eslint" | "oxc" => plugin.to_string(),
+                    "typescript" => "typescript-eslint".to_string(),
+                    "nextjs" => "eslint-plugin-next".to_string(),
+                    _ => format!("eslint-plugin-{}", plugin.replace('_', "-")),
+                };
+                RuleMetadata {
+                    id: format!("{prefix}({})", rule.name()),
+                    category: rule.category().to_string().to_lowercase(),
+                    help_uri: format!(
+                        "https://oxc.rs/docs/guide/usage/linter/rules/{plugin}/{}.html",
+                        rule.name()
+                    ),
+                    documentation: rule.documentation().map(ToString::to_string),
+                }
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use super::{Linter, OxlintConfig};
+    use std::{env, path::Path};
+
+    use serde_json::json;
+
+    use super::{FixKind, LintOptions, Linter, OxlintConfig};
 
     #[test]
     fn print_rules() {
@@ -150,6 +444,77 @@ mod test {
         assert!(!writer.is_empty());
     }
 
+    #[test]
+    fn resolved_config() {
+        let config_path = env::current_dir().unwrap().join("fixtures/eslint_config.json");
+        let linter =
+            Linter::from_options(LintOptions::default().with_config_path(Some(config_path)))
+                .unwrap();
+        let config = linter.resolved_config(Path::new("foo.js"));
+        let rules = &config["rules"];
+        assert_eq!(
+            rules["eqeqeq"],
+            json!({ "severity": "error", "options": ["always", { "null": "ignore" }] })
+        );
+        assert_eq!(rules["no-bitwise"]["options"], json!([{ "allow": ["~"] }]));
+        assert!(rules.get("no-console").is_none());
+        assert_eq!(rules["no-debugger"], json!({ "severity": "warn", "options": null }));
+        assert!(config["env"].is_object());
+        assert!(config["settings"]["jsdoc"].is_object());
+    }
+
+    #[test]
+    fn run_skips_rules_not_applying_to_file() {
+        use std::rc::Rc;
+
+        use oxc_allocator::Allocator;
+        use oxc_parser::Parser;
+        use oxc_semantic::SemanticBuilder;
+        use oxc_span::SourceType;
+
+        use crate::{AllowWarnDeny, LintContext};
+
+        // Rules which ran are timed.
+        let timed_rules = |source_text: &str| {
+            let allocator = Allocator::default();
+            let source_type = SourceType::default();
+            let program =
+                allocator.alloc(Parser::new(&allocator, source_text, source_type).parse().program);
+            let semantic = SemanticBuilder::new(source_text, source_type)
+                .with_cfg(true)
+                .build_module_record(Path::new("foo.test.js").to_path_buf(), program)
+                .build(program)
+                .semantic;
+            let options = LintOptions::default()
+                .with_filter(vec![(AllowWarnDeny::Deny, "no-identical-title".into())])
+                .with_jest_plugin(true)
+                .with_timing(true);
+            let linter = Linter::from_options(options).unwrap();
+            let ctx = LintContext::new(Path::new("foo.test.js").into(), Rc::new(semantic));
+            linter.run(ctx);
+            linter.timing().unwrap().rules().into_iter().map(|(rule, _)| rule).collect::<Vec<_>>()
+        };
+
+        assert!(timed_rules("foo();").is_empty());
+        assert_eq!(timed_rules("test('foo', () => {});"), vec!["jest/no-identical-title"]);
+    }
+
+    #[test]
+    fn config_hash() {
+        let config_path = env::current_dir().unwrap().join("fixtures/eslint_config.json");
+        let linter = |options: LintOptions| {
+            Linter::from_options(options.with_config_path(Some(config_path.clone()))).unwrap()
+        };
+        let path = Path::new("foo.js");
+        let hash = linter(LintOptions::default()).config_hash(path);
+        assert_eq!(hash, linter(LintOptions::default()).config_hash(path));
+        assert_eq!(hash, linter(LintOptions::default().with_timing(true)).config_hash(path));
+        assert_ne!(
+            hash,
+            linter(LintOptions::default().with_fix(FixKind::SafeFix)).config_hash(path)
+        );
+    }
+
     #[test]
     fn test_schema_json() {
         use std::fs;
@@ -0,0 +1,91 @@
+use std::{
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use oxc_semantic::ModuleRecord;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// Module records of the linted files and of the files they import, built by the import plugin,
+/// see [`Rule::run_on_module_graph`](crate::rule::Rule::run_on_module_graph).
+///
+/// Linted files are recorded with the path they are linted with, e.g. `./a.js`,
+/// and imported files with the absolute path they are resolved to,
+/// so modules are identified by their canonical absolute path, see [`ModuleGraph::path`].
+pub struct ModuleGraph<'a> {
+    /// One module record for each canonical path.
+    modules: Vec<Arc<ModuleRecord>>,
+    /// Canonical path of each `resolved_absolute_path` of the module records.
+    canonical_paths: FxHashMap<PathBuf, PathBuf>,
+    /// Path each file is linted with, keyed by its canonical path.
+    linted: FxHashMap<PathBuf, &'a Path>,
+    cwd: PathBuf,
+}
+
+impl<'a> ModuleGraph<'a> {
+    pub(crate) fn new(
+        modules: Vec<Arc<ModuleRecord>>,
+        paths: &'a FxHashSet<Box<Path>>,
+        cwd: &Path,
+    ) -> Self {
+        let cwd = canonicalize(cwd, Path::new(""));
+        let mut canonical_paths = FxHashMap::default();
+        let mut visited = FxHashSet::default();
+        let modules = modules
+            .into_iter()
+            .filter(|module| {
+                let path = &module.resolved_absolute_path;
+                let canonical_path = canonicalize(&cwd, path);
+                canonical_paths.insert(path.clone(), canonical_path.clone());
+                visited.insert(canonical_path)
+            })
+            .collect();
+        let linted = paths.iter().map(|path| (canonicalize(&cwd, path), &**path)).collect();
+        Self { modules, canonical_paths, linted, cwd }
+    }
+
+    pub fn modules(&self) -> &[Arc<ModuleRecord>] {
+        &self.modules
+    }
+
+    /// The canonical absolute path of `module`.
+    pub fn path<'m>(&'m self, module: &'m ModuleRecord) -> &'m Path {
+        let path = &module.resolved_absolute_path;
+        self.canonical_paths.get(path).unwrap_or(path)
+    }
+
+    /// Whether the module at the canonical `path` is linted, rather than only imported by linted files.
+    pub fn is_linted(&self, path: &Path) -> bool {
+        self.linted.contains_key(path)
+    }
+
+    /// The path the file at the canonical `path` is linted with.
+    pub(crate) fn linted_path(&self, path: &Path) -> Option<&'a Path> {
+        self.linted.get(path).copied()
+    }
+
+    /// The canonical directory paths are linted from, which glob patterns of options are relative to.
+    pub fn cwd(&self) -> &Path {
+        &self.cwd
+    }
+}
+
+/// `path` joined to `cwd`, with symbolic links resolved,
+/// or only without `.` and `..` components if it does not exist, e.g. when read from stdin.
+fn canonicalize(cwd: &Path, path: &Path) -> PathBuf {
+    let path = cwd.join(path);
+    if let Ok(path) = path.canonicalize() {
+        return path;
+    }
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
@@ -1,21 +1,28 @@
-use std::{convert::From, path::PathBuf};
+use std::{convert::From, path::PathBuf, str::FromStr};
 
 use oxc_diagnostics::{Error, OxcDiagnostic, Severity};
 use rustc_hash::FxHashSet;
 use serde_json::{Number, Value};
 
 use crate::{
-    config::OxlintConfig, rules::RULES, utils::is_jest_rule_adapted_to_vitest, RuleCategory,
-    RuleEnum, RuleWithSeverity,
+    config::OxlintConfig, fixer::FixKind, rules::RULES, utils::is_jest_rule_adapted_to_vitest,
+    RuleCategory, RuleEnum, RuleWithSeverity,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct LintOptions {
     /// Allow / Deny rules in order. [("allow" / "deny", rule name)]
     /// Defaults to [("deny", "correctness")]
     pub filter: Vec<(AllowWarnDeny, String)>,
     pub config_path: Option<PathBuf>,
-    pub fix: bool,
+    /// Kinds of fixes to apply.
+    pub fix: FixKind,
+    /// Attach the fixes of all kinds to the reported diagnostics when not fixing, e.g. for SARIF output.
+    pub report_fixes: bool,
+    /// Measure the time spent in each rule and on each file, see [`Linter::timing`](crate::Linter::timing).
+    pub timing: bool,
+    /// Severity of unused disable directives, not reported when `Allow`.
+    pub report_unused_directives: AllowWarnDeny,
 
     pub react_plugin: bool,
     pub unicorn_plugin: bool,
@@ -35,7 +42,10 @@ impl Default for LintOptions {
         Self {
             filter: vec![(AllowWarnDeny::Warn, String::from("correctness"))],
             config_path: None,
-            fix: false,
+            fix: FixKind::None,
+            report_fixes: false,
+            timing: false,
+            report_unused_directives: AllowWarnDeny::Allow,
             react_plugin: true,
             unicorn_plugin: true,
             typescript_plugin: true,
@@ -67,8 +77,26 @@ impl LintOptions {
     }
 
     #[must_use]
-    pub fn with_fix(mut self, yes: bool) -> Self {
-        self.fix = yes;
+    pub fn with_fix(mut self, kind: FixKind) -> Self {
+        self.fix = kind;
+        self
+    }
+
+    #[must_use]
+    pub fn with_report_fixes(mut self, yes: bool) -> Self {
+        self.report_fixes = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_timing(mut self, yes: bool) -> Self {
+        self.timing = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_report_unused_directives(mut self, severity: AllowWarnDeny) -> Self {
+        self.report_unused_directives = severity;
         self
     }
 
@@ -154,6 +182,15 @@ impl AllowWarnDeny {
     pub fn is_allow(self) -> bool {
         self == Self::Allow
     }
+
+    /// The severity as written in configuration files.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Allow => "off",
+            Self::Warn => "warn",
+            Self::Deny => "error",
+        }
+    }
 }
 
 impl TryFrom<&str> for AllowWarnDeny {
@@ -171,6 +208,14 @@ impl TryFrom<&str> for AllowWarnDeny {
     }
 }
 
+impl FromStr for AllowWarnDeny {
+    type Err = OxcDiagnostic;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
 impl TryFrom<&Value> for AllowWarnDeny {
     type Error = OxcDiagnostic;
 
@@ -217,7 +262,12 @@ impl LintOptions {
     pub fn derive_rules_and_config(&self) -> Result<(Vec<RuleWithSeverity>, OxlintConfig), Error> {
         let config =
             self.config_path.as_ref().map(|path| OxlintConfig::from_file(path)).transpose()?;
+        let rules = self.derive_rules(config.as_ref());
+        Ok((rules, config.unwrap_or_default()))
+    }
 
+    /// Rules enabled by the filters, then overridden by the rules of `config`.
+    pub(crate) fn derive_rules(&self, config: Option<&OxlintConfig>) -> Vec<RuleWithSeverity> {
         let mut rules: FxHashSet<RuleWithSeverity> = FxHashSet::default();
         let all_rules = self.get_filtered_rules();
 
@@ -266,7 +316,7 @@ impl LintOptions {
             }
         }
 
-        if let Some(config) = &config {
+        if let Some(config) = config {
             config.override_rules(&mut rules, &all_rules);
         }
 
@@ -275,11 +325,11 @@ impl LintOptions {
         // for stable diagnostics output ordering
         rules.sort_unstable_by_key(|rule| rule.id());
 
-        Ok((rules, config.unwrap_or_default()))
+        rules
     }
 
     /// Get final filtered rules by reading `self.xxx_plugin`
-    fn get_filtered_rules(&self) -> Vec<RuleEnum> {
+    pub(crate) fn get_filtered_rules(&self) -> Vec<RuleEnum> {
         RULES
             .iter()
             .filter(|rule| match rule.plugin_name() {
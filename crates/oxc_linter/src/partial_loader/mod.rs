@@ -39,6 +39,24 @@ impl PartialLoader {
             _ => None,
         }
     }
+
+    /// Replace each javascript source of `source_text` with its fixed code.
+    ///
+    /// `sources` must have been parsed from `source_text` and must not overlap.
+    pub fn splice(source_text: &str, sources: &[(JavaScriptSource<'_>, String)]) -> String {
+        let mut sources = sources.iter().collect::<Vec<_>>();
+        sources.sort_unstable_by_key(|(source, _)| source.start);
+
+        let mut output = String::with_capacity(source_text.len());
+        let mut last_pos = 0;
+        for (source, fixed_code) in sources {
+            output.push_str(&source_text[last_pos..source.start]);
+            output.push_str(fixed_code);
+            last_pos = source.start + source.source_text.len();
+        }
+        output.push_str(&source_text[last_pos..]);
+        output
+    }
 }
 
 /// Find closing angle for situations where there is another `>` in between.
@@ -61,3 +79,33 @@ fn find_script_closing_angle(source_text: &str, pointer: usize) -> Option<usize>
     }
     None
 }
+
+#[cfg(test)]
+mod test {
+    use super::PartialLoader;
+
+    #[test]
+    fn test_splice_multiple_scripts() {
+        let source_text = r"
+        <script>let a = 1;</script>
+        <template><div></div></template>
+        <script setup>let b = 2;</script>
+        ";
+
+        let sources = PartialLoader::parse("vue", source_text).unwrap();
+        assert_eq!(sources.len(), 2);
+        let fixed = vec![
+            (sources[1], "const b = 2;".to_string()),
+            (sources[0], "const a = 1; const c = 3;".to_string()),
+        ];
+
+        assert_eq!(
+            PartialLoader::splice(source_text, &fixed),
+            r"
+        <script>const a = 1; const c = 3;</script>
+        <template><div></div></template>
+        <script setup>const b = 2;</script>
+        "
+        );
+    }
+}
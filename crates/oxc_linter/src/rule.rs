@@ -2,11 +2,15 @@ use std::{
     fmt,
     hash::{Hash, Hasher},
     ops::Deref,
+    path::PathBuf,
 };
 
+use oxc_ast::AstType;
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_semantic::SymbolId;
+use schemars::{gen::SchemaGenerator, schema::Schema};
 
-use crate::{context::LintContext, AllowWarnDeny, AstNode, RuleEnum};
+use crate::{context::LintContext, AllowWarnDeny, AstNode, ModuleGraph, RuleEnum};
 
 pub trait Rule: Sized + Default + fmt::Debug {
     /// Initialize from eslint json configuration
@@ -14,6 +18,12 @@ pub trait Rule: Sized + Default + fmt::Debug {
         Self::default()
     }
 
+    /// Whether this rule applies to the file of `ctx` at all, e.g. based on its path,
+    /// source type or settings. Called once per file, before any other method.
+    fn should_run(&self, _ctx: &LintContext) -> bool {
+        true
+    }
+
     /// Visit each AST Node
     fn run<'a>(&self, _node: &AstNode<'a>, _ctx: &LintContext<'a>) {}
 
@@ -22,6 +32,31 @@ pub trait Rule: Sized + Default + fmt::Debug {
 
     /// Run only once. Useful for inspecting scopes and trivias etc.
     fn run_once(&self, _ctx: &LintContext) {}
+
+    /// Whether [`Rule::run_on_module_graph`] may report diagnostics with the options of the rule.
+    /// The disable directives naming the rule are then not reported as unused when linting each file.
+    fn runs_on_module_graph(&self) -> bool {
+        false
+    }
+
+    /// Run once after all files are linted, on the modules imported by each other.
+    /// Only called with the import plugin enabled.
+    ///
+    /// Returns the diagnostics with the canonical path of their file, see [`ModuleGraph::path`].
+    /// Only the diagnostics of the linted files the rule applies to, after `overrides`
+    /// and nested configuration files, are reported.
+    fn run_on_module_graph(&self, _graph: &ModuleGraph) -> Vec<(PathBuf, OxcDiagnostic)> {
+        vec![]
+    }
+
+    /// JSON schemas of the options following the severity in the configuration,
+    /// e.g. `"always"` and `{ "null": "ignore" }` for `["error", "always", { "null": "ignore" }]`.
+    ///
+    /// Options are validated against them when loading the configuration,
+    /// and `None` skips the validation.
+    fn options_schema(_gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        None
+    }
 }
 
 pub trait RuleMeta {
@@ -29,6 +64,9 @@ pub trait RuleMeta {
 
     const CATEGORY: RuleCategory;
 
+    /// Types of the AST nodes visited by [`Rule::run`], or `None` to visit every node.
+    const AST_TYPES: Option<&'static [AstType]> = None;
+
     fn documentation() -> Option<&'static str> {
         None
     }
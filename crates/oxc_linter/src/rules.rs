@@ -9,6 +9,7 @@
 mod import {
     pub mod default;
     pub mod export;
+    pub mod extensions;
     pub mod named;
     pub mod namespace;
     pub mod no_amd;
@@ -19,11 +20,14 @@ mod import {
     pub mod no_named_as_default;
     pub mod no_named_as_default_member;
     pub mod no_self_import;
-    // pub mod no_unused_modules;
+    pub mod no_unresolved;
+    pub mod no_unused_modules;
+    pub mod order;
 }
 
 mod eslint {
     pub mod array_callback_return;
+    pub mod complexity;
     pub mod constructor_super;
     pub mod default_case;
     pub mod default_case_last;
@@ -33,8 +37,11 @@ mod eslint {
     pub mod getter_return;
     pub mod guard_for_in;
     pub mod max_classes_per_file;
+    pub mod max_depth;
     pub mod max_lines;
+    pub mod max_nested_callbacks;
     pub mod max_params;
+    pub mod max_statements;
     pub mod no_array_constructor;
     pub mod no_async_promise_executor;
     pub mod no_await_in_loop;
@@ -89,6 +96,7 @@ mod eslint {
     pub mod no_self_assign;
     pub mod no_self_compare;
     pub mod no_setter_return;
+    pub mod no_shadow;
     pub mod no_shadow_restricted_names;
     pub mod no_sparse_arrays;
     pub mod no_template_curly_in_string;
@@ -101,6 +109,8 @@ mod eslint {
     pub mod no_unsafe_optional_chaining;
     pub mod no_unused_labels;
     pub mod no_unused_private_class_members;
+    pub mod no_unused_vars;
+    pub mod no_use_before_define;
     pub mod no_useless_catch;
     pub mod no_useless_concat;
     pub mod no_useless_constructor;
@@ -109,6 +119,7 @@ mod eslint {
     pub mod no_var;
     pub mod no_void;
     pub mod no_with;
+    pub mod prefer_const;
     pub mod prefer_exponentiation_operator;
     pub mod radix;
     pub mod require_await;
@@ -202,6 +213,7 @@ mod jest {
 mod react {
     pub mod button_has_type;
     pub mod checked_requires_onchange_or_readonly;
+    pub mod exhaustive_deps;
     pub mod jsx_key;
     pub mod jsx_no_comment_textnodes;
     pub mod jsx_no_duplicate_props;
@@ -352,6 +364,7 @@ mod oxc {
     pub mod bad_min_max_func;
     pub mod bad_object_literal_comparison;
     pub mod bad_replace_all_arg;
+    pub mod cognitive_complexity;
     pub mod const_comparisons;
     pub mod double_comparisons;
     pub mod erasing_op;
@@ -419,6 +432,7 @@ mod tree_shaking {
 
 oxc_macros::declare_all_lint_rules! {
     eslint::array_callback_return,
+    eslint::complexity,
     eslint::constructor_super,
     eslint::default_case,
     eslint::default_case_last,
@@ -428,8 +442,11 @@ oxc_macros::declare_all_lint_rules! {
     eslint::getter_return,
     eslint::guard_for_in,
     eslint::max_classes_per_file,
+    eslint::max_depth,
     eslint::max_lines,
+    eslint::max_nested_callbacks,
     eslint::max_params,
+    eslint::max_statements,
     eslint::no_ternary,
     eslint::no_this_before_super,
     eslint::no_template_curly_in_string,
@@ -484,6 +501,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_self_assign,
     eslint::no_self_compare,
     eslint::no_setter_return,
+    eslint::no_shadow,
     eslint::no_shadow_restricted_names,
     eslint::no_sparse_arrays,
     eslint::no_undef,
@@ -493,6 +511,8 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_unsafe_optional_chaining,
     eslint::no_unused_labels,
     eslint::no_unused_private_class_members,
+    eslint::no_unused_vars,
+    eslint::no_use_before_define,
     eslint::no_useless_catch,
     eslint::no_useless_escape,
     eslint::no_useless_rename,
@@ -511,6 +531,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_await_in_loop,
     eslint::no_new_native_nonconstructor,
     eslint::no_restricted_globals,
+    eslint::prefer_const,
     eslint::prefer_exponentiation_operator,
     eslint::no_constructor_return,
     typescript::adjacent_overload_signatures,
@@ -669,6 +690,7 @@ oxc_macros::declare_all_lint_rules! {
     unicorn::throw_new_error,
     react::button_has_type,
     react::checked_requires_onchange_or_readonly,
+    react::exhaustive_deps,
     react::jsx_no_target_blank,
     react::jsx_key,
     react::jsx_no_comment_textnodes,
@@ -702,9 +724,12 @@ oxc_macros::declare_all_lint_rules! {
     import::no_named_as_default,
     import::no_named_as_default_member,
     import::no_self_import,
-    // import::no_unused_modules,
+    import::no_unused_modules,
     import::no_duplicates,
     import::no_default_export,
+    import::no_unresolved,
+    import::extensions,
+    import::order,
     jsx_a11y::alt_text,
     jsx_a11y::anchor_has_content,
     jsx_a11y::anchor_is_valid,
@@ -739,6 +764,7 @@ oxc_macros::declare_all_lint_rules! {
     oxc::bad_min_max_func,
     oxc::bad_object_literal_comparison,
     oxc::bad_replace_all_arg,
+    oxc::cognitive_complexity,
     oxc::const_comparisons,
     oxc::double_comparisons,
     oxc::erasing_op,
@@ -0,0 +1,234 @@
+use oxc_ast::AstKind;
+use oxc_cfg::{
+    graph::{visit::EdgeRef, Direction},
+    BasicBlockId, ControlFlowGraph, EdgeType, ErrorEdgeKind, InstructionKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use rustc_hash::FxHashSet;
+use schemars::{gen::SchemaGenerator, schema::Schema};
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_function_name_with_kind, rule_option, MaxOption},
+    AstNode,
+};
+
+fn complexity_diagnostic(name: &str, complexity: usize, max: usize, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint(complexity): {name} has a complexity of {complexity}. Maximum allowed is {max}."
+    ))
+    .with_help("Reduce the number of branches in this function, e.g. by extracting some of them into separate functions.")
+    .with_labels([span.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Complexity(Box<ComplexityConfig>);
+
+#[derive(Debug, Clone)]
+pub struct ComplexityConfig {
+    max: usize,
+}
+
+impl std::ops::Deref for Complexity {
+    type Target = ComplexityConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for ComplexityConfig {
+    fn default() -> Self {
+        Self { max: 20 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforce a maximum cyclomatic complexity allowed in a function
+    ///
+    /// ### Why is this bad?
+    /// Cyclomatic complexity measures the number of linearly independent paths through a function.
+    /// Functions with a high complexity are hard to understand and to test exhaustively.
+    ///
+    /// The complexity is computed from the control flow graph of the function: it starts at 1,
+    /// and every branch adds 1. Branches are the conditions of `if` statements, conditional
+    /// expressions, loops and `case` clauses, the iterations of `for...in` and `for...of` loops,
+    /// `catch` clauses and the short-circuiting of logical operators and logical assignments.
+    /// Nested functions are measured on their own.
+    ///
+    /// ### Options
+    /// The maximum complexity, `20` by default, given as a number or as `{ "max": 20 }`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // with `2`
+    /// function foo(a, b) {
+    ///     if (a) {
+    ///         return 1;
+    ///     } else if (b) {
+    ///         return 2;
+    ///     }
+    ///     return 3;
+    /// }
+    /// ```
+    Complexity,
+    pedantic,
+    ast_types = [Function, ArrowFunctionExpression]
+);
+
+impl Rule for Complexity {
+    fn from_configuration(value: Value) -> Self {
+        let max =
+            rule_option::<Option<MaxOption>>(&value, 0).and_then(MaxOption::max).unwrap_or(20);
+
+        Self(Box::new(ComplexityConfig { max }))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<MaxOption>()])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let span = match node.kind() {
+            AstKind::Function(func) if func.body.is_some() => func.span,
+            AstKind::ArrowFunctionExpression(arrow) => arrow.span,
+            _ => return,
+        };
+
+        let complexity = cyclomatic_complexity(ctx.cfg(), node.cfg_id());
+        if complexity > self.max {
+            ctx.diagnostic(complexity_diagnostic(
+                &get_function_name_with_kind(node, ctx),
+                complexity,
+                self.max,
+                span,
+            ));
+        }
+    }
+}
+
+/// Computes the cyclomatic complexity of the function starting at `function_block`, by walking
+/// every basic block of the function without entering nested functions.
+fn cyclomatic_complexity(cfg: &ControlFlowGraph, function_block: BasicBlockId) -> usize {
+    let graph = cfg.graph();
+    let mut visited = FxHashSet::default();
+    let mut catch_handlers = FxHashSet::default();
+    let mut stack = vec![function_block];
+    let mut complexity = 1;
+
+    while let Some(block_id) = stack.pop() {
+        if !visited.insert(block_id) {
+            continue;
+        }
+
+        complexity += cfg
+            .basic_block(block_id)
+            .instructions()
+            .iter()
+            .filter(|it| {
+                matches!(it.kind, InstructionKind::Condition | InstructionKind::Iteration(_))
+            })
+            .count();
+
+        let mut normal_successors = 0;
+        let mut branches_to_condition = false;
+        for edge in graph.edges_directed(block_id, Direction::Outgoing) {
+            match edge.weight() {
+                EdgeType::NewFunction => continue,
+                EdgeType::Error(ErrorEdgeKind::Explicit) => {
+                    catch_handlers.insert(edge.target());
+                }
+                EdgeType::Normal => {
+                    normal_successors += 1;
+                    branches_to_condition |= has_condition(cfg, edge.target());
+                }
+                _ => {}
+            }
+            stack.push(edge.target());
+        }
+
+        // A block splitting into several normal paths without a condition of its own is the
+        // short-circuit of a logical operator. Splits into conditions, like the jump over an
+        // `if` statement without `else` or the dispatch of a `switch` statement to its `case`
+        // clauses, are already counted by those conditions.
+        if normal_successors > 1 && !branches_to_condition {
+            complexity += normal_successors - 1;
+        }
+    }
+
+    complexity + catch_handlers.len()
+}
+
+fn has_condition(cfg: &ControlFlowGraph, block_id: BasicBlockId) -> bool {
+    cfg.basic_block(block_id)
+        .instructions()
+        .iter()
+        .any(|it| matches!(it.kind, InstructionKind::Condition))
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function a(x) {}", None),
+        ("function b(x) {}", Some(json!([1]))),
+        ("function a(x) { if (true) { return x; } }", Some(json!([2]))),
+        ("function a(x) { if (true) { return x; } else { return x + 1; } }", Some(json!([2]))),
+        ("function a(x) { if (x === 4) { return x; } else if (x === 5) { return x + 1; } }", Some(json!([3]))),
+        ("function a(x) { for (var i = 0; i < 5; i++) { x++; } return x; }", Some(json!([2]))),
+        ("function a(obj) { for (var i in obj) { obj[i] = 3; } }", Some(json!([2]))),
+        ("function a(obj) { for (var x of obj) { x = 3; } }", Some(json!([2]))),
+        ("function a(x) { while (x < 5) { x++; } }", Some(json!([2]))),
+        ("function a(x) { do { x++; } while (x < 5); }", Some(json!([2]))),
+        ("function a(x) { return x === 4 ? 3 : 5; }", Some(json!([2]))),
+        ("function a(x) { return x || 4; }", Some(json!([2]))),
+        ("function a(x) { x ??= 4; }", Some(json!([2]))),
+        ("function a(x) { try { x(); } catch (e) { x = 1; } }", Some(json!([2]))),
+        ("function a(x) { try { x(); } finally { x = 1; } }", Some(json!([1]))),
+        ("function a(x) { switch (x) { case 1: return 1; case 2: return 2; default: return 3; } }", Some(json!([3]))),
+        ("function a(x) { for (var i = 0; i < 5; i++) { if (x) break; else continue; } }", Some(json!([3]))),
+        ("function a(x) { if (x) { return 1; } return 2; }", Some(json!([{ "max": 2 }]))),
+        ("function a(x) { if (x) { return 1; } return 2; }", Some(json!([{ "maximum": 2 }]))),
+        ("function a(x) { if (x) { function b() { if (x) {} } } }", Some(json!([2]))),
+        ("function a(x) { if (x) { return x.map((y) => y ? 1 : 2); } }", Some(json!([2]))),
+        ("var a = (x) => { if (x) {} }", Some(json!([2]))),
+        ("class A { method(x) { if (x) {} } }", Some(json!([2]))),
+        ("if (a || b) { c ? d : e }", Some(json!([1]))),
+    ];
+
+    let fail = vec![
+        ("function a(x) {}", Some(json!([0]))),
+        ("var func = function () {}", Some(json!([0]))),
+        ("var obj = { a(x) {} }", Some(json!([0]))),
+        ("class Test { a(x) {} }", Some(json!([0]))),
+        ("class Test { static #a(x) {} }", Some(json!([0]))),
+        ("var a = (x) => { if (true) { return x; } }", Some(json!([1]))),
+        ("function a(x) { if (true) { return x; } else if (x) { return x + 1; } }", Some(json!([2]))),
+        ("function a(x) { if (x && x > 1) { return x; } }", Some(json!([2]))),
+        ("function a(x) { for (var i = 0; i < 5; i++) { x++; } }", Some(json!([1]))),
+        ("function a(obj) { for (var i in obj) { obj[i] = 3; } }", Some(json!([1]))),
+        ("function a(obj) { for (var x of obj) { x = 3; } }", Some(json!([1]))),
+        ("function a(x) { while (x < 5) { x++; } }", Some(json!([1]))),
+        ("function a(x) { do { x++; } while (x < 5); }", Some(json!([1]))),
+        ("function a(x) { return x === 4 ? 3 : 5; }", Some(json!([1]))),
+        ("function a(x) { return x || x && 4; }", Some(json!([2]))),
+        ("function a(x) { x ||= 4; }", Some(json!([1]))),
+        ("function a(x) { try { x(); } catch (e) { x = 1; } }", Some(json!([1]))),
+        ("function a(x) { switch (x) { case 1: return 1; case 2: return 2; default: return 3; } }", Some(json!([2]))),
+        ("function a(x) { switch (x) { case 1: case 2: case 3: return 1; } }", Some(json!([3]))),
+        ("async function* a(x) { if (x) { yield x; } }", Some(json!([1]))),
+        ("function a(x) { function b() { if (x) {} } }", Some(json!([1]))),
+        ("function a(x) { if (x) {} }", Some(json!([{ "max": 1 }]))),
+        ("function a(x) { if (x) {} }", Some(json!([{ "maximum": 1 }]))),
+    ];
+
+    Tester::new(Complexity::NAME, pass, fail).test_and_snapshot();
+}
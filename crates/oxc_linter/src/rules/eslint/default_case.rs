@@ -3,8 +3,10 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use regex::{Regex, RegexBuilder};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
 
 fn default_case_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint(default-case): Require default cases in switch statements.")
@@ -28,6 +30,13 @@ impl std::ops::Deref for DefaultCase {
     }
 }
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+struct DefaultCaseOptions {
+    /// Regular expression of the comments allowing to omit `default`, `no default` by default.
+    comment_pattern: Option<String>,
+}
+
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -47,19 +56,19 @@ declare_oxc_lint!(
     /// ```
     DefaultCase,
     restriction,
+    ast_types = [SwitchStatement]
 );
 
 impl Rule for DefaultCase {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let mut cfg = DefaultCaseConfig::default();
-
-        if let Some(config) = value.get(0) {
-            if let Some(val) = config.get("commentPattern").and_then(serde_json::Value::as_str) {
-                cfg.comment_pattern = RegexBuilder::new(val).case_insensitive(true).build().ok();
-            }
-        }
+        let DefaultCaseOptions { comment_pattern } = rule_option(&value, 0);
+        let comment_pattern = comment_pattern
+            .and_then(|pattern| RegexBuilder::new(&pattern).case_insensitive(true).build().ok());
+        Self(Box::new(DefaultCaseConfig { comment_pattern }))
+    }
 
-        Self(Box::new(cfg))
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<DefaultCaseOptions>()])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -32,7 +32,8 @@ declare_oxc_lint!(
     /// createUser(undefined, "tabby")
     /// ```
     DefaultParamLast,
-    style
+    style,
+    ast_types = [Function, ArrowFunctionExpression]
 );
 
 impl Rule for DefaultParamLast {
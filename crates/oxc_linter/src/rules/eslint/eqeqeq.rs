@@ -6,8 +6,10 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
 
 fn eqeqeq_diagnostic(x0: &str, x1: &str, span2: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("eslint(eqeqeq): Expected {x1} and instead saw {x0}"))
@@ -35,25 +37,18 @@ declare_oxc_lint!(
     /// a == b
     /// ```
     Eqeqeq,
-    pedantic
+    pedantic,
+    ast_types = [BinaryExpression]
 );
 
 impl Rule for Eqeqeq {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj1 = value.get(0);
-        let obj2 = value.get(1);
+        let EqeqeqOptions { null: null_type } = rule_option(&value, 1);
+        Self { compare_type: rule_option(&value, 0), null_type }
+    }
 
-        Self {
-            compare_type: obj1
-                .and_then(serde_json::Value::as_str)
-                .map(CompareType::from)
-                .unwrap_or_default(),
-            null_type: obj2
-                .and_then(|v| v.get("null"))
-                .and_then(serde_json::Value::as_str)
-                .map(NullType::from)
-                .unwrap_or_default(),
-        }
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<CompareType>(), gen.subschema_for::<EqeqeqOptions>()])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -132,23 +127,18 @@ impl Rule for Eqeqeq {
     }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+#[schemars(rename = "EqeqeqCompareType")]
 enum CompareType {
     #[default]
     Always,
     Smart,
 }
 
-impl CompareType {
-    pub fn from(raw: &str) -> Self {
-        match raw {
-            "smart" => Self::Smart,
-            _ => Self::Always,
-        }
-    }
-}
-
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+#[schemars(rename = "EqeqeqNullType")]
 enum NullType {
     #[default]
     Always,
@@ -156,14 +146,12 @@ enum NullType {
     Ignore,
 }
 
-impl NullType {
-    pub fn from(raw: &str) -> Self {
-        match raw {
-            "always" => Self::Always,
-            "never" => Self::Never,
-            _ => Self::Ignore,
-        }
-    }
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default)]
+struct EqeqeqOptions {
+    /// Whether `null` is compared with `===` when `always` is set:
+    /// `always` (default), `never`, or `ignore` to allow both.
+    null: NullType,
 }
 
 fn to_strict_eq_operator_str(operator: BinaryOperator) -> (&'static str, &'static str) {
@@ -35,7 +35,8 @@ declare_oxc_lint!(
     /// for (var = 10; i >= 0; i++) {}
     /// ```
     ForDirection,
-    correctness
+    correctness,
+    ast_types = [ForStatement]
 );
 
 impl Rule for ForDirection {
@@ -15,8 +15,10 @@ use oxc_cfg::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
 
 fn getter_return_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint(getter-return): Expected to always return a value in getter.")
@@ -24,8 +26,10 @@ fn getter_return_diagnostic(span0: Span) -> OxcDiagnostic {
         .with_labels([span0.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct GetterReturn {
+    /// Allow returning `undefined` implicitly with `return;`.
     pub allow_implicit: bool,
 }
 
@@ -73,13 +77,11 @@ impl Rule for GetterReturn {
     }
 
     fn from_configuration(value: serde_json::Value) -> Self {
-        let allow_implicit = value
-            .get(0)
-            .and_then(|config| config.get("allowImplicit"))
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or(false);
+        rule_option(&value, 0)
+    }
 
-        Self { allow_implicit }
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<Self>()])
     }
 }
 
@@ -3,9 +3,10 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use oxc_syntax::class::ClassId;
-use serde_json::Value;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, utils::rule_option};
 
 fn max_classes_per_file_diagnostic(total: usize, max: usize, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!(
@@ -18,9 +19,12 @@ fn max_classes_per_file_diagnostic(total: usize, max: usize, span1: Span) -> Oxc
 #[derive(Debug, Default, Clone)]
 pub struct MaxClassesPerFile(Box<MaxClassesPerFileConfig>);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct MaxClassesPerFileConfig {
+    /// The maximum number of classes, `1` by default.
     pub max: usize,
+    /// Ignore class expressions.
     pub ignore_expressions: bool,
 }
 
@@ -38,6 +42,13 @@ impl Default for MaxClassesPerFileConfig {
     }
 }
 
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum MaxClassesPerFileOption {
+    Max(usize),
+    Object(MaxClassesPerFileConfig),
+}
+
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -55,32 +66,23 @@ declare_oxc_lint!(
     /// ```
     MaxClassesPerFile,
     pedantic,
+    ast_types = []
 );
 
 impl Rule for MaxClassesPerFile {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let config = value.get(0);
-        if let Some(max) = config
-            .and_then(Value::as_number)
-            .and_then(serde_json::Number::as_u64)
-            .and_then(|v| usize::try_from(v).ok())
-        {
-            Self(Box::new(MaxClassesPerFileConfig { max, ignore_expressions: false }))
-        } else {
-            let max = value
-                .get(0)
-                .and_then(|config| config.get("max"))
-                .and_then(serde_json::Value::as_number)
-                .and_then(serde_json::Number::as_u64)
-                .map_or(1, |v| usize::try_from(v).unwrap_or(1));
-
-            let ignore_expressions = value
-                .get(0)
-                .and_then(|config| config.get("ignoreExpressions"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false);
-            Self(Box::new(MaxClassesPerFileConfig { max, ignore_expressions }))
-        }
+        let config = match rule_option(&value, 0) {
+            Some(MaxClassesPerFileOption::Max(max)) => {
+                MaxClassesPerFileConfig { max, ..MaxClassesPerFileConfig::default() }
+            }
+            Some(MaxClassesPerFileOption::Object(config)) => config,
+            None => MaxClassesPerFileConfig::default(),
+        };
+        Self(Box::new(config))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<MaxClassesPerFileOption>()])
     }
 
     fn run_once(&self, ctx: &LintContext<'_>) {
@@ -0,0 +1,190 @@
+use oxc_ast::{ast::Statement, AstKind};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema};
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{rule_option, MaxOption},
+    AstNode,
+};
+
+fn max_depth_diagnostic(depth: usize, max: usize, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint(max-depth): Blocks are nested too deeply ({depth}). Maximum allowed is {max}."
+    ))
+    .with_help("Reduce the nesting, e.g. with early returns or by extracting the nested blocks into functions.")
+    .with_labels([span.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct MaxDepth(Box<MaxDepthConfig>);
+
+#[derive(Debug, Clone)]
+pub struct MaxDepthConfig {
+    max: usize,
+}
+
+impl std::ops::Deref for MaxDepth {
+    type Target = MaxDepthConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for MaxDepthConfig {
+    fn default() -> Self {
+        Self { max: 4 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforce a maximum depth that blocks can be nested
+    ///
+    /// ### Why is this bad?
+    /// Deeply nested blocks are hard to read, since every level adds a condition the reader
+    /// has to keep in mind.
+    ///
+    /// `if`, `switch`, `try`, `with` and loop statements add a level of nesting, except for
+    /// the `if` of an `else if`. Functions and class static blocks start again from zero.
+    ///
+    /// ### Options
+    /// The maximum depth, `4` by default, given as a number or as `{ "max": 4 }`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // with `2`
+    /// function foo() {
+    ///     for (;;) {
+    ///         while (true) {
+    ///             if (true) {}
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    MaxDepth,
+    pedantic,
+    ast_types = [
+        IfStatement,
+        SwitchStatement,
+        TryStatement,
+        DoWhileStatement,
+        WhileStatement,
+        WithStatement,
+        ForStatement,
+        ForInStatement,
+        ForOfStatement
+    ]
+);
+
+impl Rule for MaxDepth {
+    fn from_configuration(value: Value) -> Self {
+        let max = rule_option::<Option<MaxOption>>(&value, 0).and_then(MaxOption::max).unwrap_or(4);
+
+        Self(Box::new(MaxDepthConfig { max }))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<MaxOption>()])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if !is_nesting_statement(node, ctx) {
+            return;
+        }
+
+        let mut depth = 0;
+        for ancestor in ctx.nodes().iter_parents(node.id()) {
+            match ancestor.kind() {
+                AstKind::Function(_)
+                | AstKind::ArrowFunctionExpression(_)
+                | AstKind::StaticBlock(_)
+                | AstKind::Program(_) => break,
+                _ if is_nesting_statement(ancestor, ctx) => depth += 1,
+                _ => {}
+            }
+        }
+
+        if depth > self.max {
+            ctx.diagnostic(max_depth_diagnostic(depth, self.max, node.kind().span()));
+        }
+    }
+}
+
+fn is_nesting_statement(node: &AstNode, ctx: &LintContext) -> bool {
+    match node.kind() {
+        AstKind::IfStatement(stmt) => {
+            // The `if` of an `else if` doesn't nest any deeper than the first `if`.
+            !matches!(
+                ctx.nodes().parent_kind(node.id()),
+                Some(AstKind::IfStatement(parent)) if matches!(
+                    &parent.alternate,
+                    Some(Statement::IfStatement(alternate)) if alternate.span == stmt.span
+                )
+            )
+        }
+        AstKind::SwitchStatement(_)
+        | AstKind::TryStatement(_)
+        | AstKind::DoWhileStatement(_)
+        | AstKind::WhileStatement(_)
+        | AstKind::WithStatement(_)
+        | AstKind::ForStatement(_)
+        | AstKind::ForInStatement(_)
+        | AstKind::ForOfStatement(_) => true,
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function foo() { if (true) { if (false) { if (true) { } } } }", Some(json!([3]))),
+        ("function foo() { if (true) { } else if (false) { } else if (true) { } else if (false) {} }", Some(json!([3]))),
+        ("var foo = () => { if (true) { if (false) { if (true) { } } } }", Some(json!([3]))),
+        ("function foo() { if (true) { if (false) { if (true) { } } } }", None),
+        ("function foo() { if (true) { if (false) { if (true) { } } } }", Some(json!([{ "max": 3 }]))),
+        ("function foo() { if (true) { if (false) { if (true) { } } } }", Some(json!([{ "maximum": 3 }]))),
+        ("function foo() { if (true) { function bar() { if (true) {} } } }", Some(json!([1]))),
+        ("function foo() { while (true) { (() => { while (true) {} })(); } }", Some(json!([1]))),
+        ("class C { static { if (1) { if (2) {} } } }", Some(json!([2]))),
+        ("if (true) { if (false) {} }", Some(json!([2]))),
+    ];
+
+    let fail = vec![
+        ("function foo() { if (true) { if (false) { if (true) { } } } }", Some(json!([2]))),
+        ("var foo = () => { if (true) { if (false) { if (true) { } } } }", Some(json!([2]))),
+        ("function foo() { if (true) {} else { for(;;) {} } }", Some(json!([1]))),
+        ("function foo() { while (true) { if (true) {} } }", Some(json!([1]))),
+        ("function foo() { for (let x of foo) { if (true) {} } }", Some(json!([1]))),
+        ("function foo() { for (let x in foo) { if (true) {} } }", Some(json!([1]))),
+        ("function foo() { do { if (true) {} } while (true) }", Some(json!([1]))),
+        ("function foo() { try { if (true) {} } catch (e) {} }", Some(json!([1]))),
+        ("function foo() { switch (a) { case 1: if (true) {} } }", Some(json!([1]))),
+        ("function foo() { if (a) { if (b) { if (c) { if (d) { if (e) {} } } } } }", None),
+        (
+            "function foo() { if (true) { if (false) { if (true) { } } } }",
+            Some(json!([{ "max": 2 }])),
+        ),
+        (
+            "function foo() { if (true) { if (false) { if (true) { } } } }",
+            Some(json!([{ "maximum": 2 }])),
+        ),
+        (
+            "function foo() { if (true) { if (false) { if (true) { if (false) {} } } } }",
+            Some(json!([2])),
+        ),
+        ("if (true) { if (false) {} }", Some(json!([1]))),
+        ("class C { static { if (1) { if (2) {} } } }", Some(json!([1]))),
+    ];
+
+    Tester::new(MaxDepth::NAME, pass, fail).test_and_snapshot();
+}
@@ -1,9 +1,11 @@
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 use serde_json::Value;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, utils::rule_option};
 
 fn max_lines_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("eslint(max-lines): {x0:?}"))
@@ -14,10 +16,14 @@ fn max_lines_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct MaxLines(Box<MaxLinesConfig>);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct MaxLinesConfig {
+    /// The maximum number of lines, `300` by default.
     max: usize,
+    /// Ignore the lines made of whitespace.
     skip_blank_lines: bool,
+    /// Ignore the lines only containing comments.
     skip_comments: bool,
 }
 
@@ -35,6 +41,13 @@ impl Default for MaxLinesConfig {
     }
 }
 
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum MaxLinesOption {
+    Max(usize),
+    Object(MaxLinesConfig),
+}
+
 declare_oxc_lint!(
     /// ### What it does
     /// Enforce a maximum number of lines per file
@@ -48,35 +61,22 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     MaxLines,
-    pedantic
+    pedantic,
+    ast_types = []
 );
 
 impl Rule for MaxLines {
     fn from_configuration(value: Value) -> Self {
-        let config = value.get(0);
-        if let Some(max) = config
-            .and_then(Value::as_number)
-            .and_then(serde_json::Number::as_u64)
-            .and_then(|v| usize::try_from(v).ok())
-        {
-            Self(Box::new(MaxLinesConfig { max, skip_comments: false, skip_blank_lines: false }))
-        } else {
-            let max = config
-                .and_then(|config| config.get("max"))
-                .and_then(Value::as_number)
-                .and_then(serde_json::Number::as_u64)
-                .map_or(300, |v| usize::try_from(v).unwrap_or(300));
-            let skip_comments = config
-                .and_then(|config| config.get("skipComments"))
-                .and_then(Value::as_bool)
-                .unwrap_or(false);
-            let skip_blank_lines = config
-                .and_then(|config| config.get("skipBlankLines"))
-                .and_then(Value::as_bool)
-                .unwrap_or(false);
-
-            Self(Box::new(MaxLinesConfig { max, skip_blank_lines, skip_comments }))
-        }
+        let config = match rule_option(&value, 0) {
+            Some(MaxLinesOption::Max(max)) => MaxLinesConfig { max, ..MaxLinesConfig::default() },
+            Some(MaxLinesOption::Object(config)) => config,
+            None => MaxLinesConfig::default(),
+        };
+        Self(Box::new(config))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<MaxLinesOption>()])
     }
 
     fn run_once(&self, ctx: &LintContext) {
@@ -0,0 +1,152 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema};
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{rule_option, MaxOption},
+    AstNode,
+};
+
+fn max_nested_callbacks_diagnostic(depth: usize, max: usize, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint(max-nested-callbacks): Too many nested callbacks ({depth}). Maximum allowed is {max}."
+    ))
+    .with_help("Flatten the callbacks, e.g. with named functions, promises or `async` functions.")
+    .with_labels([span.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct MaxNestedCallbacks(Box<MaxNestedCallbacksConfig>);
+
+#[derive(Debug, Clone)]
+pub struct MaxNestedCallbacksConfig {
+    max: usize,
+}
+
+impl std::ops::Deref for MaxNestedCallbacks {
+    type Target = MaxNestedCallbacksConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for MaxNestedCallbacksConfig {
+    fn default() -> Self {
+        Self { max: 10 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforce a maximum depth that callbacks can be nested
+    ///
+    /// ### Why is this bad?
+    /// Deeply nested callbacks, also known as "callback hell", are hard to read and to follow.
+    ///
+    /// A callback is a function or arrow function passed as an argument of a call.
+    ///
+    /// ### Options
+    /// The maximum depth, `10` by default, given as a number or as `{ "max": 10 }`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // with `3`
+    /// foo1(function() {
+    ///     foo2(function() {
+    ///         foo3(function() {
+    ///             foo4(function() {
+    ///                 // ...
+    ///             });
+    ///         });
+    ///     });
+    /// });
+    /// ```
+    MaxNestedCallbacks,
+    pedantic,
+    ast_types = [Function, ArrowFunctionExpression]
+);
+
+impl Rule for MaxNestedCallbacks {
+    fn from_configuration(value: Value) -> Self {
+        let max =
+            rule_option::<Option<MaxOption>>(&value, 0).and_then(MaxOption::max).unwrap_or(10);
+
+        Self(Box::new(MaxNestedCallbacksConfig { max }))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<MaxOption>()])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if !is_callback(node, ctx) {
+            return;
+        }
+
+        let depth = ctx
+            .nodes()
+            .iter_parents(node.id())
+            .filter(|ancestor| {
+                matches!(
+                    ancestor.kind(),
+                    AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)
+                ) && is_callback(ancestor, ctx)
+            })
+            .count();
+
+        if depth > self.max {
+            ctx.diagnostic(max_nested_callbacks_diagnostic(depth, self.max, node.kind().span()));
+        }
+    }
+}
+
+fn is_callback(node: &AstNode, ctx: &LintContext) -> bool {
+    let mut parents = ctx
+        .nodes()
+        .iter_parents(node.id())
+        .skip(1)
+        .skip_while(|parent| matches!(parent.kind(), AstKind::ParenthesizedExpression(_)));
+
+    matches!(parents.next().map(AstNode::kind), Some(AstKind::Argument(_)))
+        && matches!(parents.next().map(AstNode::kind), Some(AstKind::CallExpression(_)))
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("foo(function () { bar(thing, function (data) {}); });", Some(json!([3]))),
+        ("var foo = function () {}; bar(function(){ baz(function() { qux(foo); }) });", Some(json!([2]))),
+        ("fn(function(){}, function(){}, function(){});", Some(json!([2]))),
+        ("fn(() => {}, function(){}, function(){});", Some(json!([2]))),
+        ("foo(function () { bar(thing, function (data) {}); });", Some(json!([{ "max": 3 }]))),
+        ("foo(function () { bar(thing, function (data) {}); });", Some(json!([{ "maximum": 3 }]))),
+        ("foo(function () { function bar() { baz(function () {}); } });", Some(json!([2]))),
+        ("foo(function () { new Bar(function () { baz(function () {}); }); });", Some(json!([2]))),
+        ("foo(function () { return function () { return function () {}; }; });", Some(json!([1]))),
+        ("a(function() { b(function() { c(function() { d(function() { e(function() { f(function() { g(function() { h(function() { i(function() { j(function() {}); }); }); }); }); }); }); }); }); });", None),
+    ];
+
+    let fail = vec![
+        ("foo(function () { bar(thing, function (data) { baz(function () {}); }); });", Some(json!([2]))),
+        ("foo(function () { bar(thing, (data) => { baz(function () {}); }); });", Some(json!([2]))),
+        ("foo(() => { bar(thing, (data) => { baz( () => {}); }); });", Some(json!([2]))),
+        ("foo(function () { if (isTrue) { bar(function (data) { baz(function () {}); }); } });", Some(json!([2]))),
+        ("foo(function () { bar(thing, (function (data) { baz(function () {}); })); });", Some(json!([2]))),
+        ("a(function() { b(function() { c(function() { d(function() { e(function() { f(function() { g(function() { h(function() { i(function() { j(function() { k(function() {}); }); }); }); }); }); }); }); }); }); });", None),
+        ("foo(function () { bar(thing, function (data) { baz(function () {}); }); });", Some(json!([{ "max": 2 }]))),
+        ("foo(function () { bar(thing, function (data) { baz(function () {}); }); });", Some(json!([{ "maximum": 2 }]))),
+        ("foo(function () { bar(function () { baz(function () { qux(function () {}); }); }); });", Some(json!([2]))),
+    ];
+
+    Tester::new(MaxNestedCallbacks::NAME, pass, fail).test_and_snapshot();
+}
@@ -2,9 +2,11 @@ use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 use serde_json::Value;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
 
 fn max_params_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("eslint(max-params): {x0:?}"))
@@ -17,8 +19,10 @@ fn max_params_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct MaxParams(Box<MaxParamsConfig>);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct MaxParamsConfig {
+    /// The maximum number of parameters, `3` by default.
     max: usize,
 }
 
@@ -36,6 +40,13 @@ impl Default for MaxParamsConfig {
     }
 }
 
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum MaxParamsOption {
+    Max(usize),
+    Object(MaxParamsConfig),
+}
+
 declare_oxc_lint!(
     /// ### What it does
     /// Enforce a maximum number of parameters in function definitions
@@ -50,27 +61,22 @@ declare_oxc_lint!(
     /// }
     /// ```
     MaxParams,
-    style
+    style,
+    ast_types = [Function, ArrowFunctionExpression]
 );
 
 impl Rule for MaxParams {
     fn from_configuration(value: Value) -> Self {
-        let config = value.get(0);
-        if let Some(max) = config
-            .and_then(Value::as_number)
-            .and_then(serde_json::Number::as_u64)
-            .and_then(|v| usize::try_from(v).ok())
-        {
-            Self(Box::new(MaxParamsConfig { max }))
-        } else {
-            let max = config
-                .and_then(|config| config.get("max"))
-                .and_then(Value::as_number)
-                .and_then(serde_json::Number::as_u64)
-                .map_or(3, |v| usize::try_from(v).unwrap_or(3));
-
-            Self(Box::new(MaxParamsConfig { max }))
-        }
+        let config = match rule_option(&value, 0) {
+            Some(MaxParamsOption::Max(max)) => MaxParamsConfig { max },
+            Some(MaxParamsOption::Object(config)) => config,
+            None => MaxParamsConfig::default(),
+        };
+        Self(Box::new(config))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<MaxParamsOption>()])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
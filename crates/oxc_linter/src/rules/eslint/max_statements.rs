@@ -0,0 +1,221 @@
+use oxc_ast::{
+    ast::{ArrowFunctionExpression, BlockStatement, Function, FunctionBody, StaticBlock},
+    visit::walk::walk_block_statement,
+    AstKind, Visit,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ScopeFlags;
+use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_function_name_with_kind, rule_option, MaxOption},
+    AstNode,
+};
+
+fn max_statements_diagnostic(name: &str, count: usize, max: usize, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint(max-statements): {name} has too many statements ({count}). Maximum allowed is {max}."
+    ))
+    .with_help("Split this function into smaller functions.")
+    .with_labels([span.into()])
+}
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+struct MaxStatementsOptions {
+    /// Do not report the only top-level function of the file.
+    ignore_top_level_functions: bool,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct MaxStatements(Box<MaxStatementsConfig>);
+
+#[derive(Debug, Clone)]
+pub struct MaxStatementsConfig {
+    max: usize,
+    ignore_top_level_functions: bool,
+}
+
+impl std::ops::Deref for MaxStatements {
+    type Target = MaxStatementsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for MaxStatementsConfig {
+    fn default() -> Self {
+        Self { max: 10, ignore_top_level_functions: false }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforce a maximum number of statements allowed in function blocks
+    ///
+    /// ### Why is this bad?
+    /// Functions with many statements are usually doing too many things, and are hard to
+    /// understand and to reuse.
+    ///
+    /// The statements of the function body and of all the blocks nested in it are counted,
+    /// except for the statements of nested functions, which are counted on their own.
+    ///
+    /// ### Options
+    /// The first option is the maximum number of statements, `10` by default,
+    /// given as a number or as `{ "max": 10 }`.
+    ///
+    /// The second option is an object:
+    /// * `ignoreTopLevelFunctions`: do not report the function which is not nested in another
+    ///   function, when it is the only one in the file, e.g. a module wrapper.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // with `2`
+    /// function foo() {
+    ///     var bar = 1;
+    ///     var baz = 2;
+    ///     var qux = 3;
+    /// }
+    /// ```
+    MaxStatements,
+    pedantic,
+    ast_types = []
+);
+
+impl Rule for MaxStatements {
+    fn from_configuration(value: Value) -> Self {
+        let max =
+            rule_option::<Option<MaxOption>>(&value, 0).and_then(MaxOption::max).unwrap_or(10);
+        let MaxStatementsOptions { ignore_top_level_functions } = rule_option(&value, 1);
+
+        Self(Box::new(MaxStatementsConfig { max, ignore_top_level_functions }))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<MaxOption>(), gen.subschema_for::<MaxStatementsOptions>()])
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let mut top_level_functions = vec![];
+        for node in ctx.nodes().iter() {
+            let (body, span) = match node.kind() {
+                AstKind::Function(func) => match &func.body {
+                    Some(body) => (body, func.span),
+                    None => continue,
+                },
+                AstKind::ArrowFunctionExpression(arrow) => (&arrow.body, arrow.span),
+                _ => continue,
+            };
+            let count = count_statements(node, body);
+
+            let is_top_level = !ctx.nodes().ancestors(node.id()).skip(1).any(|id| {
+                matches!(
+                    ctx.nodes().kind(id),
+                    AstKind::Function(_) | AstKind::ArrowFunctionExpression(_)
+                )
+            });
+            if self.ignore_top_level_functions && is_top_level {
+                top_level_functions.push((node, count, span));
+            } else {
+                self.report(node, count, span, ctx);
+            }
+        }
+
+        if top_level_functions.len() > 1 {
+            for (node, count, span) in top_level_functions {
+                self.report(node, count, span, ctx);
+            }
+        }
+    }
+}
+
+impl MaxStatements {
+    fn report(&self, node: &AstNode, count: usize, span: Span, ctx: &LintContext) {
+        if count > self.max {
+            ctx.diagnostic(max_statements_diagnostic(
+                &get_function_name_with_kind(node, ctx),
+                count,
+                self.max,
+                span,
+            ));
+        }
+    }
+}
+
+fn count_statements(node: &AstNode, body: &FunctionBody) -> usize {
+    // The expression of an arrow function like `() => foo` is not a statement.
+    if matches!(node.kind(), AstKind::ArrowFunctionExpression(arrow) if arrow.expression) {
+        return 0;
+    }
+    let mut counter = StatementCounter { count: body.directives.len() + body.statements.len() };
+    counter.visit_statements(&body.statements);
+    counter.count
+}
+
+struct StatementCounter {
+    count: usize,
+}
+
+impl<'a> Visit<'a> for StatementCounter {
+    fn visit_block_statement(&mut self, stmt: &BlockStatement<'a>) {
+        self.count += stmt.body.len();
+        walk_block_statement(self, stmt);
+    }
+
+    fn visit_arrow_expression(&mut self, _expr: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: Option<ScopeFlags>) {}
+
+    fn visit_static_block(&mut self, _block: &StaticBlock<'a>) {}
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function foo() { var bar = 1; function qux () { var noCount = 2; } return 3; }", Some(json!([3]))),
+        ("function foo() { var bar = 1; if (true) { for (;;) { var qux = null; } } else { quxx(); } return 3; }", Some(json!([6]))),
+        ("function foo() { var x = 5; function bar() { var y = 6; } bar(); z = 10; baz(); }", Some(json!([5]))),
+        ("function foo() { var a; var b; var c; var x; var y; var z; bar(); baz(); qux(); quxx(); }", None),
+        ("(function() { var bar = 1; return function () { return 42; }; })()", Some(json!([1, { "ignoreTopLevelFunctions": true }]))),
+        ("function foo() { var bar = 1; var baz = 2; }", Some(json!([1, { "ignoreTopLevelFunctions": true }]))),
+        ("define(['foo', 'qux'], function(foo, qux) { var bar = 1; var baz = 2; })", Some(json!([1, { "ignoreTopLevelFunctions": true }]))),
+        ("var foo = { thing: function() { var bar = 1; var baz = 2; } }", Some(json!([2]))),
+        ("var foo = { ['thing']() { var bar = 1; var baz = 2; } }", Some(json!([2]))),
+        ("var foo = { thing: () => { var bar = 1; var baz = 2; } }", Some(json!([2]))),
+        ("var foo = { thing: function() { var bar = 1; var baz = 2; } }", Some(json!([{ "max": 2 }]))),
+        ("var foo = () => bar;", Some(json!([0]))),
+        ("class C { static { one; two; three; { four; five; six; } } }", Some(json!([2]))),
+    ];
+
+    let fail = vec![
+        ("function foo() { var bar = 1; var baz = 2; var qux = 3; }", Some(json!([2]))),
+        ("var foo = () => { var bar = 1; var baz = 2; var qux = 3; };", Some(json!([2]))),
+        ("var foo = function() { var bar = 1; var baz = 2; var qux = 3; };", Some(json!([2]))),
+        ("function foo() { var bar = 1; if (true) { while (false) { var qux = null; } } return 3; }", Some(json!([4]))),
+        ("function foo() { var bar = 1; if (true) { for (;;) { var qux = null; } } return 3; }", Some(json!([4]))),
+        ("function foo() { var bar = 1; if (true) { for (;;) { var qux = null; } } else { quxx(); } return 3; }", Some(json!([5]))),
+        ("function foo() { var x = 5; function bar() { var y = 6; } bar(); z = 10; baz(); }", Some(json!([3]))),
+        ("function foo() { 'use strict'; var bar = 1; }", Some(json!([1]))),
+        ("function foo() { function bar() { var a = 1; var b = 2; } }", Some(json!([1]))),
+        ("function foo() { var a; var b; var c; var x; var y; var z; bar(); baz(); qux(); quxx(); foo(); }", None),
+        ("var foo = { thing: function() { var bar = 1; var baz = 2; var baz2; } }", Some(json!([2]))),
+        ("var foo = { thing() { var bar = 1; var baz = 2; var baz2; } }", Some(json!([2]))),
+        ("var foo = { thing: () => { var bar = 1; var baz = 2; var baz2; } }", Some(json!([2]))),
+        ("var foo = { thing: function() { var bar = 1; var baz = 2; var baz2; } }", Some(json!([{ "max": 2 }]))),
+        ("function foo() { var a = 1; var b = 2; } function bar() { var c = 3; var d = 4; }", Some(json!([1, { "ignoreTopLevelFunctions": true }]))),
+        ("(function() { var bar = 1; return function () { var a = 1; var b = 2; }; })()", Some(json!([1, { "ignoreTopLevelFunctions": true }]))),
+    ];
+
+    Tester::new(MaxStatements::NAME, pass, fail).test_and_snapshot();
+}
@@ -3,8 +3,10 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use oxc_syntax::operator::BinaryOperator;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
 
 fn no_bitwise_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("eslint(no-bitwise): Unexpected use of {x0:?}"))
@@ -15,9 +17,12 @@ fn no_bitwise_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct NoBitwise(Box<NoBitwiseConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoBitwiseConfig {
+    /// The bitwise operators allowed, e.g. `["~"]`.
     allow: Vec<String>,
+    /// Allow `| 0`, used to truncate numbers to integers.
     int32_hint: bool,
 }
 
@@ -45,29 +50,17 @@ declare_oxc_lint!(
     /// var x = y | z;
     /// ```
     NoBitwise,
-    restriction
+    restriction,
+    ast_types = [BinaryExpression, UnaryExpression, AssignmentExpression]
 );
 
 impl Rule for NoBitwise {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
-
-        Self(Box::new(NoBitwiseConfig {
-            allow: obj
-                .and_then(|v| v.get("allow"))
-                .and_then(serde_json::Value::as_array)
-                .map(|v| {
-                    v.iter()
-                        .filter_map(serde_json::Value::as_str)
-                        .map(ToString::to_string)
-                        .collect()
-                })
-                .unwrap_or_default(),
-            int32_hint: obj
-                .and_then(|v| v.get("int32Hint"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-        }))
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<NoBitwiseConfig>()])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -39,7 +39,8 @@ declare_oxc_lint!(
     /// });
     /// ```
     NoCaller,
-    correctness
+    correctness,
+    ast_types = [MemberExpression]
 );
 
 impl Rule for NoCaller {
@@ -44,7 +44,8 @@ declare_oxc_lint!(
     // }
     /// ```
     NoCaseDeclarations,
-    pedantic
+    pedantic,
+    ast_types = [SwitchCase]
 );
 
 impl Rule for NoCaseDeclarations {
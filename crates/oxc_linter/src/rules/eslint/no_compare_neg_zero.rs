@@ -31,7 +31,8 @@ declare_oxc_lint!(
     /// if (x === -0) {}
     /// ```
     NoCompareNegZero,
-    correctness
+    correctness,
+    ast_types = [BinaryExpression]
 );
 
 impl Rule for NoCompareNegZero {
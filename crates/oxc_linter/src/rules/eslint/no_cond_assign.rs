@@ -5,8 +5,10 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
 
 fn no_cond_assign_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(
@@ -21,7 +23,9 @@ pub struct NoCondAssign {
     config: NoCondAssignConfig,
 }
 
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+#[schemars(rename = "NoCondAssignOption")]
 enum NoCondAssignConfig {
     #[default]
     ExceptParens,
@@ -39,19 +43,24 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoCondAssign,
-    correctness
+    correctness,
+    ast_types = [
+        IfStatement,
+        WhileStatement,
+        DoWhileStatement,
+        ForStatement,
+        ConditionalExpression,
+        AssignmentExpression,
+    ]
 );
 
 impl Rule for NoCondAssign {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let config = value.get(0).and_then(serde_json::Value::as_str).map_or_else(
-            NoCondAssignConfig::default,
-            |value| match value {
-                "always" => NoCondAssignConfig::Always,
-                _ => NoCondAssignConfig::ExceptParens,
-            },
-        );
-        Self { config }
+        Self { config: rule_option(&value, 0) }
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<NoCondAssignConfig>()])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -12,9 +12,7 @@ fn no_constant_condition_diagnostic(span0: Span) -> OxcDiagnostic {
 }
 
 #[derive(Debug, Default, Clone)]
-pub struct NoConstantCondition {
-    _check_loops: bool,
-}
+pub struct NoConstantCondition;
 
 declare_oxc_lint!(
     /// ### What it does
@@ -33,21 +31,11 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoConstantCondition,
-    correctness
+    correctness,
+    ast_types = [IfStatement, ConditionalExpression]
 );
 
 impl Rule for NoConstantCondition {
-    fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
-
-        Self {
-            _check_loops: obj
-                .and_then(|v| v.get("checkLoops"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-        }
-    }
-
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::IfStatement(if_stmt) => {
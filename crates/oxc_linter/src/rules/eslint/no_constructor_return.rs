@@ -44,7 +44,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoConstructorReturn,
-    pedantic
+    pedantic,
+    ast_types = [ReturnStatement]
 );
 
 impl Rule for NoConstructorReturn {
@@ -28,7 +28,8 @@ declare_oxc_lint!(
     /// debugger;
     /// ```
     NoDebugger,
-    correctness
+    correctness,
+    ast_types = [DebuggerStatement]
 );
 
 impl Rule for NoDebugger {
@@ -29,7 +29,8 @@ declare_oxc_lint!(
     /// delete x;
     /// ```
     NoDeleteVar,
-    correctness
+    correctness,
+    ast_types = [UnaryExpression]
 );
 
 impl Rule for NoDeleteVar {
@@ -32,6 +32,7 @@ declare_oxc_lint!(
     /// ```
     NoDivRegex,
     restriction,
+    ast_types = [RegExpLiteral]
 );
 
 impl Rule for NoDivRegex {
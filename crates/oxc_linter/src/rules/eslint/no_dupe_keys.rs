@@ -35,7 +35,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoDupeKeys,
-    correctness
+    correctness,
+    ast_types = [ObjectExpression]
 );
 
 impl Rule for NoDupeKeys {
@@ -33,6 +33,7 @@ declare_oxc_lint!(
     /// ```
     NoEmptyFunction,
     restriction,
+    ast_types = [FunctionBody]
 );
 
 impl Rule for NoEmptyFunction {
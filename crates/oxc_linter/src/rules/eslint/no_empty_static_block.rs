@@ -32,7 +32,8 @@ declare_oxc_lint!(
     ///
     /// ```
     NoEmptyStaticBlock,
-    correctness
+    correctness,
+    ast_types = [StaticBlock]
 );
 
 impl Rule for NoEmptyStaticBlock {
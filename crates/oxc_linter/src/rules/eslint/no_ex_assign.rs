@@ -32,7 +32,8 @@ declare_oxc_lint!(
     // }
     /// ```
     NoExAssign,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for NoExAssign {
@@ -17,8 +17,10 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use regex::Regex;
 use rustc_hash::{FxHashMap, FxHashSet};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
 
 fn no_fallthrough_case_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("eslint(no-fallthrough): Expected a 'break' statement before 'case'.")
@@ -68,24 +70,39 @@ impl Default for NoFallthrough {
     }
 }
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct NoFallthroughOptions {
+    /// Regular expression of the comments marking an intentional fallthrough, `falls? ?through` by default.
+    comment_pattern: Option<String>,
+    /// Allow a fallthrough from an empty case.
+    allow_empty_case: Option<bool>,
+    /// Report the fallthrough comments of cases which cannot fall through.
+    report_unused_fallthrough_comment: Option<bool>,
+}
+
 declare_oxc_lint!(
     /// ### What it does
     ///
     /// Disallow fallthrough of `case` statements
     ///
     NoFallthrough,
-    correctness
+    correctness,
+    ast_types = [SwitchStatement]
 );
 
 impl Rule for NoFallthrough {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let Some(value) = value.get(0) else { return Self::default() };
-        let comment_pattern = value.get("commentPattern").and_then(serde_json::Value::as_str);
-        let allow_empty_case = value.get("allowEmptyCase").and_then(serde_json::Value::as_bool);
-        let report_unused_fallthrough_comment =
-            value.get("reportUnusedFallthroughComment").and_then(serde_json::Value::as_bool);
+        let NoFallthroughOptions {
+            comment_pattern,
+            allow_empty_case,
+            report_unused_fallthrough_comment,
+        } = rule_option(&value, 0);
+        Self::new(comment_pattern.as_deref(), allow_empty_case, report_unused_fallthrough_comment)
+    }
 
-        Self::new(comment_pattern, allow_empty_case, report_unused_fallthrough_comment)
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<NoFallthroughOptions>()])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -28,7 +28,8 @@ declare_oxc_lint!(
     /// foo = bar;
     /// ```
     NoFuncAssign,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for NoFuncAssign {
@@ -1,8 +1,10 @@
 use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{CompactStr, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, utils::rule_option};
 
 fn no_global_assign_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!(
@@ -30,6 +32,13 @@ impl std::ops::Deref for NoGlobalAssign {
     }
 }
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default)]
+struct NoGlobalAssignOptions {
+    /// Names of the global variables which are allowed to be assigned.
+    exceptions: Vec<String>,
+}
+
 declare_oxc_lint!(
     /// ### What it does
     /// Disallow modifications to read-only global variables.
@@ -42,26 +51,22 @@ declare_oxc_lint!(
     /// Object = null
     /// ```
     NoGlobalAssign,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for NoGlobalAssign {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
-
+        let NoGlobalAssignOptions { exceptions } = rule_option(&value, 0);
         Self(Box::new(NoGlobalAssignConfig {
-            excludes: obj
-                .and_then(|v| v.get("exceptions"))
-                .and_then(serde_json::Value::as_array)
-                .unwrap_or(&vec![])
-                .iter()
-                .map(serde_json::Value::as_str)
-                .filter(Option::is_some)
-                .map(|x| x.unwrap().into())
-                .collect::<Vec<CompactStr>>(),
+            excludes: exceptions.into_iter().map(CompactStr::from).collect(),
         }))
     }
 
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<NoGlobalAssignOptions>()])
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let symbol_table = ctx.symbols();
         for reference_id_list in ctx.scopes().root_unresolved_references().values() {
@@ -2,8 +2,10 @@ use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
 
 fn no_inner_declarations_diagnostic(x0: &str, x1: &str, span2: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint(no-inner-declarations): Variable or `function` declarations are not allowed in nested blocks")
@@ -16,7 +18,9 @@ pub struct NoInnerDeclarations {
     config: NoInnerDeclarationsConfig,
 }
 
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+#[schemars(rename = "NoInnerDeclarationsOption")]
 enum NoInnerDeclarationsConfig {
     /// Disallows function declarations in nested blocks
     #[default]
@@ -49,14 +53,15 @@ declare_oxc_lint!(
 
 impl Rule for NoInnerDeclarations {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let config = value.get(0).and_then(serde_json::Value::as_str).map_or_else(
-            NoInnerDeclarationsConfig::default,
-            |value| match value {
-                "functions" => NoInnerDeclarationsConfig::Functions,
-                _ => NoInnerDeclarationsConfig::Both,
-            },
-        );
-        Self { config }
+        Self { config: rule_option(&value, 0) }
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![
+            gen.subschema_for::<NoInnerDeclarationsConfig>(),
+            // `{ "blockScopedFunctions": "allow" }` is not supported yet.
+            gen.subschema_for::<serde_json::Map<String, serde_json::Value>>(),
+        ])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -28,7 +28,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoIterator,
-    restriction
+    restriction,
+    ast_types = [MemberExpression]
 );
 
 impl Rule for NoIterator {
@@ -33,7 +33,8 @@ declare_oxc_lint!(
     /// var x = 2e999;
     /// ```
     NoLossOfPrecision,
-    correctness
+    correctness,
+    ast_types = [NumericLiteral]
 );
 
 impl Rule for NoLossOfPrecision {
@@ -35,6 +35,7 @@ declare_oxc_lint!(
     /// ```
     NoNewNativeNonconstructor,
     correctness,
+    ast_types = [NewExpression]
 );
 
 impl Rule for NoNewNativeNonconstructor {
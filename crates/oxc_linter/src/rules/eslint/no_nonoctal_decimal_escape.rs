@@ -43,7 +43,8 @@ declare_oxc_lint!(
     /// "\\9"
     /// ```
     NoNonoctalDecimalEscape,
-    correctness
+    correctness,
+    ast_types = [StringLiteral]
 );
 
 impl Rule for NoNonoctalDecimalEscape {
@@ -34,7 +34,8 @@ declare_oxc_lint!(
     /// obj["__proto__"] = b;
     /// ```
     NoProto,
-    restriction
+    restriction,
+    ast_types = [MemberExpression]
 );
 
 impl Rule for NoProto {
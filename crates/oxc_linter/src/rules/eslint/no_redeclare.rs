@@ -5,8 +5,10 @@ use oxc_ast::{
 use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, utils::rule_option};
 
 fn no_redeclare_diagnostic(x0: &str, span1: Span, span2: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("eslint(no-redeclare): '{x0}' is already defined.")).with_labels([
@@ -34,8 +36,11 @@ fn no_redeclare_as_builti_in_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic
 //     #[label("It cannot be redeclared here.")] pub Span,
 // );
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoRedeclare {
+    /// Also report the redeclarations of global variables, e.g. `var Object`.
+    #[serde(rename = "builtinGlobals")]
     built_in_globals: bool,
 }
 
@@ -54,18 +59,17 @@ declare_oxc_lint!(
     /// var a = 10;
     /// ```
     NoRedeclare,
-    pedantic
+    pedantic,
+    ast_types = []
 );
 
 impl Rule for NoRedeclare {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let built_in_globals = value
-            .get(0)
-            .and_then(|config| config.get("builtinGlobals"))
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or(false);
+        rule_option(&value, 0)
+    }
 
-        Self { built_in_globals }
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<Self>()])
     }
 
     fn run_once(&self, ctx: &LintContext) {
@@ -53,6 +53,7 @@ declare_oxc_lint!(
     /// ```
     NoRestrictedGlobals,
     restriction,
+    ast_types = [IdentifierReference]
 );
 
 impl Rule for NoRestrictedGlobals {
@@ -34,8 +34,9 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoSelfCompare,
-    pedantic // The code is not wrong if it is intended to check for NaNs, which is the majority of
-             // the case.
+    pedantic, // The code is not wrong if it is intended to check for NaNs, which is the majority of
+              // the case.
+    ast_types = [BinaryExpression]
 );
 
 impl Rule for NoSelfCompare {
@@ -0,0 +1,317 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{SymbolFlags, SymbolId};
+use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{rule_option, symbol_name_span},
+};
+
+fn no_shadow_diagnostic(name: &str, span: Span, shadowed_span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint(no-shadow): '{name}' is already declared in the upper scope."
+    ))
+    .with_help("Rename this variable, so that the upper one can still be used here.")
+    .with_labels([
+        LabeledSpan::new_with_span(Some(format!("'{name}' is declared here")), shadowed_span),
+        LabeledSpan::new_with_span(Some("and shadowed here".into()), span),
+    ])
+}
+
+fn no_shadow_global_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("eslint(no-shadow): '{name}' is already a global variable."))
+        .with_help("Rename this variable, so that the global one can still be used here.")
+        .with_labels([span.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoShadow(Box<NoShadowConfig>);
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoShadowConfig {
+    /// Also report the variables shadowing global variables, e.g. `Object`, `false` by default.
+    builtin_globals: bool,
+    /// Which variables declared after the variables shadowing them are reported, `functions` by default.
+    hoist: HoistOption,
+    /// Names which are allowed to shadow.
+    allow: Vec<String>,
+    /// Ignore a type shadowing a value or a value shadowing a type, `true` by default.
+    ignore_type_value_shadow: bool,
+}
+
+impl std::ops::Deref for NoShadow {
+    type Target = NoShadowConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for NoShadowConfig {
+    fn default() -> Self {
+        Self {
+            builtin_globals: false,
+            hoist: HoistOption::default(),
+            allow: vec![],
+            ignore_type_value_shadow: true,
+        }
+    }
+}
+
+/// Which variables declared after the variables shadowing them are reported.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+#[schemars(rename = "NoShadowHoist")]
+enum HoistOption {
+    All,
+    #[default]
+    Functions,
+    FunctionsAndTypes,
+    Types,
+    Never,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow variable declarations from shadowing variables declared in the outer scope.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A variable with the same name as a variable of an outer scope hides the outer one,
+    /// which can't be used anymore, and readers may confuse them.
+    ///
+    /// In TypeScript, a type and a value with the same name don't hide each other,
+    /// so a type shadowing a value, or a value shadowing a type, is not reported by default.
+    /// The parameters of function types, e.g. `a` in `type F = (a: string) => void`,
+    /// are never reported.
+    ///
+    /// ### Options
+    ///
+    /// * `builtinGlobals`: also report the variables shadowing global variables, e.g. `Object`,
+    ///   including the top-level variables of modules.
+    /// * `hoist`: whether to report shadowing variables declared before the variables they
+    ///   shadow, `"functions"` (default) only for function declarations, `"types"` only for
+    ///   types, `"functions-and-types"`, `"all"` or `"never"`.
+    /// * `allow`: names which are allowed to shadow, e.g. `["resolve", "reject"]`.
+    /// * `ignoreTypeValueShadow`: ignore a type shadowing a value or a value shadowing a type,
+    ///   `true` by default.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var a = 3;
+    /// function b() {
+    ///     var a = 10;
+    /// }
+    ///
+    /// if (true) {
+    ///     let a = 5;
+    /// }
+    /// ```
+    NoShadow,
+    suspicious,
+    ast_types = []
+);
+
+impl Rule for NoShadow {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<NoShadowConfig>()])
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        !ctx.source_type().is_typescript_definition()
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        let symbols = ctx.symbols();
+        let name = symbols.get_name(symbol_id);
+        if symbols.get_flag(symbol_id).contains(SymbolFlags::EnumMember)
+            || self.allow.iter().any(|allowed| allowed == name)
+        {
+            return;
+        }
+        let span = symbol_name_span(symbol_id, ctx);
+
+        let scopes = ctx.scopes();
+        let Some(parent_scope_id) = scopes.get_parent_id(symbols.get_scope_id(symbol_id)) else {
+            // The top-level variables of a script are the global variables themselves.
+            if self.builtin_globals && ctx.source_type().is_module() && is_global(name, ctx) {
+                ctx.diagnostic(no_shadow_global_diagnostic(name, span));
+            }
+            return;
+        };
+        match scopes.find_binding(parent_scope_id, name) {
+            Some(shadowed_id) => {
+                if self.is_reported(symbol_id, shadowed_id, ctx) {
+                    ctx.diagnostic(no_shadow_diagnostic(
+                        name,
+                        span,
+                        symbol_name_span(shadowed_id, ctx),
+                    ));
+                }
+            }
+            None => {
+                if self.builtin_globals && is_global(name, ctx) {
+                    ctx.diagnostic(no_shadow_global_diagnostic(name, span));
+                }
+            }
+        }
+    }
+}
+
+impl NoShadow {
+    fn is_reported(&self, symbol_id: SymbolId, shadowed_id: SymbolId, ctx: &LintContext) -> bool {
+        if is_on_initializer(symbol_id, shadowed_id, ctx) {
+            return false;
+        }
+        if self.ignore_type_value_shadow
+            && is_type_only(symbol_id, ctx) != is_type_only(shadowed_id, ctx)
+        {
+            return false;
+        }
+        // The shadowed variable is declared after, e.g. `function foo() { let a; } let a;`.
+        if ctx.symbols().get_span(symbol_id).end < ctx.symbols().get_span(shadowed_id).start {
+            let is_function = matches!(
+                ctx.nodes().kind(ctx.symbols().get_declaration(shadowed_id)),
+                AstKind::Function(func) if func.is_declaration()
+            );
+            return match self.hoist {
+                HoistOption::All => true,
+                HoistOption::Functions => is_function,
+                HoistOption::FunctionsAndTypes => is_function || is_type_only(shadowed_id, ctx),
+                HoistOption::Types => is_type_only(shadowed_id, ctx),
+                HoistOption::Never => false,
+            };
+        }
+        true
+    }
+}
+
+fn is_global(name: &str, ctx: &LintContext) -> bool {
+    ctx.env_contains_var(name) || ctx.globals().is_enabled(name)
+}
+
+/// Whether the variable is the name of a function or class expression in the declaration of
+/// the shadowed variable, e.g. `var a = function a() {};`.
+fn is_on_initializer(symbol_id: SymbolId, shadowed_id: SymbolId, ctx: &LintContext) -> bool {
+    let symbols = ctx.symbols();
+    let is_expression_name = match ctx.nodes().kind(symbols.get_declaration(symbol_id)) {
+        AstKind::Function(func) => func.is_expression(),
+        AstKind::Class(class) => class.is_expression(),
+        _ => false,
+    };
+    let span = symbols.get_span(symbol_id);
+    is_expression_name
+        && matches!(
+            ctx.nodes().kind(symbols.get_declaration(shadowed_id)),
+            AstKind::VariableDeclarator(declarator)
+                if declarator.span.start <= span.start && span.end <= declarator.span.end
+        )
+}
+
+/// Whether the symbol can only be used as a type, e.g. an interface, a type parameter or a
+/// type imported by `import type`.
+fn is_type_only(symbol_id: SymbolId, ctx: &LintContext) -> bool {
+    let flags = ctx.symbols().get_flag(symbol_id);
+    if flags
+        .intersects(SymbolFlags::TypeAlias | SymbolFlags::Interface | SymbolFlags::TypeParameter)
+        && !flags.intersects(SymbolFlags::Value)
+    {
+        return true;
+    }
+    let declaration = ctx.symbols().get_declaration(symbol_id);
+    match ctx.nodes().kind(declaration) {
+        AstKind::ImportSpecifier(specifier) if specifier.import_kind.is_type() => true,
+        AstKind::ImportSpecifier(_)
+        | AstKind::ImportDefaultSpecifier(_)
+        | AstKind::ImportNamespaceSpecifier(_) => matches!(
+            ctx.nodes().parent_kind(declaration),
+            Some(AstKind::ImportDeclaration(import)) if import.import_kind.is_type()
+        ),
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var a = 3; var b = (x) => { a++; return x + a; }; setTimeout(() => { b(a); }, 0);", None),
+        ("class A { foo() { var a; } }", None),
+        ("(function() { var A = class A {}; })()", None),
+        ("var a = function a() {};", None),
+        ("var a = class a {};", None),
+        ("var a = foo(function a() {});", None),
+        ("function foo() { var a; } function bar() { var a; }", None),
+        ("{ let a; } let b;", None),
+        ("function foo() { let a; } let a;", None),
+        ("function foo() { var a; } var a;", None),
+        ("function foo() { class A {} } class A {}", None),
+        ("function foo(a) { } let a;", Some(json!([{ "hoist": "functions" }]))),
+        ("function foo() { function a() {} } function a() {}", Some(json!([{ "hoist": "never" }]))),
+        ("function foo() { var Object = 0; }", None),
+        ("function foo() { var Object = 0; }", Some(json!([{ "builtinGlobals": false }]))),
+        ("var Object = 0;", Some(json!([{ "builtinGlobals": false }]))),
+        (
+            "function foo(cb) { (function (cb) { cb(42); })(cb); }",
+            Some(json!([{ "allow": ["cb"] }])),
+        ),
+        ("enum E { A } const A = 1;", None),
+        ("type Foo = number; function bar() { const Foo = 1; }", None),
+        ("const foo = 1; function bar<foo>(a: foo) {}", None),
+        ("import type { Foo } from './foo'; function bar() { const Foo = 1; }", None),
+        ("interface Foo {} function bar() { const Foo = 1; }", None),
+        ("const a = 1; type Fn = (a: string) => void;", None),
+        ("function foo() { let a; } let a;", Some(json!([{ "hoist": "types" }]))),
+    ];
+
+    let fail = vec![
+        ("var a = 3; function b() { var a = 10; }", None),
+        ("var a = 3; var b = function () { var a = 10; };", None),
+        ("var a = 3; var b = () => { var a = 10; };", None),
+        ("function a() {} function b() { var a = 10; }", None),
+        ("let a = 1; if (true) { let a = 2; }", None),
+        ("let a = 1; { const a = 2; }", None),
+        ("let x = 1; try {} catch (x) {}", None),
+        ("function foo(a) { function bar(a) {} }", None),
+        ("for (let i = 0; i < 10; i++) { for (let i = 0; i < 10; i++) {} }", None),
+        ("var a; var b = function a() {};", None),
+        ("var A = class { foo() { var A; } };", None),
+        ("function foo() { function a() {} } function a() {}", None),
+        ("function foo() { let a; } let a;", Some(json!([{ "hoist": "all" }]))),
+        ("function foo() { var a; } var a;", Some(json!([{ "hoist": "all" }]))),
+        ("function foo() { type A = 1; } type A = 2;", Some(json!([{ "hoist": "types" }]))),
+        (
+            "function foo() { type A = 1; } type A = 2;",
+            Some(json!([{ "hoist": "functions-and-types" }])),
+        ),
+        ("function foo() { var Object = 0; }", Some(json!([{ "builtinGlobals": true }]))),
+        ("var Object = 0;", Some(json!([{ "builtinGlobals": true }]))),
+        (
+            "function foo(cb) { (function (cb) { cb(42); })(cb); }",
+            Some(json!([{ "allow": ["done"] }])),
+        ),
+        (
+            "type Foo = number; function bar() { const Foo = 1; }",
+            Some(json!([{ "ignoreTypeValueShadow": false }])),
+        ),
+        ("type Foo = number; function bar<Foo>() {}", None),
+        ("class Foo {} function bar() { class Foo {} }", None),
+        ("import { foo } from './foo'; function bar() { const foo = 1; }", None),
+    ];
+
+    Tester::new(NoShadow::NAME, pass, fail).test_and_snapshot();
+}
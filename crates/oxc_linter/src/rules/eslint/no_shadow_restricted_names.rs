@@ -33,7 +33,8 @@ declare_oxc_lint!(
     /// try {} catch(eval){}
     /// ```
     NoShadowRestrictedNames,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 #[inline]
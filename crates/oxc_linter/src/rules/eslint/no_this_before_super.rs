@@ -43,7 +43,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoThisBeforeSuper,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 #[derive(Default, Copy, Clone, Debug)]
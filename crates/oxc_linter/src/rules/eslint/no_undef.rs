@@ -3,8 +3,10 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use oxc_syntax::operator::UnaryOperator;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
 
 fn no_undef_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint(no-undef): Disallow the use of undeclared variables.")
@@ -12,9 +14,12 @@ fn no_undef_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
         .with_labels([span1.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoUndef {
     #[allow(dead_code)]
+    /// Also report the undeclared variables used with `typeof`.
+    #[serde(rename = "typeof")]
     type_of: bool,
 }
 
@@ -33,17 +38,17 @@ declare_oxc_lint!(
     /// var bar = a + 1;
     /// ```
     NoUndef,
-    nursery
+    nursery,
+    ast_types = []
 );
 
 impl Rule for NoUndef {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let type_of = value
-            .get(0)
-            .and_then(|config| config.get("typeof"))
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or_default();
-        Self { type_of }
+        rule_option(&value, 0)
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<Self>()])
     }
 
     fn run_once(&self, ctx: &LintContext) {
@@ -6,8 +6,10 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use oxc_syntax::operator::LogicalOperator;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
 
 fn no_unsafe_optional_chaining_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint(no-unsafe-optional-chaining): Unsafe usage of optional chaining")
@@ -23,10 +25,10 @@ fn no_unsafe_arithmetic_diagnostic(span0: Span) -> OxcDiagnostic {
     .with_labels([span0.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoUnsafeOptionalChaining {
-    /// Disallow arithmetic operations on optional chaining expressions (Default false).
-    /// If this is true, this rule warns arithmetic operations on optional chaining expressions, which possibly result in NaN.
+    /// Also report the optional chains used in arithmetic operations.
     disallow_arithmetic_operators: bool,
 }
 
@@ -51,18 +53,32 @@ declare_oxc_lint!(
     /// const { bar } = obj?.foo;  // TypeError
     /// ```
     NoUnsafeOptionalChaining,
-    restriction // TypeScript checks optional chaining
+    restriction, // TypeScript checks optional chaining
+    ast_types = [
+        CallExpression,
+        MemberExpression,
+        TaggedTemplateExpression,
+        NewExpression,
+        AssignmentExpression,
+        BinaryExpression,
+        UnaryExpression,
+        ForOfStatement,
+        WithStatement,
+        Class,
+        AssignmentPattern,
+        Argument,
+        VariableDeclarator,
+        AssignmentTargetWithDefault,
+    ]
 );
 
 impl Rule for NoUnsafeOptionalChaining {
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self {
-            disallow_arithmetic_operators: value
-                .get(0)
-                .and_then(|v| v.get("disallowArithmeticOperators"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-        }
+        rule_option(&value, 0)
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<Self>()])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -35,7 +35,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoUnusedLabels,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for NoUnusedLabels {
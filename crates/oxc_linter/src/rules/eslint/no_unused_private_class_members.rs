@@ -86,7 +86,8 @@ declare_oxc_lint!(
     ///
     /// ```
     NoUnusedPrivateClassMembers,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for NoUnusedPrivateClassMembers {
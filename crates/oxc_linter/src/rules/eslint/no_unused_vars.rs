@@ -0,0 +1,815 @@
+use oxc_ast::{
+    ast::{
+        AssignmentTarget, BindingPattern, BindingPatternKind, Expression, FormalParameters,
+        ImportDeclaration, ImportDeclarationSpecifier,
+    },
+    syntax_directed_operations::BoundNames,
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{Reference, SymbolId};
+use oxc_span::{GetSpan, Span};
+use regex::Regex;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
+
+use crate::{
+    context::LintContext,
+    fixer::{CompositeFix, Fix, RuleFixer},
+    rule::Rule,
+    utils::{rule_option, symbol_name_span},
+    AstNode,
+};
+
+fn no_unused_vars_diagnostic(
+    name: &str,
+    assigned: bool,
+    allowed: Option<(&str, &Regex)>,
+    span: Span,
+) -> OxcDiagnostic {
+    let action = if assigned { "assigned a value" } else { "defined" };
+    let diagnostic = OxcDiagnostic::warn(format!(
+        "eslint(no-unused-vars): '{name}' is {action} but never used."
+    ))
+    .with_labels([span.into()]);
+    match allowed {
+        Some((kind, pattern)) => diagnostic
+            .with_help(format!("Unused {kind} are allowed if their names match `{pattern}`")),
+        None => diagnostic.with_help("Consider removing this declaration."),
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnusedVars(Box<NoUnusedVarsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnusedVarsConfig {
+    vars: VarsOption,
+    args: ArgsOption,
+    caught_errors: CaughtErrorsOption,
+    /// Ignore the variables of an object destructuring with a rest property,
+    /// e.g. `a` in `const { a, ...rest } = obj`, which are only declared to be omitted from `rest`.
+    ignore_rest_siblings: bool,
+    vars_ignore_pattern: Option<Regex>,
+    args_ignore_pattern: Option<Regex>,
+    caught_errors_ignore_pattern: Option<Regex>,
+}
+
+impl std::ops::Deref for NoUnusedVars {
+    type Target = NoUnusedVarsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+#[schemars(rename = "NoUnusedVarsVars")]
+enum VarsOption {
+    #[default]
+    All,
+    Local,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+#[schemars(rename = "NoUnusedVarsArgs")]
+enum ArgsOption {
+    #[default]
+    AfterUsed,
+    All,
+    None,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+#[schemars(rename = "NoUnusedVarsCaughtErrors")]
+enum CaughtErrorsOption {
+    All,
+    #[default]
+    None,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum NoUnusedVarsOptions {
+    Vars(VarsOption),
+    Object(NoUnusedVarsObjectOptions),
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+struct NoUnusedVarsObjectOptions {
+    /// Whether the variables of the global scope are checked, `all` by default, or `local`.
+    vars: VarsOption,
+    /// Whether parameters are checked: `after-used` (default) for those after the last used one,
+    /// `all`, or `none`.
+    args: ArgsOption,
+    /// Whether the parameters of `catch` clauses are checked, `none` by default.
+    caught_errors: CaughtErrorsOption,
+    /// Ignore the variables of an object destructuring with a rest property.
+    ignore_rest_siblings: bool,
+    /// Regular expression of the unused variable names allowed, e.g. `^_`.
+    vars_ignore_pattern: Option<String>,
+    /// Regular expression of the unused parameter names allowed, e.g. `^_`.
+    args_ignore_pattern: Option<String>,
+    /// Regular expression of the unused `catch` parameter names allowed, e.g. `^ignore`.
+    caught_errors_ignore_pattern: Option<String>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unused variables, functions, classes, imports, parameters and,
+    /// in TypeScript, types, interfaces, enums and namespaces.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Declarations which are never read are most likely left over from a refactoring,
+    /// or a typo in a name which is used instead of the intended one.
+    /// They take up space and confuse readers.
+    ///
+    /// A variable which is only written to, e.g. `x = 1` or `x++`, is unused.
+    /// Usages in type positions count, so imports only used as types are not reported,
+    /// and neither are exported declarations or `declare`d ones.
+    ///
+    /// ### Options
+    ///
+    /// Either `"all"` or `"local"` for the `vars` option, or an object:
+    ///
+    /// * `vars`: `"all"` (default) or `"local"` to not check the global variables of scripts.
+    /// * `args`: `"after-used"` (default) to only check the parameters after the last used one,
+    ///   `"all"` or `"none"`.
+    /// * `caughtErrors`: `"none"` (default) or `"all"` to check the parameters of `catch` clauses.
+    /// * `ignoreRestSiblings`: ignore the variables of an object destructuring with a rest property.
+    /// * `varsIgnorePattern`, `argsIgnorePattern` and `caughtErrorsIgnorePattern`:
+    ///   regular expressions of the unused names allowed, e.g. `"^_"`.
+    ///
+    /// The other options of ESLint, e.g. `destructuredArrayIgnorePattern`, are accepted but ignored.
+    ///
+    /// Unused imports are removed by `--fix`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// import { used, unused } from "./foo";
+    ///
+    /// function add(a, b, c) {
+    ///   let x = 1;
+    ///   x++;
+    ///   return a + b + used;
+    /// }
+    /// add(1, 2, 3);
+    /// ```
+    NoUnusedVars,
+    nursery,
+    ast_types = []
+);
+
+/// How a symbol was declared, which selects the options applied to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Declaration {
+    Variable,
+    Parameter,
+    CaughtError,
+    Import,
+    /// Functions, classes, and TypeScript types, interfaces, enums and namespaces.
+    Other,
+}
+
+/// An unused symbol to report.
+struct Unused {
+    assigned: bool,
+    /// Kind of the symbol and pattern configured for it, for the help message.
+    allowed: Option<(&'static str, Regex)>,
+}
+
+impl Rule for NoUnusedVars {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let options = match rule_option(&value, 0) {
+            Some(NoUnusedVarsOptions::Vars(vars)) => {
+                NoUnusedVarsObjectOptions { vars, ..NoUnusedVarsObjectOptions::default() }
+            }
+            Some(NoUnusedVarsOptions::Object(options)) => options,
+            None => return Self::default(),
+        };
+        let pattern = |pattern: Option<String>| pattern.and_then(|p| Regex::new(&p).ok());
+        Self(Box::new(NoUnusedVarsConfig {
+            vars: options.vars,
+            args: options.args,
+            caught_errors: options.caught_errors,
+            ignore_rest_siblings: options.ignore_rest_siblings,
+            vars_ignore_pattern: pattern(options.vars_ignore_pattern),
+            args_ignore_pattern: pattern(options.args_ignore_pattern),
+            caught_errors_ignore_pattern: pattern(options.caught_errors_ignore_pattern),
+        }))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<NoUnusedVarsOptions>()])
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        !ctx.source_type().is_typescript_definition()
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        let Some(unused) = self.check(symbol_id, ctx) else {
+            return;
+        };
+        let symbols = ctx.symbols();
+        let declaration = ctx.nodes().get_node(symbols.get_declaration(symbol_id));
+        let span = match declaration.kind() {
+            AstKind::TSModuleDeclaration(module) => module.id.span(),
+            _ => symbol_name_span(symbol_id, ctx),
+        };
+        let allowed = unused.allowed.as_ref().map(|(kind, pattern)| (*kind, pattern));
+        let diagnostic =
+            no_unused_vars_diagnostic(symbols.get_name(symbol_id), unused.assigned, allowed, span);
+
+        let import =
+            ctx.nodes().iter_parents(declaration.id()).find_map(|node| match node.kind() {
+                AstKind::ImportDeclaration(import) => Some(import),
+                _ => None,
+            });
+        let Some(import) = import.filter(|_| is_import(declaration)) else {
+            ctx.diagnostic(diagnostic);
+            return;
+        };
+        let kept = import
+            .specifiers
+            .iter()
+            .flatten()
+            .filter(|specifier| {
+                local_symbol_id(specifier).map_or(true, |id| self.check(id, ctx).is_none())
+            })
+            .collect::<Vec<_>>();
+        if kept.is_empty() && import.import_kind.is_value() {
+            // Removing the whole declaration also removes the side effects of the imported module.
+            ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+                fixer.delete_range(statement_span(import, ctx))
+            });
+        } else {
+            ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                if kept.is_empty() {
+                    fixer.delete_range(statement_span(import, ctx)).into()
+                } else {
+                    remove_specifiers(fixer, import, &kept, ctx)
+                }
+            });
+        }
+    }
+}
+
+impl NoUnusedVars {
+    /// Whether `symbol_id` is unused and not allowed to be by the options.
+    fn check(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) -> Option<Unused> {
+        let symbols = ctx.symbols();
+        let declaration_node = ctx.nodes().get_node(symbols.get_declaration(symbol_id));
+        let declaration = declaration_of(declaration_node)?;
+        if is_exported(symbol_id, declaration_node, ctx) || is_ambient(declaration_node, ctx) {
+            return None;
+        }
+
+        let is_root_scope = symbols.get_scope_id(symbol_id) == ctx.scopes().root_scope_id();
+        // Top level bindings of vue, svelte and astro scripts can be used by their templates.
+        if is_root_scope
+            && ctx
+                .file_path()
+                .extension()
+                .is_some_and(|ext| ext == "vue" || ext == "svelte" || ext == "astro")
+        {
+            return None;
+        }
+
+        let (kind, pattern) = match declaration {
+            Declaration::Parameter => {
+                if self.args == ArgsOption::None || !has_body(declaration_node, ctx) {
+                    return None;
+                }
+                ("parameters", self.args_ignore_pattern.as_ref())
+            }
+            Declaration::CaughtError => {
+                if self.caught_errors == CaughtErrorsOption::None {
+                    return None;
+                }
+                ("caught errors", self.caught_errors_ignore_pattern.as_ref())
+            }
+            Declaration::Variable | Declaration::Import | Declaration::Other => {
+                if self.vars == VarsOption::Local && is_root_scope && !ctx.source_type().is_module()
+                {
+                    return None;
+                }
+                ("variables", self.vars_ignore_pattern.as_ref())
+            }
+        };
+
+        if symbols
+            .get_resolved_references(symbol_id)
+            .any(|reference| is_used_by(reference, symbol_id, declaration_node, ctx))
+        {
+            return None;
+        }
+        if pattern.is_some_and(|pattern| pattern.is_match(symbols.get_name(symbol_id))) {
+            return None;
+        }
+        if declaration == Declaration::Parameter
+            && self.args == ArgsOption::AfterUsed
+            && has_used_parameter_after(symbol_id, declaration_node, ctx)
+        {
+            return None;
+        }
+        if self.ignore_rest_siblings
+            && binding_pattern(declaration_node)
+                .is_some_and(|pattern| has_rest_sibling(pattern, symbol_id))
+        {
+            return None;
+        }
+
+        let assigned = symbols.get_resolved_references(symbol_id).any(Reference::is_write)
+            || matches!(declaration_node.kind(), AstKind::VariableDeclarator(decl) if decl.init.is_some());
+        Some(Unused { assigned, allowed: pattern.map(|pattern| (kind, pattern.clone())) })
+    }
+}
+
+fn declaration_of(node: &AstNode) -> Option<Declaration> {
+    let declaration = match node.kind() {
+        AstKind::VariableDeclarator(_) => Declaration::Variable,
+        // TypeScript parameter properties are class members.
+        AstKind::FormalParameter(param)
+            if param.accessibility.is_some() || param.readonly || param.r#override =>
+        {
+            return None;
+        }
+        AstKind::FormalParameter(_) | AstKind::BindingRestElement(_) => Declaration::Parameter,
+        AstKind::CatchParameter(_) => Declaration::CaughtError,
+        AstKind::ImportSpecifier(_)
+        | AstKind::ImportDefaultSpecifier(_)
+        | AstKind::ImportNamespaceSpecifier(_) => Declaration::Import,
+        // The names of function and class expressions are only bound inside of them.
+        AstKind::Function(func) if func.is_expression() => return None,
+        AstKind::Class(class) if class.is_expression() => return None,
+        AstKind::Function(_)
+        | AstKind::Class(_)
+        | AstKind::TSTypeAliasDeclaration(_)
+        | AstKind::TSInterfaceDeclaration(_)
+        | AstKind::TSEnumDeclaration(_)
+        | AstKind::TSModuleDeclaration(_) => Declaration::Other,
+        // Enum members, type parameters...
+        _ => return None,
+    };
+    Some(declaration)
+}
+
+fn is_import(node: &AstNode) -> bool {
+    declaration_of(node) == Some(Declaration::Import)
+}
+
+/// Whether the symbol is declared by an export declaration, e.g. `export const a = 1`,
+/// or exported by name, e.g. `export { a }` or `export type { A }`.
+fn is_exported(symbol_id: SymbolId, declaration: &AstNode, ctx: &LintContext) -> bool {
+    // `VariableDeclarator` > `VariableDeclaration` > `ExportNamedDeclaration`
+    let in_export_declaration =
+        ctx.nodes().iter_parents(declaration.id()).skip(1).take(2).any(|node| {
+            matches!(
+                node.kind(),
+                AstKind::ExportNamedDeclaration(_) | AstKind::ExportDefaultDeclaration(_)
+            )
+        });
+    if in_export_declaration {
+        return true;
+    }
+    let symbols = ctx.symbols();
+    // Merged declarations, e.g. `interface A {} export interface A {}`, are exported by any of them.
+    let redeclarations = symbols.get_redeclare_variables(symbol_id);
+    if !redeclarations.is_empty()
+        && ctx.nodes().iter().any(|node| match node.kind() {
+            AstKind::ExportNamedDeclaration(export) => {
+                export.declaration.as_ref().is_some_and(|decl| {
+                    redeclarations.iter().any(|span| is_inside(*span, decl.span()))
+                })
+            }
+            _ => false,
+        })
+    {
+        return true;
+    }
+    symbols.get_scope_id(symbol_id) == ctx.scopes().root_scope_id() && {
+        let name = symbols.get_name(symbol_id);
+        ctx.module_record()
+            .local_export_entries
+            .iter()
+            .any(|entry| entry.local_name.name().is_some_and(|local| local.as_str() == name))
+    }
+}
+
+/// Whether the declaration is ambient, e.g. `declare const a: number` or inside of `declare global {}`.
+fn is_ambient(declaration: &AstNode, ctx: &LintContext) -> bool {
+    ctx.nodes().iter_parents(declaration.id()).any(|node| match node.kind() {
+        AstKind::VariableDeclaration(decl) => decl.declare,
+        AstKind::Function(func) => func.declare,
+        AstKind::Class(class) => class.declare,
+        AstKind::TSModuleDeclaration(module) => module.declare,
+        AstKind::TSEnumDeclaration(decl) => decl.declare,
+        AstKind::TSInterfaceDeclaration(decl) => decl.declare,
+        AstKind::TSTypeAliasDeclaration(decl) => decl.declare,
+        _ => false,
+    })
+}
+
+fn parent_formal_parameters<'a, 'b>(
+    declaration: &AstNode<'a>,
+    ctx: &'b LintContext<'a>,
+) -> Option<&'b FormalParameters<'a>> {
+    match ctx.nodes().parent_kind(declaration.id()) {
+        Some(AstKind::FormalParameters(params)) => Some(params),
+        _ => None,
+    }
+}
+
+/// Parameters of overloads, abstract methods and `declare function` have no body to be used in.
+fn has_body(param: &AstNode, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    let Some(params) = nodes.parent_node(param.id()) else {
+        return false;
+    };
+    match nodes.parent_kind(params.id()) {
+        Some(AstKind::Function(func)) => func.body.is_some(),
+        Some(AstKind::ArrowFunctionExpression(_)) => true,
+        _ => false,
+    }
+}
+
+/// Whether `reference` is a usage of `symbol_id`, i.e. a read which is not only used to update
+/// the symbol itself or from inside of its own function, or a usage as a type.
+fn is_used_by(
+    reference: &Reference,
+    symbol_id: SymbolId,
+    declaration: &AstNode,
+    ctx: &LintContext,
+) -> bool {
+    if reference.is_type() {
+        return true;
+    }
+    if !reference.is_read() {
+        return false;
+    }
+    !((reference.is_write() && is_discarded_update(reference, ctx))
+        || is_read_for_itself(reference, symbol_id, ctx)
+        || is_self_reference(reference, declaration))
+}
+
+/// `a++` or `a += 1` whose value is not used.
+fn is_discarded_update(reference: &Reference, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    for node in nodes.iter_parents(reference.node_id()).skip(1) {
+        match node.kind() {
+            AstKind::SimpleAssignmentTarget(_)
+            | AstKind::AssignmentTarget(_)
+            | AstKind::ParenthesizedExpression(_) => continue,
+            AstKind::AssignmentExpression(assign) if assign.operator.is_logical() => return false,
+            AstKind::AssignmentExpression(_) | AstKind::UpdateExpression(_) => {
+                return is_value_discarded(node, ctx);
+            }
+            _ => return false,
+        }
+    }
+    false
+}
+
+/// `a = a + 1` whose value is not used.
+fn is_read_for_itself(reference: &Reference, symbol_id: SymbolId, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    for node in nodes.iter_parents(reference.node_id()).skip(1) {
+        match node.kind() {
+            AstKind::AssignmentExpression(assign) => {
+                let AssignmentTarget::AssignmentTargetIdentifier(target) = &assign.left else {
+                    return false;
+                };
+                let target_symbol_id = target
+                    .reference_id
+                    .get()
+                    .and_then(|id| ctx.symbols().get_reference(id).symbol_id());
+                return target_symbol_id == Some(symbol_id)
+                    && is_inside(reference.span(), assign.right.span())
+                    && is_value_discarded(node, ctx);
+            }
+            kind if kind.is_statement() || kind.is_function_like() => return false,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// A usage from inside of the function declaring the symbol, e.g. a recursive call.
+fn is_self_reference(reference: &Reference, declaration: &AstNode) -> bool {
+    let function_span = match declaration.kind() {
+        AstKind::Function(func) => func.span,
+        AstKind::VariableDeclarator(decl) => match &decl.init {
+            Some(
+                init @ (Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_)),
+            ) => init.span(),
+            _ => return false,
+        },
+        _ => return false,
+    };
+    is_inside(reference.span(), function_span)
+}
+
+fn is_value_discarded(node: &AstNode, ctx: &LintContext) -> bool {
+    let parent = ctx
+        .nodes()
+        .iter_parents(node.id())
+        .skip(1)
+        .find(|parent| !matches!(parent.kind(), AstKind::ParenthesizedExpression(_)));
+    match parent.map(AstNode::kind) {
+        Some(AstKind::ExpressionStatement(_)) => true,
+        Some(AstKind::ForStatement(stmt)) => {
+            stmt.update.as_ref().is_some_and(|update| is_inside(node.kind().span(), update.span()))
+        }
+        _ => false,
+    }
+}
+
+fn is_inside(inner: Span, outer: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+/// With `args: "after-used"`, parameters before a used one are needed for its position.
+fn has_used_parameter_after<'a>(
+    symbol_id: SymbolId,
+    declaration: &AstNode<'a>,
+    ctx: &LintContext<'a>,
+) -> bool {
+    let Some(params) = parent_formal_parameters(declaration, ctx) else {
+        return false;
+    };
+    let mut param_symbols = vec![];
+    params.bound_names(&mut |ident| param_symbols.extend(ident.symbol_id.get()));
+    param_symbols
+        .iter()
+        .skip_while(|id| **id != symbol_id)
+        .skip(1)
+        .any(|id| !ctx.symbols().get_resolved_reference_ids(*id).is_empty())
+}
+
+fn binding_pattern<'a, 'b>(declaration: &'b AstNode<'a>) -> Option<&'b BindingPattern<'a>> {
+    match declaration.kind() {
+        AstKind::VariableDeclarator(decl) => Some(&decl.id),
+        AstKind::FormalParameter(param) => Some(&param.pattern),
+        AstKind::CatchParameter(param) => Some(&param.pattern),
+        _ => None,
+    }
+}
+
+/// Whether `symbol_id` is bound by a property of an object pattern with a rest property.
+fn has_rest_sibling(pattern: &BindingPattern, symbol_id: SymbolId) -> bool {
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(_) => false,
+        BindingPatternKind::ObjectPattern(object) => {
+            object.properties.iter().any(|property| {
+                (object.rest.is_some() && binds(&property.value, symbol_id))
+                    || has_rest_sibling(&property.value, symbol_id)
+            }) || object
+                .rest
+                .as_ref()
+                .is_some_and(|rest| has_rest_sibling(&rest.argument, symbol_id))
+        }
+        BindingPatternKind::ArrayPattern(array) => {
+            array.elements.iter().flatten().any(|element| has_rest_sibling(element, symbol_id))
+                || array
+                    .rest
+                    .as_ref()
+                    .is_some_and(|rest| has_rest_sibling(&rest.argument, symbol_id))
+        }
+        BindingPatternKind::AssignmentPattern(assign) => has_rest_sibling(&assign.left, symbol_id),
+    }
+}
+
+fn binds(pattern: &BindingPattern, symbol_id: SymbolId) -> bool {
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(ident) => ident.symbol_id.get() == Some(symbol_id),
+        BindingPatternKind::AssignmentPattern(assign) => binds(&assign.left, symbol_id),
+        _ => false,
+    }
+}
+
+fn local_symbol_id(specifier: &ImportDeclarationSpecifier) -> Option<SymbolId> {
+    match specifier {
+        ImportDeclarationSpecifier::ImportSpecifier(specifier) => specifier.local.symbol_id.get(),
+        ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+            specifier.local.symbol_id.get()
+        }
+        ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+            specifier.local.symbol_id.get()
+        }
+    }
+}
+
+/// The span of the import declaration, with its line break when it is alone on its line.
+fn statement_span(import: &ImportDeclaration, ctx: &LintContext) -> Span {
+    let source_text = ctx.source_text();
+    let end = import.span.end as usize;
+    if source_text[end..].starts_with('\n') {
+        return Span::new(import.span.start, import.span.end + 1);
+    }
+    import.span
+}
+
+/// Delete the specifiers of `import` which are not `kept`, along with their commas,
+/// e.g. `import a, { b, c } from "x"` into `import { c } from "x"`.
+fn remove_specifiers<'a>(
+    fixer: RuleFixer<'_, 'a>,
+    import: &ImportDeclaration<'a>,
+    kept: &[&ImportDeclarationSpecifier<'a>],
+    ctx: &LintContext<'a>,
+) -> CompositeFix<'a> {
+    let is_kept = |specifier: &ImportDeclarationSpecifier| {
+        kept.iter().any(|kept| kept.span() == specifier.span())
+    };
+    let (named, others): (Vec<_>, Vec<_>) =
+        import.specifiers.iter().flatten().partition(|specifier| {
+            matches!(specifier, ImportDeclarationSpecifier::ImportSpecifier(_))
+        });
+
+    // The default or namespace specifier, followed by the braces of the named specifiers.
+    let mut items =
+        others.iter().map(|specifier| (specifier.span(), is_kept(specifier))).collect::<Vec<_>>();
+    let mut deleted = vec![];
+    if let (Some(first), Some(last)) = (named.first(), named.last()) {
+        let source_text = ctx.source_text();
+        let (start, end) = (first.span().start as usize, last.span().end as usize);
+        let start = source_text[..start].rfind('{').unwrap_or(start);
+        let end = source_text[end..].find('}').map_or(end, |i| end + i + 1);
+        let is_named_kept = named.iter().any(|specifier| is_kept(specifier));
+        #[allow(clippy::cast_possible_truncation)] // for `as u32`
+        items.push((Span::new(start as u32, end as u32), is_named_kept));
+        if is_named_kept {
+            let named = named
+                .iter()
+                .map(|specifier| (specifier.span(), is_kept(specifier)))
+                .collect::<Vec<_>>();
+            deleted.extend(deleted_list_items(&named));
+        }
+    }
+    deleted.extend(deleted_list_items(&items));
+    fixer.composite(deleted.into_iter().map(Fix::delete))
+}
+
+/// Spans to delete for removing the items of a comma separated list which are not kept,
+/// up to the next kept item, or from the previous one for the last items.
+fn deleted_list_items(items: &[(Span, bool)]) -> Vec<Span> {
+    let mut deleted = vec![];
+    let mut i = 0;
+    while i < items.len() {
+        if items[i].1 {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < items.len() && !items[i].1 {
+            i += 1;
+        }
+        let span = match (items.get(i), start.checked_sub(1)) {
+            (Some((next, _)), _) => Span::new(items[start].0.start, next.start),
+            (None, Some(previous)) => Span::new(items[previous].0.end, items[i - 1].0.end),
+            (None, None) => Span::new(items[start].0.start, items[i - 1].0.end),
+        };
+        deleted.push(span);
+    }
+    deleted
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var foo = 5; label: while (true) { console.log(foo); break label; }", None),
+        ("var a = 10; alert(a);", None),
+        ("var a = 10; (function() { alert(a); })();", None),
+        ("function a(x, y) { return y; }; a();", None),
+        ("var arr1 = [1, 2]; var arr2 = [3, 4]; for (var i in arr1) { arr1[i] = 5; } arr2;", None),
+        ("function f() { var a = 1; return function() { f(a *= 2); }; } f();", None),
+        ("let a = 1; a += 1; console.log(a);", None),
+        ("let a = 0; foo(a++);", None),
+        ("let a = 0; while (a++ < 10) {}", None),
+        ("let a = 0; const b = (a = a + 1); b;", None),
+        ("let a; a ||= 1;", None),
+        ("export const a = 1;", None),
+        ("export function foo(a) { return a; }", None),
+        ("export default function foo() {}", None),
+        ("export class A {}", None),
+        ("const a = 1; export { a };", None),
+        ("const a = 1; export default a;", None),
+        ("var x = function foo() {}; x();", None),
+        ("const A = class B {}; new A();", None),
+        ("function foo(a, b) { return b; } foo();", None),
+        ("(function(a, b, c) { c; })();", None),
+        ("(function(a, { b }) { b; })();", None),
+        ("(function(a, b) { a; })();", Some(serde_json::json!([{ "args": "none" }]))),
+        ("(function(_a, b) { b; })();", Some(serde_json::json!([{ "args": "all", "argsIgnorePattern": "^_" }]))),
+        ("try {} catch (e) {}", None),
+        ("try {} catch (ignored) {}", Some(serde_json::json!([{ "caughtErrors": "all", "caughtErrorsIgnorePattern": "^ignore" }]))),
+        ("var _unused = 1;", Some(serde_json::json!([{ "varsIgnorePattern": "^_" }]))),
+        ("const { a, ...rest } = obj; rest;", Some(serde_json::json!([{ "ignoreRestSiblings": true }]))),
+        ("const { a = 1, ...rest } = obj; rest;", Some(serde_json::json!([{ "ignoreRestSiblings": true }]))),
+        ("import { a } from 'a'; a();", None),
+        ("import a from 'a'; <a.Foo />;", None),
+        ("import Foo from 'foo'; <Foo />;", None),
+        ("import * as ns from 'ns'; ns.a;", None),
+        // TypeScript
+        ("import type { A } from 'a'; let a: A; a;", None),
+        ("import { A } from 'a'; function foo(a: A) { return a; } foo();", None),
+        ("interface A {} export const a: A = {};", None),
+        ("type A = string; export type B = A[];", None),
+        ("enum E { A } console.log(E.A);", None),
+        ("namespace N { export const a = 1; } N.a;", None),
+        ("interface A {} export type { A };", None),
+        ("interface A { a: string } interface A { b: string } export const a: A = { a: '', b: '' };", None),
+        ("interface A {} export interface A { a: string }", None),
+        ("namespace N {} export = N;", None),
+        ("const a = 1; export = a;", None),
+        ("const a = 1; type T = typeof a; export const t: T = 1;", None),
+        ("declare const a: number;", None),
+        ("declare function foo(a: string): void;", None),
+        ("declare global { interface Window { a: string } }", None),
+        ("declare module 'foo' { const a: number; }", None),
+        ("export function foo(a: string): void; export function foo(a: string | number) { return a; }", None),
+        ("export abstract class A { abstract foo(a: string): void; }", None),
+        ("export class A { constructor(private a: string) {} }", None),
+        ("export type F = (a: string) => void;", None),
+        ("export interface I { method(a: string): void }", None),
+        ("export function foo<T>() {}", None),
+        ("export enum E { A, B }", None),
+    ];
+
+    let fail = vec![
+        ("var a = 10;", None),
+        ("let a; a = 10;", None),
+        ("var a = 10; a = 20;", None),
+        ("function foo() {}", None),
+        ("function foo() { foo(); }", None),
+        ("const foo = () => { foo(); };", None),
+        ("class A {}", None),
+        ("let a = 0; a++;", None),
+        ("let a = 0; a += 1;", None),
+        ("let a = 0; a = a + 1;", None),
+        ("export function foo(a, b) { return a; }", None),
+        ("export function foo(a, b) { return a; }", Some(serde_json::json!([{ "args": "all" }]))),
+        ("export function foo(a, b) { return b; }", Some(serde_json::json!([{ "args": "all" }]))),
+        ("export const foo = (a, { b }) => a;", None),
+        (
+            "export function foo(_a, b) { return _a; }",
+            Some(serde_json::json!([{ "argsIgnorePattern": "^_" }])),
+        ),
+        ("try {} catch (e) {}", Some(serde_json::json!([{ "caughtErrors": "all" }]))),
+        ("var unused = 1;", Some(serde_json::json!([{ "varsIgnorePattern": "^_" }]))),
+        ("export function foo() { var a = 1; }", Some(serde_json::json!(["local"]))),
+        ("const { a, ...rest } = obj; rest;", None),
+        (
+            "const { a, b } = obj; export { b };",
+            Some(serde_json::json!([{ "ignoreRestSiblings": true }])),
+        ),
+        // Only direct properties are siblings of the rest element, as in ESLint.
+        (
+            "const { a: { b }, ...rest } = obj; rest;",
+            Some(serde_json::json!([{ "ignoreRestSiblings": true }])),
+        ),
+        ("import { a } from 'a';", None),
+        ("import a, { b } from 'a'; b();", None),
+        ("import * as ns from 'ns';", None),
+        // TypeScript
+        ("type A = string;", None),
+        ("interface A {}", None),
+        ("enum E { A }", None),
+        ("namespace N {}", None),
+        ("export function foo<T>(a: T) {}", None),
+        ("import type { A } from 'a';", None),
+        ("interface A {} interface A { a: string }", None),
+    ];
+
+    let fix = vec![
+        ("import { a } from 'a';\nfoo();", "foo();", None),
+        ("import { a, b } from 'a';\nb();", "import { b } from 'a';\nb();", None),
+        ("import { a, b, c } from 'a';\nb();", "import { b } from 'a';\nb();", None),
+        ("import a, { b } from 'a';\nb();", "import { b } from 'a';\nb();", None),
+        ("import a, * as ns from 'a';\na();", "import a from 'a';\na();", None),
+        ("import a, { b, c, d } from 'a';\nc();", "import { c } from 'a';\nc();", None),
+        ("import {\n  a,\n  b,\n} from 'a';\na();", "import {\n  a,\n} from 'a';\na();", None),
+        (
+            "import a, { b as c } from 'a' with { type: 'json' };\na();",
+            "import a from 'a' with { type: 'json' };\na();",
+            None,
+        ),
+        (
+            "import { type A, B } from 'a';\nlet b: B;\nb;",
+            "import { B } from 'a';\nlet b: B;\nb;",
+            None,
+        ),
+        ("import type { A } from 'a';\nfoo();", "foo();", None),
+    ];
+
+    Tester::new(NoUnusedVars::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
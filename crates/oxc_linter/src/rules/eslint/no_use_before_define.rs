@@ -0,0 +1,346 @@
+use oxc_ast::{
+    ast::{BindingPattern, BindingPatternKind, Class, ClassElement},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{ScopeFlags, ScopeId, SymbolId};
+use oxc_span::{GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
+
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
+
+fn no_use_before_define_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint(no-use-before-define): '{name}' was used before it was defined."
+    ))
+    .with_help(format!("Move the declaration of '{name}' before its first use."))
+    .with_labels([span.into()])
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum NoUseBeforeDefineOption {
+    /// `nofunc`, which is `{ "functions": false }`.
+    NoFunc(NoUseBeforeDefineNoFunc),
+    Object(NoUseBeforeDefineConfig),
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+enum NoUseBeforeDefineNoFunc {
+    NoFunc,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUseBeforeDefine(Box<NoUseBeforeDefineConfig>);
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct NoUseBeforeDefineConfig {
+    /// Check the uses of functions, `true` by default.
+    functions: bool,
+    /// Check the uses of classes in functions declared before the class, `true` by default.
+    classes: bool,
+    /// Check the uses of variables in functions declared before the variable, `true` by default.
+    variables: bool,
+    /// Check the uses of TypeScript type aliases and interfaces, `true` by default.
+    typedefs: bool,
+    /// Ignore the uses in types, e.g. `let a: Foo`, `true` by default.
+    ignore_type_references: bool,
+}
+
+impl std::ops::Deref for NoUseBeforeDefine {
+    type Target = NoUseBeforeDefineConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for NoUseBeforeDefineConfig {
+    fn default() -> Self {
+        Self {
+            functions: true,
+            classes: true,
+            variables: true,
+            typedefs: true,
+            ignore_type_references: true,
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow the use of variables before they are defined.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Using a `let`, `const` or `class` before its declaration throws a `ReferenceError`,
+    /// since it is in its temporal dead zone. A `var` used before its declaration is
+    /// `undefined`, which is rarely intended. In both cases the code is harder to follow.
+    ///
+    /// A variable used in its own initializer, e.g. `const a = a + 1`, or a class used in its
+    /// own `extends` clause, is also reported.
+    ///
+    /// ### Options
+    ///
+    /// Either `"nofunc"`, which is `{ "functions": false }`, or an object:
+    ///
+    /// * `functions`: `false` to not check the uses of functions, which are hoisted.
+    /// * `classes`: `false` to not check the uses of classes in functions declared before the
+    ///   class, which may run after it is declared.
+    /// * `variables`: `false` to not check the uses of variables in functions declared before
+    ///   the variable, which may run after it is declared.
+    /// * `typedefs`: `false` to not check the uses of TypeScript type aliases and interfaces.
+    /// * `ignoreTypeReferences`: `true` (default) to not check the uses in types,
+    ///   e.g. `let a: Foo`.
+    ///
+    /// All of them are checked by default.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// alert(a);
+    /// var a = 10;
+    ///
+    /// f();
+    /// function f() {}
+    ///
+    /// new A();
+    /// class A {}
+    /// ```
+    NoUseBeforeDefine,
+    restriction,
+    ast_types = []
+);
+
+impl Rule for NoUseBeforeDefine {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        match rule_option(&value, 0) {
+            Some(NoUseBeforeDefineOption::NoFunc(NoUseBeforeDefineNoFunc::NoFunc)) => {
+                Self(Box::new(NoUseBeforeDefineConfig {
+                    functions: false,
+                    ..NoUseBeforeDefineConfig::default()
+                }))
+            }
+            Some(NoUseBeforeDefineOption::Object(config)) => Self(Box::new(config)),
+            None => Self::default(),
+        }
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<NoUseBeforeDefineOption>()])
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        !ctx.source_type().is_typescript_definition()
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        let symbols = ctx.symbols();
+        let declaration = ctx.nodes().get_node(symbols.get_declaration(symbol_id));
+        let (is_function, is_class, is_variable, is_typedef) = match declaration.kind() {
+            AstKind::Function(_) => (true, false, false, false),
+            AstKind::Class(_) => (false, true, false, false),
+            AstKind::VariableDeclarator(_) => (false, false, true, false),
+            AstKind::TSTypeAliasDeclaration(_) | AstKind::TSInterfaceDeclaration(_) => {
+                (false, false, false, true)
+            }
+            _ => (false, false, false, false),
+        };
+        if (is_function && !self.functions) || (is_typedef && !self.typedefs) {
+            return;
+        }
+
+        let name = symbols.get_name(symbol_id);
+        let name_span = symbols.get_span(symbol_id);
+        let symbol_function_scope = function_scope(symbols.get_scope_id(symbol_id), ctx);
+        for reference in symbols.get_resolved_references(symbol_id) {
+            if self.ignore_type_references && reference.is_type() {
+                continue;
+            }
+            let node = ctx.nodes().get_node(reference.node_id());
+            let is_separate = function_scope(node.scope_id(), ctx) != symbol_function_scope
+                || is_in_instance_field(node, ctx);
+            if is_separate && ((is_class && !self.classes) || (is_variable && !self.variables)) {
+                continue;
+            }
+            let span = reference.span();
+            if span.end < name_span.end
+                || (!is_separate
+                    && is_evaluated_during_initialization(span, name_span, declaration, ctx))
+            {
+                ctx.diagnostic(no_use_before_define_diagnostic(name, span));
+            }
+        }
+    }
+}
+
+/// The scope of the function or program running the code of the given scope.
+fn function_scope(scope_id: ScopeId, ctx: &LintContext) -> Option<ScopeId> {
+    let scopes = ctx.scopes();
+    scopes
+        .ancestors(scope_id)
+        .find(|&id| scopes.get_flags(id).intersects(ScopeFlags::Top | ScopeFlags::Function))
+}
+
+/// Whether the node is in the initializer of a class field, which runs when the class is
+/// instantiated, e.g. `a` in `class A { b = a; }`.
+fn is_in_instance_field(node: &AstNode, ctx: &LintContext) -> bool {
+    let span = node.kind().span();
+    ctx.nodes()
+        .iter_parents(node.id())
+        .take_while(|parent| {
+            !matches!(parent.kind(), AstKind::Function(_) | AstKind::ArrowFunctionExpression(_))
+        })
+        .any(|parent| {
+            matches!(
+                parent.kind(),
+                AstKind::PropertyDefinition(prop) if !prop.r#static
+                    && prop.value.as_ref().is_some_and(|value| is_inside(span, value.span()))
+            )
+        })
+}
+
+/// Whether the use at `span` runs while the variable is initialized, e.g. in `const a = a + 1`,
+/// `for (const a of a) {}`, `function foo(a = a) {}` or `class A extends A {}`.
+fn is_evaluated_during_initialization(
+    span: Span,
+    name_span: Span,
+    declaration: &AstNode,
+    ctx: &LintContext,
+) -> bool {
+    match declaration.kind() {
+        AstKind::Class(class) => {
+            is_inside(span, class.span) && !is_in_static_initializer(class, span)
+        }
+        AstKind::VariableDeclarator(declarator) => {
+            declarator.init.as_ref().is_some_and(|init| is_inside(span, init.span()))
+                || is_in_default(&declarator.id, name_span, span)
+                || ctx
+                    .nodes()
+                    .parent_node(declaration.id())
+                    .and_then(|decl| ctx.nodes().parent_kind(decl.id()))
+                    .is_some_and(|parent| match parent {
+                        AstKind::ForInStatement(stmt) => is_inside(span, stmt.right.span()),
+                        AstKind::ForOfStatement(stmt) => is_inside(span, stmt.right.span()),
+                        _ => false,
+                    })
+        }
+        AstKind::FormalParameter(param) => is_in_default(&param.pattern, name_span, span),
+        _ => false,
+    }
+}
+
+/// Whether the span is in a static block or static field initializer of the class, which run
+/// after the class is initialized.
+fn is_in_static_initializer(class: &Class, span: Span) -> bool {
+    class.body.body.iter().any(|element| match element {
+        ClassElement::StaticBlock(block) => is_inside(span, block.span),
+        ClassElement::PropertyDefinition(prop) if prop.r#static => {
+            prop.value.as_ref().is_some_and(|value| is_inside(span, value.span()))
+        }
+        _ => false,
+    })
+}
+
+/// Whether the span is in the default value of the binding at `name_span`,
+/// e.g. `a` in `const { a = a } = obj`.
+fn is_in_default(pattern: &BindingPattern, name_span: Span, span: Span) -> bool {
+    match &pattern.kind {
+        BindingPatternKind::BindingIdentifier(_) => false,
+        BindingPatternKind::AssignmentPattern(assign) => {
+            (is_inside(name_span, assign.left.span()) && is_inside(span, assign.right.span()))
+                || is_in_default(&assign.left, name_span, span)
+        }
+        BindingPatternKind::ObjectPattern(object) => {
+            object.properties.iter().any(|property| is_in_default(&property.value, name_span, span))
+                || object
+                    .rest
+                    .as_ref()
+                    .is_some_and(|rest| is_in_default(&rest.argument, name_span, span))
+        }
+        BindingPatternKind::ArrayPattern(array) => {
+            array.elements.iter().flatten().any(|element| is_in_default(element, name_span, span))
+                || array
+                    .rest
+                    .as_ref()
+                    .is_some_and(|rest| is_in_default(&rest.argument, name_span, span))
+        }
+    }
+}
+
+fn is_inside(inner: Span, outer: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var a = 10; alert(a);", None),
+        ("function b(a) { alert(a); }", None),
+        ("Object.hasOwnProperty.call(a);", None),
+        ("function a() { alert(arguments); }", None),
+        ("a(); function a() { alert(arguments); }", Some(json!(["nofunc"]))),
+        ("a(); function a() { alert(arguments); }", Some(json!([{ "functions": false }]))),
+        ("(() => { var a = 42; alert(a); })();", None),
+        ("a(); try { throw new Error(); } catch (a) {}", None),
+        ("class A {} new A();", None),
+        ("var a = 0, b = a;", None),
+        ("var { a = 0, b = a } = {};", None),
+        ("var [a = 0, b = a] = {};", None),
+        ("function foo() { foo(); }", None),
+        ("var foo = function() { foo(); };", None),
+        ("var a; for (a in a) {}", None),
+        ("var a; for (a of a) {}", None),
+        ("function foo() { new A(); } class A {}", Some(json!([{ "classes": false }]))),
+        ("function foo() { bar; } var bar;", Some(json!([{ "variables": false }]))),
+        ("var foo = () => bar; var bar;", Some(json!([{ "variables": false }]))),
+        ("class A { b = a; } const a = 1;", Some(json!([{ "variables": false }]))),
+        ("class C { static { C; } }", None),
+        ("class C { static x = C; }", None),
+        ("class C { foo() { C; } }", None),
+        ("type Foo = string; let a: Foo;", None),
+        ("let a: Foo; type Foo = string;", None),
+        (
+            "let a: Foo; interface Foo {}",
+            Some(json!([{ "typedefs": false, "ignoreTypeReferences": false }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("a++; var a = 19;", None),
+        ("a(); var a = function() {};", None),
+        ("alert(a[1]); var a = [1, 3];", None),
+        ("a(); function a() { alert(b); var b = 10; a(); }", None),
+        ("a(); var a = function() {};", Some(json!(["nofunc"]))),
+        ("(() => { alert(a); var a = 42; })();", None),
+        ("(() => a())(); function a() {}", None),
+        ("new A(); class A {}", None),
+        ("function foo() { new A(); } class A {}", None),
+        ("new A(); var A = class {};", None),
+        ("function foo() { new A(); } var A = class {};", Some(json!([{ "classes": false }]))),
+        ("function foo() { bar; } var bar;", None),
+        ("class A { b = a; } const a = 1;", None),
+        ("var a = a;", None),
+        ("let a = a + b;", None),
+        ("const a = foo(a);", None),
+        ("function foo(a = a) {}", None),
+        ("var { a = a } = [];", None),
+        ("var [a = a] = [];", None),
+        ("for (var a in a) {}", None),
+        ("for (var a of a) {}", None),
+        ("class C extends C {}", None),
+        ("const C = class extends C {};", None),
+        ("class C { [C]; }", None),
+        ("type Foo = Bar; type Bar = string;", Some(json!([{ "ignoreTypeReferences": false }]))),
+    ];
+
+    Tester::new(NoUseBeforeDefine::NAME, pass, fail).test_and_snapshot();
+}
@@ -46,7 +46,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoUselessCatch,
-    correctness
+    correctness,
+    ast_types = [TryStatement]
 );
 
 impl Rule for NoUselessCatch {
@@ -32,7 +32,8 @@ declare_oxc_lint!(
     /// var foo = "a" + "b";
     /// ```
     NoUselessConcat,
-    suspicious
+    suspicious,
+    ast_types = [BinaryExpression]
 );
 
 impl Rule for NoUselessConcat {
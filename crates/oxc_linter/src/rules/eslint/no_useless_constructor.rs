@@ -79,6 +79,7 @@ declare_oxc_lint!(
     ///```
     NoUselessConstructor,
     nursery,
+    ast_types = [MethodDefinition]
 );
 
 impl Rule for NoUselessConstructor {
@@ -27,7 +27,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoUselessEscape,
-    correctness
+    correctness,
+    ast_types = [RegExpLiteral, StringLiteral, TemplateLiteral]
 );
 
 impl Rule for NoUselessEscape {
@@ -5,8 +5,10 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
 
 fn no_useless_rename_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint(no-useless-rename): Disallow renaming import, export, and destructured assignments to the same name")
@@ -18,10 +20,14 @@ fn no_useless_rename_diagnostic(span0: Span) -> OxcDiagnostic {
 pub struct NoUselessRename(Box<NoUselessRenameConfig>);
 
 #[allow(clippy::struct_field_names)]
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoUselessRenameConfig {
+    /// Do not report the renames in destructurings.
     ignore_destructuring: bool,
+    /// Do not report the renames in imports.
     ignore_import: bool,
+    /// Do not report the renames in exports.
     ignore_export: bool,
 }
 
@@ -55,27 +61,17 @@ declare_oxc_lint!(
     /// export { baz };
     /// ```
     NoUselessRename,
-    correctness
+    correctness,
+    ast_types = [ObjectPattern, AssignmentTarget, ImportSpecifier, ExportNamedDeclaration]
 );
 
 impl Rule for NoUselessRename {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
+        Self(Box::new(rule_option(&value, 0)))
+    }
 
-        Self(Box::new(NoUselessRenameConfig {
-            ignore_destructuring: obj
-                .and_then(|v| v.get("ignoreDestructuring"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-            ignore_import: obj
-                .and_then(|v| v.get("ignoreImport"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-            ignore_export: obj
-                .and_then(|v| v.get("ignoreExport"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-        }))
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<NoUselessRenameConfig>()])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -37,7 +37,8 @@ declare_oxc_lint!(
     /// const CONFIG = {};
     /// ```
     NoVar,
-    restriction
+    restriction,
+    ast_types = [VariableDeclaration]
 );
 
 impl Rule for NoVar {
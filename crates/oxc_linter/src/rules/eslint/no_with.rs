@@ -28,7 +28,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoWith,
-    correctness
+    correctness,
+    ast_types = [WithStatement]
 );
 
 impl Rule for NoWith {
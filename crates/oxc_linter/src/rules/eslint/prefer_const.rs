@@ -0,0 +1,432 @@
+use oxc_ast::{
+    ast::{
+        AssignmentExpression, AssignmentTargetPropertyIdentifier, AssignmentTargetPropertyProperty,
+        AssignmentTargetWithDefault, BindingPattern, SimpleAssignmentTarget,
+        VariableDeclarationKind, VariableDeclarator,
+    },
+    syntax_directed_operations::BoundNames,
+    AstKind, Visit,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{ReferenceId, SymbolId};
+use oxc_span::Span;
+use oxc_syntax::operator::AssignmentOperator;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{rule_option, symbol_name_span},
+    AstNode,
+};
+
+fn prefer_const_diagnostic(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("eslint(prefer-const): '{name}' is never reassigned."))
+        .with_help("Use `const` instead.")
+        .with_labels([span.into()])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferConst(Box<PreferConstConfig>);
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PreferConstConfig {
+    /// Which variables of a destructuring are reported, `any` by default.
+    destructuring: DestructuringOption,
+    /// Ignore the variables which are read before their only assignment,
+    /// e.g. by a function declared before it.
+    ignore_read_before_assign: bool,
+}
+
+impl std::ops::Deref for PreferConst {
+    type Target = PreferConstConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Which variables of a destructuring are reported when only some of them are reassigned.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+#[schemars(rename = "PreferConstDestructuring")]
+enum DestructuringOption {
+    /// Report the variables which are never reassigned.
+    #[default]
+    Any,
+    /// Only report the variables if none of them is reassigned.
+    All,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require `const` declarations for variables that are never reassigned after declared.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A `let` variable which is never reassigned makes readers look for the reassignment.
+    /// Declaring it with `const` tells them the value doesn't change.
+    ///
+    /// A `let` variable declared without an initializer is also reported when it is assigned
+    /// exactly once, by an assignment statement in the same block, e.g. `let a; a = 0;`.
+    /// The variables declared by the initializer of a `for` loop are never reported, since
+    /// they can't be declared with `const` separately.
+    ///
+    /// ### Options
+    ///
+    /// * `destructuring`: `"any"` (default) to report the variables of a destructuring which
+    ///   are never reassigned even if others are, or `"all"` to only report them if none is.
+    /// * `ignoreReadBeforeAssign`: ignore the variables which are read before their only
+    ///   assignment, e.g. by a function declared before it.
+    ///
+    /// Declarations where all the variables are initialized and never reassigned are changed to
+    /// `const` by `--fix`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// let a = 3;
+    /// console.log(a);
+    ///
+    /// let b;
+    /// b = 0;
+    /// console.log(b);
+    ///
+    /// for (let i in [1, 2, 3]) {
+    ///   console.log(i);
+    /// }
+    /// ```
+    PreferConst,
+    style,
+    ast_types = [VariableDeclaration]
+);
+
+/// A `let` variable which could be declared with `const`.
+struct Candidate<'a> {
+    /// Where to report the variable: its declaration, or its only assignment.
+    span: Span,
+    /// The assignment initializing the variable, if it isn't initialized by its declaration.
+    assignment: Option<&'a AssignmentExpression<'a>>,
+}
+
+impl Rule for PreferConst {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<PreferConstConfig>()])
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        !ctx.source_type().is_typescript_definition()
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::VariableDeclaration(decl) = node.kind() else {
+            return;
+        };
+        if decl.kind != VariableDeclarationKind::Let || decl.declare {
+            return;
+        }
+        let parent = ctx.nodes().parent_kind(node.id());
+        // In `for (let i = 0, n = a.length; i < n; i++)`, `n` can't be `const` without `i`.
+        if matches!(parent, Some(AstKind::ForStatementInit(_))) {
+            return;
+        }
+        let is_loop_left =
+            matches!(parent, Some(AstKind::ForInStatement(_) | AstKind::ForOfStatement(_)));
+
+        let mut candidates = vec![];
+        let mut all_const = true;
+        for declarator in &decl.declarations {
+            for symbol_id in bound_symbols(&declarator.id) {
+                let candidate = self.check(symbol_id, ctx).filter(|candidate| {
+                    self.destructuring == DestructuringOption::Any
+                        || self.is_group_const(declarator, candidate, ctx)
+                });
+                match candidate {
+                    Some(candidate) => candidates.push((symbol_id, candidate)),
+                    None => all_const = false,
+                }
+            }
+        }
+
+        let fixable =
+            all_const && (is_loop_left || decl.declarations.iter().all(|d| d.init.is_some()));
+        for (symbol_id, candidate) in candidates {
+            let diagnostic =
+                prefer_const_diagnostic(ctx.symbols().get_name(symbol_id), candidate.span);
+            if fixable {
+                ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                    fixer.replace(Span::new(decl.span.start, decl.span.start + 3), "const")
+                });
+            } else {
+                ctx.diagnostic(diagnostic);
+            }
+        }
+    }
+}
+
+impl PreferConst {
+    /// Whether the `let` variable `symbol_id` could be declared with `const`, because it is only
+    /// written by its initializer, or by a single assignment statement in its own scope.
+    fn check<'a>(&self, symbol_id: SymbolId, ctx: &LintContext<'a>) -> Option<Candidate<'a>> {
+        let symbols = ctx.symbols();
+        let declaration = ctx.nodes().get_node(symbols.get_declaration(symbol_id));
+        let AstKind::VariableDeclarator(declarator) = declaration.kind() else {
+            return None;
+        };
+        if declarator.kind != VariableDeclarationKind::Let {
+            return None;
+        }
+
+        let mut writes = symbols.get_resolved_references(symbol_id).filter(|r| r.is_write());
+        if declarator.init.is_some() || is_loop_left(declaration, ctx) {
+            return writes
+                .next()
+                .is_none()
+                .then(|| Candidate { span: symbol_name_span(symbol_id, ctx), assignment: None });
+        }
+
+        let only_write = writes.next()?;
+        if writes.next().is_some() {
+            return None;
+        }
+        let scope_id = symbols.get_scope_id(symbol_id);
+        let write_node = ctx.nodes().get_node(only_write.node_id());
+        if write_node.scope_id() != scope_id {
+            return None;
+        }
+        let assignment = declaring_assignment(write_node, ctx)?;
+        // `[a, b] = arr` can't be turned into a declaration of `a` if `b` is declared elsewhere.
+        let is_local = |id: SymbolId| {
+            symbols.get_scope_id(id) == scope_id
+                && matches!(
+                    ctx.nodes().kind(symbols.get_declaration(id)),
+                    AstKind::VariableDeclarator(_)
+                )
+        };
+        if !assigned_symbols(assignment, ctx)?.into_iter().all(is_local) {
+            return None;
+        }
+
+        let is_read_before = symbols
+            .get_resolved_references(symbol_id)
+            .any(|r| r.is_read() && r.span().start < assignment.span.end);
+        if !is_read_before {
+            return Some(Candidate { span: only_write.span(), assignment: Some(assignment) });
+        }
+        if self.ignore_read_before_assign {
+            return None;
+        }
+        Some(Candidate { span: symbol_name_span(symbol_id, ctx), assignment: Some(assignment) })
+    }
+
+    /// Whether all the `let` variables initialized together with the candidate, by the same
+    /// declarator or destructuring assignment, could be declared with `const`.
+    fn is_group_const(
+        &self,
+        declarator: &VariableDeclarator,
+        candidate: &Candidate,
+        ctx: &LintContext,
+    ) -> bool {
+        let group = match candidate.assignment {
+            Some(assignment) => assigned_symbols(assignment, ctx).unwrap_or_default(),
+            None => bound_symbols(&declarator.id),
+        };
+        group.into_iter().all(|symbol_id| {
+            let declaration = ctx.nodes().kind(ctx.symbols().get_declaration(symbol_id));
+            !matches!(
+                declaration,
+                AstKind::VariableDeclarator(declarator)
+                    if declarator.kind == VariableDeclarationKind::Let
+            ) || self.check(symbol_id, ctx).is_some()
+        })
+    }
+}
+
+fn bound_symbols(pattern: &BindingPattern) -> Vec<SymbolId> {
+    let mut symbols = vec![];
+    pattern.bound_names(&mut |ident| symbols.extend(ident.symbol_id.get()));
+    symbols
+}
+
+/// Whether the declarator is the left side of a `for...in` or `for...of` loop,
+/// which initializes it on each iteration.
+fn is_loop_left(declarator: &AstNode, ctx: &LintContext) -> bool {
+    ctx.nodes().parent_node(declarator.id()).is_some_and(|decl| {
+        matches!(
+            ctx.nodes().parent_kind(decl.id()),
+            Some(AstKind::ForInStatement(_) | AstKind::ForOfStatement(_))
+        )
+    })
+}
+
+/// The assignment writing the reference, if it is a statement which could be turned into a
+/// declaration, e.g. `a = 0;` or `[a, b] = arr;` but not `foo(a = 0)` or `if (x) a = 0;`.
+fn declaring_assignment<'a>(
+    reference: &AstNode<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<&'a AssignmentExpression<'a>> {
+    let mut parents = ctx.nodes().iter_parents(reference.id()).skip(1).skip_while(|parent| {
+        matches!(
+            parent.kind(),
+            AstKind::SimpleAssignmentTarget(_)
+                | AstKind::AssignmentTarget(_)
+                | AstKind::AssignmentTargetWithDefault(_)
+        )
+    });
+    let AstKind::AssignmentExpression(assignment) = parents.next()?.kind() else {
+        return None;
+    };
+    if assignment.operator != AssignmentOperator::Assign {
+        return None;
+    }
+    let mut parents =
+        parents.skip_while(|parent| matches!(parent.kind(), AstKind::ParenthesizedExpression(_)));
+    if !matches!(parents.next()?.kind(), AstKind::ExpressionStatement(_)) {
+        return None;
+    }
+    matches!(
+        parents.next()?.kind(),
+        AstKind::Program(_)
+            | AstKind::BlockStatement(_)
+            | AstKind::FunctionBody(_)
+            | AstKind::StaticBlock(_)
+            | AstKind::SwitchCase(_)
+    )
+    .then_some(assignment)
+}
+
+/// The variables assigned by the assignment, or `None` if it also assigns something else,
+/// e.g. the member in `[a, b.c] = arr` or an undeclared variable.
+fn assigned_symbols(assignment: &AssignmentExpression, ctx: &LintContext) -> Option<Vec<SymbolId>> {
+    let mut targets = AssignmentTargets::default();
+    targets.visit_assignment_target(&assignment.left);
+    if targets.has_other_targets {
+        return None;
+    }
+    targets
+        .references
+        .into_iter()
+        .map(|id| id.and_then(|id| ctx.symbols().get_reference(id).symbol_id()))
+        .collect()
+}
+
+#[derive(Default)]
+struct AssignmentTargets {
+    references: Vec<Option<ReferenceId>>,
+    /// Whether members or TypeScript expressions are assigned, e.g. `a.b` or `a!`.
+    has_other_targets: bool,
+}
+
+impl<'a> Visit<'a> for AssignmentTargets {
+    fn visit_simple_assignment_target(&mut self, target: &SimpleAssignmentTarget<'a>) {
+        match target {
+            SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+                self.references.push(ident.reference_id.get());
+            }
+            _ => self.has_other_targets = true,
+        }
+    }
+
+    fn visit_assignment_target_with_default(&mut self, target: &AssignmentTargetWithDefault<'a>) {
+        self.visit_assignment_target(&target.binding);
+    }
+
+    fn visit_assignment_target_property_identifier(
+        &mut self,
+        ident: &AssignmentTargetPropertyIdentifier<'a>,
+    ) {
+        self.references.push(ident.binding.reference_id.get());
+    }
+
+    fn visit_assignment_target_property_property(
+        &mut self,
+        property: &AssignmentTargetPropertyProperty<'a>,
+    ) {
+        self.visit_assignment_target_maybe_default(&property.binding);
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var x = 0;", None),
+        ("let x;", None),
+        ("let x; { x = 0; } foo(x);", None),
+        ("let x = 0; x = 1;", None),
+        ("let x = 0; x++;", None),
+        ("const x = 0;", None),
+        ("for (let i = 0, end = 10; i < end; ++i) {}", None),
+        ("for (let i in [1, 2, 3]) { i = 0; }", None),
+        ("for (let x of [1, 2, 3]) { x = 0; }", None),
+        ("let f = function() { f = 0; };", None),
+        ("let a; a = 0; a = 1;", None),
+        ("let a; function foo() { a = 0; }", None),
+        ("let a; (function() { a = 0; })();", None),
+        ("let x; if (true) { x = 0; }", None),
+        ("let x; if (true) x = 0;", None),
+        ("let x; foo(x = 0);", None),
+        ("let x; x += 1;", None),
+        ("let x; for (x of xs) {}", None),
+        ("let a; [a, b.c] = [1, 2];", None),
+        ("let a; [a, b] = [1, 2];", None),
+        ("let b; function foo(a) { let c; [a, c] = bar(); }", None),
+        ("let a, b; ({ a, b } = obj); b++;", Some(json!([{ "destructuring": "all" }]))),
+        ("let { a, b } = obj; b = 0;", Some(json!([{ "destructuring": "all" }]))),
+        ("let [a, b] = arr; a = 0;", Some(json!([{ "destructuring": "all" }]))),
+        (
+            "let timer; function init() { clearInterval(timer); } timer = setInterval(init, 100);",
+            Some(json!([{ "ignoreReadBeforeAssign": true }])),
+        ),
+        ("declare let x: number;", None),
+    ];
+
+    let fail = vec![
+        ("let x = 1; foo(x);", None),
+        ("for (let i in [1, 2, 3]) { foo(i); }", None),
+        ("for (let x of [1, 2, 3]) { foo(x); }", None),
+        ("let [a = -1, b] = [1, 2]; foo(a, b);", None),
+        ("let x; x = 0;", None),
+        ("switch (a) { case 0: let x; x = 0; }", None),
+        ("let { a = 0, b } = obj, c = a; b = 0;", None),
+        ("let { a, b } = obj; b = 0;", None),
+        ("let a, b; ({ a, b } = obj);", None),
+        ("let a, b; ({ a, b } = obj);", Some(json!([{ "destructuring": "all" }]))),
+        (
+            "let timer; function init() { clearInterval(timer); } timer = setInterval(init, 100);",
+            None,
+        ),
+        ("class C { static { let a = 1; foo(a); } }", None),
+        ("let a = 1, b = 2; foo(a, b);", None),
+        ("let a: number = 1; foo(a);", None),
+        ("function foo() { let x; x = bar(); return x; }", None),
+    ];
+
+    let fix = vec![
+        ("let x = 1; foo(x);", "const x = 1; foo(x);", None),
+        ("for (let i in [1, 2, 3]) { foo(i); }", "for (const i in [1, 2, 3]) { foo(i); }", None),
+        ("for (let x of [1, 2, 3]) { foo(x); }", "for (const x of [1, 2, 3]) { foo(x); }", None),
+        ("let [a = -1, b] = [1, 2]; foo(a, b);", "const [a = -1, b] = [1, 2]; foo(a, b);", None),
+        (
+            "class C { static { let a = 1; foo(a); } }",
+            "class C { static { const a = 1; foo(a); } }",
+            None,
+        ),
+        ("let a = 1, b = 2; foo(a, b);", "const a = 1, b = 2; foo(a, b);", None),
+        ("let a: number = 1; foo(a);", "const a: number = 1; foo(a);", None),
+        ("let { a, b } = obj; b = 0;", "let { a, b } = obj; b = 0;", None),
+        ("let x; x = 0;", "let x; x = 0;", None),
+    ];
+
+    Tester::new(PreferConst::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
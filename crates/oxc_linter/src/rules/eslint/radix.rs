@@ -5,8 +5,10 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
 
 fn missing_parameters(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint(radix): Missing parameters.").with_labels([span0.into()])
@@ -52,19 +54,17 @@ declare_oxc_lint!(
     /// var num = parseInt("071", 10);  // 71
     /// ```
     Radix,
-    pedantic
+    pedantic,
+    ast_types = [CallExpression]
 );
 
 impl Rule for Radix {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
+        Self { radix_type: rule_option(&value, 0) }
+    }
 
-        Self {
-            radix_type: obj
-                .and_then(serde_json::Value::as_str)
-                .map(RadixType::from)
-                .unwrap_or_default(),
-        }
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<RadixType>()])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -124,22 +124,15 @@ impl Radix {
     }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+#[schemars(rename = "RadixOption")]
 enum RadixType {
     #[default]
     Always,
     AsNeeded,
 }
 
-impl RadixType {
-    pub fn from(raw: &str) -> Self {
-        match raw {
-            "as-needed" => Self::AsNeeded,
-            _ => Self::Always,
-        }
-    }
-}
-
 fn is_default_radix(node: &Argument) -> bool {
     node.to_expression().is_specific_raw_number_literal("10")
 }
@@ -33,6 +33,7 @@ declare_oxc_lint!(
     /// ```
     RequireAwait,
     pedantic,
+    ast_types = [FunctionBody]
 );
 
 impl Rule for RequireAwait {
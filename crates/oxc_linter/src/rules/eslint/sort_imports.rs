@@ -1,7 +1,4 @@
-use std::{
-    fmt::{Display, Write},
-    str::FromStr,
-};
+use std::fmt::{Display, Write};
 
 use itertools::Itertools;
 use oxc_ast::{
@@ -11,8 +8,10 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{CompactStr, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, utils::rule_option};
 
 fn unexpected_syntax_order_diagnostic(
     x0: &ImportKind,
@@ -38,12 +37,18 @@ fn sort_members_alphabetically_diagnostic(x0: &str, span0: Span) -> OxcDiagnosti
 #[derive(Debug, Default, Clone)]
 pub struct SortImports(Box<SortImportsOptions>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct SortImportsOptions {
+    /// Compare the names case-insensitively.
     ignore_case: bool,
+    /// Do not report the order of the import declarations.
     ignore_declaration_sort: bool,
+    /// Do not report the order of the members of each import declaration.
     ignore_member_sort: bool,
+    /// Only compare the declarations of a group separated by blank lines.
     allow_separated_groups: bool,
+    /// The order of the kinds of import declarations, `["none", "all", "multiple", "single"]` by default.
     member_syntax_sort_order: MemberSyntaxSortOrder,
 }
 
@@ -74,61 +79,17 @@ declare_oxc_lint!(
     /// import e from 'bar.js';
     /// ```
     SortImports,
-    style
+    style,
+    ast_types = []
 );
 
 impl Rule for SortImports {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let Some(config) = value.get(0) else {
-            return Self(Box::default());
-        };
-
-        let ignore_case =
-            config.get("ignoreCase").and_then(serde_json::Value::as_bool).unwrap_or_default();
-        let ignore_member_sort =
-            config.get("ignoreMemberSort").and_then(serde_json::Value::as_bool).unwrap_or_default();
-        let ignore_declaration_sort = config
-            .get("ignoreDeclarationSort")
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or_default();
-        let allow_separated_groups = config
-            .get("allowSeparatedGroups")
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or_default();
-
-        let member_syntax_sort_order = config
-            .get("memberSyntaxSortOrder")
-            .and_then(|v| v.as_array())
-            .map(|arr| {
-                // memberSyntaxSortOrder in config file must have 4 items
-                if arr.len() != 4 {
-                    return MemberSyntaxSortOrder::default();
-                }
-
-                let kinds: Vec<ImportKind> = arr
-                    .iter()
-                    .filter_map(|v| v.as_str())
-                    .map(ImportKind::from_str)
-                    .filter_map(Result::ok)
-                    .unique()
-                    .collect();
-
-                // 4 items must all unique and valid.
-                if kinds.len() != 4 {
-                    return MemberSyntaxSortOrder::default();
-                }
+        Self(Box::new(rule_option(&value, 0)))
+    }
 
-                MemberSyntaxSortOrder(kinds)
-            })
-            .unwrap_or_default();
-
-        Self(Box::new(SortImportsOptions {
-            ignore_case,
-            ignore_declaration_sort,
-            ignore_member_sort,
-            allow_separated_groups,
-            member_syntax_sort_order,
-        }))
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<SortImportsOptions>()])
     }
 
     fn run_once(&self, ctx: &LintContext) {
@@ -355,8 +316,9 @@ impl SortImports {
     }
 }
 
-#[derive(Debug, Clone)]
-struct MemberSyntaxSortOrder(Vec<ImportKind>);
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(from = "Vec<ImportKind>")]
+struct MemberSyntaxSortOrder(#[schemars(length(equal = 4))] Vec<ImportKind>);
 
 impl Default for MemberSyntaxSortOrder {
     fn default() -> Self {
@@ -369,6 +331,16 @@ impl Default for MemberSyntaxSortOrder {
     }
 }
 
+impl From<Vec<ImportKind>> for MemberSyntaxSortOrder {
+    fn from(kinds: Vec<ImportKind>) -> Self {
+        // memberSyntaxSortOrder in config file must have 4 unique items
+        if kinds.len() != 4 || kinds.iter().unique().count() != 4 {
+            return MemberSyntaxSortOrder::default();
+        }
+        MemberSyntaxSortOrder(kinds)
+    }
+}
+
 impl std::ops::Deref for MemberSyntaxSortOrder {
     type Target = Vec<ImportKind>;
 
@@ -407,7 +379,9 @@ impl MemberSyntaxSortOrder {
     }
 }
 
-#[derive(Debug, Default, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Default, Clone, Hash, Eq, PartialEq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+#[schemars(rename = "SortImportsKind")]
 enum ImportKind {
     // import from 'foo.js'
     #[default]
@@ -420,20 +394,6 @@ enum ImportKind {
     Single,
 }
 
-impl FromStr for ImportKind {
-    type Err = &'static str;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "none" => Ok(ImportKind::None),
-            "all" => Ok(ImportKind::All),
-            "multiple" => Ok(ImportKind::Multiple),
-            "single" => Ok(ImportKind::Single),
-            _ => Err("Invalid import kind"),
-        }
-    }
-}
-
 impl Display for ImportKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -1,8 +1,10 @@
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{Span, SPAN};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, utils::rule_option};
 
 fn unexpected_unicode_bom_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint(unicode-bom): Unexpected Unicode BOM (Byte Order Mark)")
@@ -40,18 +42,16 @@ declare_oxc_lint!(
     /// ```
     UnicodeBom,
     restriction,
+    ast_types = []
 );
 
 impl Rule for UnicodeBom {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
+        Self { bom_option: rule_option(&value, 0) }
+    }
 
-        Self {
-            bom_option: obj
-                .and_then(serde_json::Value::as_str)
-                .map(BomOptionType::from)
-                .unwrap_or_default(),
-        }
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<BomOptionType>()])
     }
 
     fn run_once(&self, ctx: &LintContext) {
@@ -72,22 +72,15 @@ impl Rule for UnicodeBom {
     }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+#[schemars(rename = "UnicodeBomOption")]
 enum BomOptionType {
     Always,
     #[default]
     Never,
 }
 
-impl BomOptionType {
-    pub fn from(raw: &str) -> Self {
-        match raw {
-            "always" => Self::Always,
-            _ => Self::Never,
-        }
-    }
-}
-
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -6,8 +6,10 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::BinaryOperator;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
 
 fn comparison_with_na_n(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint(use-isnan): Requires calls to isNaN() when checking for NaN")
@@ -35,11 +37,12 @@ fn index_of_na_n(x0: &str, span1: Span) -> OxcDiagnostic {
         .with_labels([span1.into()])
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct UseIsnan {
-    /// Whether to disallow NaN in switch cases and discriminants
+    /// Also report `switch (NaN)` and `case NaN:`, `true` by default.
     enforce_for_switch_case: bool,
-    /// Whether to disallow NaN as arguments of `indexOf` and `lastIndexOf`
+    /// Also report `indexOf(NaN)` and `lastIndexOf(NaN)`.
     enforce_for_index_of: bool,
 }
 
@@ -75,6 +78,7 @@ declare_oxc_lint!(
     /// ```
     UseIsnan,
     correctness,
+    ast_types = [BinaryExpression, SwitchCase, SwitchStatement, CallExpression]
 );
 
 impl Rule for UseIsnan {
@@ -130,21 +134,11 @@ impl Rule for UseIsnan {
     }
 
     fn from_configuration(value: serde_json::Value) -> Self {
-        let (enforce_for_switch_case, enforce_for_index_of) =
-            value.get(0).map_or((true, false), |config| {
-                (
-                    config
-                        .get("enforceForSwitchCase")
-                        .and_then(serde_json::Value::as_bool)
-                        .unwrap_or(true),
-                    config
-                        .get("enforceForIndexOf")
-                        .and_then(serde_json::Value::as_bool)
-                        .unwrap_or_default(),
-                )
-            });
+        rule_option(&value, 0)
+    }
 
-        Self { enforce_for_switch_case, enforce_for_index_of }
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<Self>()])
     }
 }
 
@@ -30,7 +30,8 @@ declare_oxc_lint!(
     /// export * from "./export-all" // export-all.js also export foo
     /// ```
     Export,
-    nursery
+    nursery,
+    ast_types = []
 );
 
 impl Rule for Export {
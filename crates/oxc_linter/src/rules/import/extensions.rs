@@ -0,0 +1,282 @@
+use std::path::Path;
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span, VALID_EXTENSIONS};
+use rustc_hash::FxHashMap;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{is_builtin_module, is_external_root_module, rule_option, ImportType},
+};
+
+fn missing_extension_diagnostic(
+    extension: Option<&str>,
+    specifier: &str,
+    span: Span,
+) -> OxcDiagnostic {
+    let extension = extension.map(|extension| format!("\"{extension}\" ")).unwrap_or_default();
+    OxcDiagnostic::warn(format!(
+        "eslint-plugin-import(extensions): Missing file extension {extension}for \"{specifier}\""
+    ))
+    .with_labels([span.into()])
+}
+
+fn unexpected_extension_diagnostic(extension: &str, specifier: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint-plugin-import(extensions): Unexpected use of file extension \"{extension}\" for \"{specifier}\""
+    ))
+    .with_labels([span.into()])
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+enum ExtensionMode {
+    Always,
+    #[default]
+    Never,
+    Ignore,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum ExtensionsOption {
+    /// Mode of all extensions.
+    Mode(ExtensionsMode),
+    /// Modes by extension.
+    Pattern(ExtensionsPatternOptions),
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+enum ExtensionsMode {
+    Always,
+    Never,
+    Ignore,
+    IgnorePackages,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct ExtensionsPatternOptions {
+    /// Modes by extension, e.g. `{ "json": "always" }`.
+    pattern: Option<FxHashMap<String, ExtensionMode>>,
+    /// Do not report missing extensions in imports of packages, e.g. `lodash/fp`.
+    ignore_packages: Option<bool>,
+    /// Modes by extension given without `pattern`, the other properties are ignored.
+    #[serde(flatten)]
+    extensions: FxHashMap<String, Value>,
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/extensions.md>
+#[derive(Debug, Default, Clone)]
+pub struct Extensions(Box<ExtensionsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct ExtensionsConfig {
+    /// Mode of the extensions without their own mode in `pattern`.
+    default_mode: ExtensionMode,
+    /// Modes by extension, e.g. `{ "js": "never", "json": "always" }`.
+    pattern: FxHashMap<CompactStr, ExtensionMode>,
+    /// Do not require extensions for imports of packages, e.g. `lodash/fp`.
+    ignore_packages: bool,
+}
+
+impl std::ops::Deref for Extensions {
+    type Target = ExtensionsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Ensures consistent use of file extensions in import paths,
+    /// checked against the extension of the file each path resolves to.
+    ///
+    /// ### Options
+    ///
+    /// The first option is the mode of all extensions, `"never"` by default:
+    /// * `"always"`: require the extension.
+    /// * `"never"`: forbid the extension, when the file can be resolved without it,
+    ///   i.e. for the extensions `js`, `mjs`, `cjs`, `jsx`, `ts`, `mts`, `cts` and `tsx`.
+    /// * `"ignorePackages"`: like `"always"`, but imports of packages such as `lodash/fp` are not reported.
+    ///
+    /// The modes of individual extensions are set by an object, e.g. `{ "json": "always" }`,
+    /// given instead of or after the first option. The object may also be given
+    /// as `{ "pattern": { "json": "always" }, "ignorePackages": true }`.
+    ///
+    /// Root packages like `lodash` and Node.js builtin modules are never reported.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // with `"never"`
+    /// import foo from './foo.js';
+    ///
+    /// // with `"always"`
+    /// import foo from './foo';
+    /// ```
+    Extensions,
+    nursery,
+    ast_types = []
+);
+
+impl Rule for Extensions {
+    fn from_configuration(value: Value) -> Self {
+        let mut config = ExtensionsConfig::default();
+        for option in (0..2).filter_map(|index| rule_option(&value, index)) {
+            match option {
+                ExtensionsOption::Mode(ExtensionsMode::IgnorePackages) => {
+                    config.default_mode = ExtensionMode::Always;
+                    config.ignore_packages = true;
+                }
+                ExtensionsOption::Mode(mode) => {
+                    config.default_mode = match mode {
+                        ExtensionsMode::Always => ExtensionMode::Always,
+                        ExtensionsMode::Ignore => ExtensionMode::Ignore,
+                        ExtensionsMode::Never | ExtensionsMode::IgnorePackages => {
+                            ExtensionMode::Never
+                        }
+                    };
+                }
+                ExtensionsOption::Pattern(options) => {
+                    let pattern = options.pattern.unwrap_or_else(|| {
+                        options
+                            .extensions
+                            .into_iter()
+                            .filter_map(|(extension, mode)| {
+                                Some((extension, ExtensionMode::deserialize(mode).ok()?))
+                            })
+                            .collect()
+                    });
+                    for (extension, mode) in pattern {
+                        config.pattern.insert(CompactStr::from(extension), mode);
+                    }
+                    if let Some(ignore_packages) = options.ignore_packages {
+                        config.ignore_packages = ignore_packages;
+                    }
+                }
+            }
+        }
+        Self(Box::new(config))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        let option = gen.subschema_for::<ExtensionsOption>();
+        Some(vec![option.clone(), option])
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.module_record();
+        for (specifier, requested_modules) in &module_record.requested_modules {
+            // Query strings like `./foo.js?raw` are not module paths.
+            if is_builtin_module(specifier)
+                || is_external_root_module(specifier)
+                || specifier.contains('?')
+            {
+                continue;
+            }
+            let resolved_path = module_record
+                .resolved_modules
+                .get(specifier)
+                .and_then(|resolved_path| resolved_path.value().clone());
+            let extension = resolved_path
+                .as_deref()
+                .unwrap_or_else(|| Path::new(specifier.as_str()))
+                .extension()
+                .and_then(|extension| extension.to_str());
+
+            match extension {
+                Some(extension) if specifier.ends_with(&format!(".{extension}")) => {
+                    if self.mode(extension) == ExtensionMode::Never
+                        && VALID_EXTENSIONS.contains(&extension)
+                    {
+                        for requested_module in requested_modules {
+                            ctx.diagnostic(unexpected_extension_diagnostic(
+                                extension,
+                                specifier,
+                                requested_module.span(),
+                            ));
+                        }
+                    }
+                }
+                _ => {
+                    let mode =
+                        extension.map_or(self.default_mode, |extension| self.mode(extension));
+                    let is_package = specifier.starts_with('@')
+                        || ImportType::of(specifier, resolved_path.as_deref())
+                            == ImportType::External;
+                    if mode != ExtensionMode::Always || (self.ignore_packages && is_package) {
+                        continue;
+                    }
+                    for requested_module in
+                        requested_modules.iter().filter(|module| !module.is_type())
+                    {
+                        ctx.diagnostic(missing_extension_diagnostic(
+                            extension,
+                            specifier,
+                            requested_module.span(),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Extensions {
+    fn mode(&self, extension: &str) -> ExtensionMode {
+        self.pattern.get(extension).copied().unwrap_or(self.default_mode)
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("import foo from './bar'", None),
+        ("import foo from './bar'", Some(json!(["never"]))),
+        ("import data from './data.json'", Some(json!(["never"]))),
+        ("import React from 'react'", Some(json!(["always"]))),
+        ("import { foo } from '@org/package'", Some(json!(["always"]))),
+        ("import fs from 'fs'", Some(json!(["always"]))),
+        ("import fs from 'node:fs'", Some(json!(["always"]))),
+        ("import foo from './bar.js'", Some(json!(["always"]))),
+        ("import data from './data.json'", Some(json!(["always"]))),
+        ("import foo from './bar.js'", Some(json!(["ignorePackages"]))),
+        ("import internal from '@org/package/internal'", Some(json!(["ignorePackages"]))),
+        ("import foo from './bar'", Some(json!([{ "js": "never", "json": "always" }]))),
+        ("import data from './data.json'", Some(json!([{ "js": "never", "json": "always" }]))),
+        ("import foo from './bar.js'", Some(json!(["never", { "js": "always" }]))),
+        ("import foo from './bar.js'", Some(json!(["never", { "pattern": { "js": "ignore" } }]))),
+        ("import raw from './bar.js?raw'", None),
+        ("export { foo } from './bar'", None),
+    ];
+
+    let fail = vec![
+        ("import foo from './bar.js'", None),
+        ("import foo from './bar.js'", Some(json!(["never"]))),
+        ("import foo from './bar'", Some(json!(["always"]))),
+        ("import foo from './bar'", Some(json!(["ignorePackages"]))),
+        ("import internal from '@org/package/internal'", Some(json!(["always"]))),
+        ("import foo from './bar.js'", Some(json!(["always", { "js": "never" }]))),
+        ("import foo from './bar'", Some(json!(["never", { "pattern": { "js": "always" } }]))),
+        ("import foo from './does-not-exist'", Some(json!(["always"]))),
+        ("export { foo } from './bar.js'", None),
+        ("export * from './bar'", Some(json!(["always"]))),
+    ];
+
+    Tester::new(Extensions::NAME, pass, fail)
+        .change_rule_path("index.js")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
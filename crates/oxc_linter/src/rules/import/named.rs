@@ -24,7 +24,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     Named,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for Named {
@@ -9,8 +9,10 @@ use oxc_macros::declare_oxc_lint;
 use oxc_semantic::{AstNode, ModuleRecord};
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::module_record::{ExportExportName, ExportImportName, ImportImportName};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, utils::rule_option};
 
 fn no_export(span0: Span, x1: &str, x2: &str) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!(
@@ -38,8 +40,10 @@ fn assignment(span0: Span, x1: &str) -> OxcDiagnostic {
 }
 
 /// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/namespace.md>
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct Namespace {
+    /// Allow computed member accesses on namespaces, e.g. `ns[name]`.
     allow_computed: bool,
 }
 
@@ -50,18 +54,17 @@ declare_oxc_lint!(
     /// Also, will report for computed references (i.e. foo["bar"]()).
     /// Reports on assignment to a member of an imported namespace.
     Namespace,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for Namespace {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
-        Self {
-            allow_computed: obj
-                .and_then(|v| v.get("allowComputed"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-        }
+        rule_option(&value, 0)
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<Self>()])
     }
 
     fn run_once(&self, ctx: &LintContext<'_>) {
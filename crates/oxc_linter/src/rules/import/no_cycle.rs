@@ -8,8 +8,10 @@ use oxc_syntax::{
     module_graph_visitor::{ModuleGraphVisitorBuilder, ModuleGraphVisitorEvent, VisitFoldWhile},
     module_record::ModuleRecord,
 };
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, utils::rule_option};
 
 fn no_cycle_diagnostic(span0: Span, x1: &str) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint-plugin-import(no-cycle): Dependency cycle detected")
@@ -43,6 +45,32 @@ impl Default for NoCycle {
     }
 }
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+struct NoCycleOptions {
+    /// The maximum depth of the dependency chains which are checked, unlimited by default.
+    max_depth: Option<NoCycleMaxDepth>,
+    /// Ignore the type-only imports.
+    ignore_types: bool,
+    /// Ignore the cycles going through external modules.
+    ignore_external: bool,
+    /// Allow the cycles made of dynamic imports.
+    allow_unsafe_dynamic_cyclic_dependency: bool,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum NoCycleMaxDepth {
+    Depth(u32),
+    Unlimited(NoCycleUnlimited),
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+enum NoCycleUnlimited {
+    #[serde(rename = "∞")]
+    Infinity,
+}
+
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -69,31 +97,27 @@ declare_oxc_lint!(
     /// import { b } from './dep-b.js' // reported: Dependency cycle detected.
     /// ```
     NoCycle,
-    restriction
+    restriction,
+    ast_types = []
 );
 
 impl Rule for NoCycle {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
-        Self {
-            max_depth: obj
-                .and_then(|v| v.get("maxDepth"))
-                .and_then(serde_json::Value::as_number)
-                .and_then(serde_json::Number::as_u64)
-                .map_or(u32::MAX, |n| n as u32),
-            ignore_types: obj
-                .and_then(|v| v.get("ignoreTypes"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-            ignore_external: obj
-                .and_then(|v| v.get("ignoreExternal"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-            allow_unsafe_dynamic_cyclic_dependency: obj
-                .and_then(|v| v.get("allowUnsafeDynamicCyclicDependency"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-        }
+        let NoCycleOptions {
+            max_depth,
+            ignore_types,
+            ignore_external,
+            allow_unsafe_dynamic_cyclic_dependency,
+        } = rule_option(&value, 0);
+        let max_depth = match max_depth {
+            Some(NoCycleMaxDepth::Depth(max_depth)) => max_depth,
+            Some(NoCycleMaxDepth::Unlimited(_)) | None => u32::MAX,
+        };
+        Self { max_depth, ignore_types, ignore_external, allow_unsafe_dynamic_cyclic_dependency }
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<NoCycleOptions>()])
     }
 
     fn run_once(&self, ctx: &LintContext<'_>) {
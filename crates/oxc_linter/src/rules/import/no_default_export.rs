@@ -37,7 +37,8 @@ declare_oxc_lint!(
     /// ```
     ///
     NoDefaultExport,
-    restriction
+    restriction,
+    ast_types = []
 );
 
 impl Rule for NoDefaultExport {
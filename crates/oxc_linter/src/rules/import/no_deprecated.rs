@@ -18,7 +18,8 @@ declare_oxc_lint!(
     ///
     /// Reports use of a deprecated name, as indicated by a JSDoc block with a @deprecated tag or TomDoc Deprecated: comment.
     NoDeprecated,
-    nursery
+    nursery,
+    ast_types = []
 );
 
 impl Rule for NoDeprecated {
@@ -2,12 +2,16 @@ use itertools::Itertools;
 use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
 use oxc_macros::declare_oxc_lint;
 use oxc_syntax::module_record::{ImportImportName, RequestedModule};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, utils::rule_option};
 
 /// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-duplicates.md>
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoDuplicates {
+    /// Merge the type-only imports into the value imports as inline `type` specifiers.
     prefer_inline: bool,
 }
 
@@ -16,17 +20,17 @@ declare_oxc_lint!(
     ///
     /// Reports if a resolved path is imported more than once.
     NoDuplicates,
-    suspicious
+    suspicious,
+    ast_types = []
 );
 
 impl Rule for NoDuplicates {
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self {
-            prefer_inline: value
-                .get("preferInline")
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-        }
+        rule_option(&value, 0)
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<Self>()])
     }
 
     fn run_once(&self, ctx: &LintContext<'_>) {
@@ -323,7 +327,7 @@ fn test() {
         (r"import {AValue, type x, BValue} from './foo'; import {type y} from './foo'", None),
         (
             r"import {AValue} from './foo'; import type {AType} from './foo'",
-            Some(json!({ "preferInline": true })),
+            Some(json!([{ "preferInline": true }])),
         ),
     ];
 
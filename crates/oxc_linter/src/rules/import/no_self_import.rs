@@ -27,7 +27,8 @@ declare_oxc_lint!(
     /// const foo = require('./foo')
     /// ```
     NoSelfImport,
-    suspicious
+    suspicious,
+    ast_types = []
 );
 
 impl Rule for NoSelfImport {
@@ -0,0 +1,137 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use regex::Regex;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{is_builtin_module, rule_option},
+};
+
+fn no_unresolved_diagnostic(specifier: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint-plugin-import(no-unresolved): Unable to resolve path to module '{specifier}'"
+    ))
+    .with_labels([span.into()])
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-unresolved.md>
+#[derive(Debug, Default, Clone)]
+pub struct NoUnresolved(Box<NoUnresolvedConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnresolvedConfig {
+    /// Specifiers matching any of these patterns are not reported.
+    ignore: Vec<Regex>,
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default)]
+struct NoUnresolvedOptions {
+    /// Regular expressions of the specifiers which are not reported.
+    ignore: Vec<String>,
+}
+
+impl std::ops::Deref for NoUnresolved {
+    type Target = NoUnresolvedConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Ensures an imported module can be resolved to a module on the local filesystem,
+    /// with the same resolver used to build the module graph, including the `paths` of `--tsconfig`.
+    ///
+    /// Node.js builtin modules are never reported.
+    ///
+    /// ### Options
+    ///
+    /// * `ignore`: regular expressions of the specifiers which are not reported.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// import x from './foo'; // reported if `./foo` does not exist
+    /// import { y } from 'not-installed'; // reported if `not-installed` is not in `node_modules`
+    /// ```
+    NoUnresolved,
+    nursery,
+    ast_types = []
+);
+
+impl Rule for NoUnresolved {
+    fn from_configuration(value: Value) -> Self {
+        let NoUnresolvedOptions { ignore } = rule_option(&value, 0);
+        let ignore = ignore.iter().filter_map(|pattern| Regex::new(pattern).ok()).collect();
+        Self(Box::new(NoUnresolvedConfig { ignore }))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<NoUnresolvedOptions>()])
+    }
+
+    fn run_once(&self, ctx: &LintContext<'_>) {
+        let module_record = ctx.module_record();
+        for (specifier, requested_modules) in &module_record.requested_modules {
+            if is_builtin_module(specifier)
+                || self.ignore.iter().any(|pattern| pattern.is_match(specifier))
+            {
+                continue;
+            }
+            let is_unresolved = module_record
+                .resolved_modules
+                .get(specifier)
+                .is_some_and(|resolved_path| resolved_path.is_none());
+            if !is_unresolved {
+                continue;
+            }
+            for requested_module in requested_modules {
+                ctx.diagnostic(no_unresolved_diagnostic(specifier, requested_module.span()));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("import foo from './bar'", None),
+        ("import foo from './bar.js'", None),
+        ("import data from './data.json'", None),
+        ("import * as deep from './deep/a'", None),
+        ("import React from 'react'", None),
+        ("import { foo } from '@org/package'", None),
+        ("import fs from 'fs'", None),
+        ("import path from 'node:path'", None),
+        ("export { foo } from './bar'", None),
+        ("export * from './bar'", None),
+        ("import foo from './does-not-exist'", Some(json!([{ "ignore": ["^\\./does-not"] }]))),
+        ("import foo from 'not-installed'", Some(json!([{ "ignore": ["^not-"] }]))),
+    ];
+
+    let fail = vec![
+        ("import foo from './does-not-exist'", None),
+        ("import foo from './bar.coffee.js'", None),
+        ("import foo from 'not-installed'", None),
+        ("import { foo } from '@org/not-installed'", None),
+        ("export { foo } from './does-not-exist'", None),
+        ("export * from './does-not-exist'", None),
+        ("import foo from './does-not-exist'", Some(json!([{ "ignore": ["^not-"] }]))),
+    ];
+
+    Tester::new(NoUnresolved::NAME, pass, fail)
+        .change_rule_path("index.js")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
@@ -1,18 +1,67 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{CompactStr, Span};
+use oxc_syntax::module_record::{
+    ExportEntry, ExportExportName, ExportImportName, ImportImportName, ModuleRecord,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
+use serde_json::Value;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{config::Globs, context::LintContext, rule::Rule, utils::rule_option, ModuleGraph};
 
 fn no_exports_found(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint-plugin-import(no-unused-modules): No exports found")
         .with_labels([span0.into()])
 }
 
+fn unused_export(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint-plugin-import(no-unused-modules): exported declaration '{name}' not used within other modules"
+    ))
+    .with_labels([span.into()])
+}
+
 /// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-unused-modules.md>
 #[derive(Debug, Default, Clone)]
-pub struct NoUnusedModules {
+pub struct NoUnusedModules(Box<NoUnusedModulesConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnusedModulesConfig {
     missing_exports: bool,
     unused_exports: bool,
+    /// Files checked for unused exports, all linted files when empty.
+    src: Globs,
+    /// Files whose exports are never reported, e.g. entry points of the application.
+    ignore_exports: Globs,
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+struct NoUnusedModulesOptions {
+    /// Report modules without any exports, `false` by default.
+    missing_exports: bool,
+    /// Report exports not used by other modules, `false` by default.
+    unused_exports: bool,
+    /// Glob patterns of the files checked for unused exports, relative to the current directory.
+    src: Vec<String>,
+    /// Glob patterns of the files whose exports are never reported.
+    ignore_exports: Vec<String>,
+}
+
+impl std::ops::Deref for NoUnusedModules {
+    type Target = NoUnusedModulesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
 }
 
 declare_oxc_lint!(
@@ -23,22 +72,46 @@ declare_oxc_lint!(
     /// * individual exports not being statically imported or requireed from other modules in the same project
     /// * dynamic imports are supported if argument is a literal string
     ///
+    /// ### Options
+    ///
+    /// * `missingExports`: report modules without any exports.
+    /// * `unusedExports`: report exports not imported by any other linted module, or by the modules they import.
+    ///   Imports through `export * from` and `export { name } from` are followed.
+    ///   Exports of the `main`, `bin` and `browser` entry points of the nearest `package.json` are not reported.
+    /// * `src`: glob patterns of the files checked for unused exports, relative to the current directory.
+    /// * `ignoreExports`: glob patterns of the files whose exports are never reported.
+    ///
+    /// Unused exports are found once all files are linted. The exports of each file are reported
+    /// with the options applying to it after `overrides` and nested configuration files,
+    /// while the imports of all linted files are followed.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // a.js
+    /// export const used = 1;
+    /// export const unused = 2; // reported with `unusedExports`
+    ///
+    /// // b.js
+    /// import { used } from './a';
+    /// ```
     NoUnusedModules,
-    nursery
+    nursery,
+    ast_types = []
 );
 
 impl Rule for NoUnusedModules {
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self {
-            missing_exports: value
-                .get("missingExports")
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-            unused_exports: value
-                .get("unusedExports")
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-        }
+        let options: NoUnusedModulesOptions = rule_option(&value, 0);
+        Self(Box::new(NoUnusedModulesConfig {
+            missing_exports: options.missing_exports,
+            unused_exports: options.unused_exports,
+            src: Globs::new(options.src).unwrap_or_default(),
+            ignore_exports: Globs::new(options.ignore_exports).unwrap_or_default(),
+        }))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<NoUnusedModulesOptions>()])
     }
 
     fn run_once(&self, ctx: &LintContext<'_>) {
@@ -46,20 +119,203 @@ impl Rule for NoUnusedModules {
         if self.missing_exports && module_record.local_export_entries.is_empty() {
             ctx.diagnostic(no_exports_found(Span::new(0, 0)));
         }
-        if self.unused_exports {
-            // TODO: implement unused exports
+    }
+
+    fn runs_on_module_graph(&self) -> bool {
+        self.unused_exports
+    }
+
+    fn run_on_module_graph(&self, graph: &ModuleGraph) -> Vec<(PathBuf, OxcDiagnostic)> {
+        if !self.unused_exports {
+            return vec![];
+        }
+        let usages = Usages::new(graph);
+        let mut diagnostics = vec![];
+        for module in graph.modules() {
+            let path = graph.path(module);
+            let relative_path = path.strip_prefix(graph.cwd()).unwrap_or(path);
+            if !graph.is_linted(path)
+                || (!self.src.patterns().is_empty() && !self.src.is_match(relative_path))
+                || self.ignore_exports.is_match(relative_path)
+            {
+                continue;
+            }
+            let usage = usages.get(path);
+            if usage.is_some_and(|usage| usage.all) {
+                continue;
+            }
+            let unused = exports(module)
+                .filter_map(|entry| Some((export_name(entry)?, entry.export_name.span()?)))
+                .filter(|(name, _)| !usage.is_some_and(|usage| usage.is_used(name)))
+                .collect::<Vec<_>>();
+            if unused.is_empty() || is_package_entry_point(path) {
+                continue;
+            }
+            diagnostics.extend(
+                unused
+                    .into_iter()
+                    .map(|(name, span)| (path.to_path_buf(), unused_export(name, span))),
+            );
         }
+        diagnostics
     }
 }
 
+/// The names imported from a module by other modules.
+#[derive(Debug, Default)]
+struct Usage {
+    names: FxHashSet<CompactStr>,
+    /// Imported as a namespace object, e.g. `import * as ns from "mod"`.
+    all: bool,
+    /// Imported as a namespace object through `export * from "mod"`, which does not include `default`.
+    all_but_default: bool,
+}
+
+impl Usage {
+    fn is_used(&self, name: &str) -> bool {
+        self.all || (self.all_but_default && name != "default") || self.names.contains(name)
+    }
+}
+
+/// What a module imports from another one.
+enum Imported {
+    Name(CompactStr),
+    All,
+    AllButDefault,
+}
+
+/// The usages of all modules, following re-exports.
+struct Usages {
+    usages: FxHashMap<PathBuf, Usage>,
+}
+
+impl Usages {
+    fn new(graph: &ModuleGraph) -> Self {
+        let mut stack = vec![];
+        for module in graph.modules() {
+            for entry in &module.import_entries {
+                let imported = match &entry.import_name {
+                    ImportImportName::Name(name) => Imported::Name(name.name().clone()),
+                    ImportImportName::Default(_) => Imported::Name(CompactStr::new("default")),
+                    ImportImportName::NamespaceObject => Imported::All,
+                };
+                if let Some(target) = loaded_module(module, entry.module_request.name()) {
+                    stack.push((target, imported));
+                }
+            }
+            // `export { name } from "mod"` counts as using `name`, like in eslint-plugin-import.
+            for entry in &module.indirect_export_entries {
+                let imported = match &entry.import_name {
+                    ExportImportName::Name(name) => Imported::Name(name.name().clone()),
+                    ExportImportName::All => Imported::All,
+                    ExportImportName::AllButDefault | ExportImportName::Null => continue,
+                };
+                let target = entry
+                    .module_request
+                    .as_ref()
+                    .and_then(|request| loaded_module(module, request.name()));
+                if let Some(target) = target {
+                    stack.push((target, imported));
+                }
+            }
+        }
+
+        let mut usages = FxHashMap::<PathBuf, Usage>::default();
+        while let Some((module, imported)) = stack.pop() {
+            let usage = usages.entry(graph.path(&module).to_path_buf()).or_default();
+            // Names not exported by the module itself may be exported by `export * from "mod"`.
+            let forwarded = match imported {
+                Imported::Name(name) => {
+                    let is_own_export = name == "default"
+                        || exports(&module).any(|entry| export_name(entry) == Some(name.as_str()));
+                    if !usage.names.insert(name.clone()) || is_own_export {
+                        continue;
+                    }
+                    Imported::Name(name)
+                }
+                Imported::All if !usage.all => {
+                    usage.all = true;
+                    Imported::AllButDefault
+                }
+                Imported::AllButDefault if !usage.all && !usage.all_but_default => {
+                    usage.all_but_default = true;
+                    Imported::AllButDefault
+                }
+                Imported::All | Imported::AllButDefault => continue,
+            };
+            for entry in &module.star_export_entries {
+                let target = entry
+                    .module_request
+                    .as_ref()
+                    .and_then(|request| loaded_module(&module, request.name()));
+                if let Some(target) = target {
+                    let imported = match &forwarded {
+                        Imported::Name(name) => Imported::Name(name.clone()),
+                        Imported::All | Imported::AllButDefault => Imported::AllButDefault,
+                    };
+                    stack.push((target, imported));
+                }
+            }
+        }
+        Self { usages }
+    }
+
+    fn get(&self, path: &Path) -> Option<&Usage> {
+        self.usages.get(path)
+    }
+}
+
+fn loaded_module(module: &ModuleRecord, request: &str) -> Option<Arc<ModuleRecord>> {
+    module.loaded_modules.get(request).map(|module| Arc::clone(module.value()))
+}
+
+/// The local and indirect exports of a module, i.e. all but `export * from "mod"`.
+fn exports(module: &ModuleRecord) -> impl Iterator<Item = &ExportEntry> {
+    module.local_export_entries.iter().chain(&module.indirect_export_entries)
+}
+
+fn export_name(entry: &ExportEntry) -> Option<&str> {
+    match &entry.export_name {
+        ExportExportName::Name(name) => Some(name.name().as_str()),
+        ExportExportName::Default(_) => Some("default"),
+        ExportExportName::Null => None,
+    }
+}
+
+/// Whether `path` is the `main`, `bin` or `browser` entry point of the nearest `package.json`,
+/// whose exports are used by other packages.
+fn is_package_entry_point(path: &Path) -> bool {
+    let Some((dir, package_json)) = path.ancestors().skip(1).find_map(|dir| {
+        let json = fs::read_to_string(dir.join("package.json")).ok()?;
+        Some((dir, serde_json::from_str::<Value>(&json).ok()?))
+    }) else {
+        return false;
+    };
+    let mut entry_points = vec![];
+    for key in ["main", "bin", "browser"] {
+        match package_json.get(key) {
+            Some(Value::String(entry_point)) => entry_points.push(entry_point.as_str()),
+            Some(Value::Object(entry_points_by_name)) => {
+                entry_points.extend(entry_points_by_name.values().filter_map(Value::as_str));
+            }
+            _ => {}
+        }
+    }
+    entry_points.into_iter().any(|entry_point| {
+        let entry_point = dir.join(entry_point.trim_start_matches("./"));
+        // The extension may be omitted, e.g. `"main": "lib/index"`.
+        entry_point == path || entry_point == path.with_extension("")
+    })
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
     use serde_json::json;
 
-    let missing_exports_options = json!({
+    let missing_exports_options = json!([{
       "missingExports": true,
-    });
+    }]);
 
     let pass = vec![
         ("export default function noOptions() {}", None),
@@ -94,23 +350,4 @@ fn test() {
         .change_rule_path("missing-exports.js")
         .with_import_plugin(true)
         .test_and_snapshot();
-
-    // TODO: support unused exports
-    // let unused_exports_options = json!({
-    //   "unusedExports": true,
-    //   "src": ["./no-unused-modules/**/*.js"],
-    //   "ignoreExports": ["./no-unused-modules/*ignored*.js"],
-    // });
-
-    // let pass = vec![
-    //     ("export default function noOptions() {}", None),
-    //     ("export default () => 1", Some(unused_exports_options)),
-    // ];
-
-    // let fail = vec![];
-
-    // Tester::new(NoUnusedModules::NAME, pass, fail)
-    //     .change_rule_path("unused-exports.js")
-    //     .with_import_plugin(true)
-    //     .test_and_snapshot();
 }
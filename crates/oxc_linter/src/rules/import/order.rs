@@ -0,0 +1,705 @@
+use std::cmp::Reverse;
+
+use oxc_ast::{
+    ast::{ImportDeclaration, Statement},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{rule_option, ImportType},
+};
+
+fn out_of_order_diagnostic(name: &str, order: &str, other: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint-plugin-import(order): `{name}` import should occur {order} import of `{other}`"
+    ))
+    .with_labels([span.into()])
+}
+
+fn missing_newline_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "eslint-plugin-import(order): There should be at least one empty line between import groups",
+    )
+    .with_labels([span.into()])
+}
+
+fn newline_within_group_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "eslint-plugin-import(order): There should be no empty line within import group",
+    )
+    .with_labels([span.into()])
+}
+
+fn newline_between_groups_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "eslint-plugin-import(order): There should be no empty line between import groups",
+    )
+    .with_labels([span.into()])
+}
+
+const DEFAULT_GROUPS: [&str; 5] = ["builtin", "external", "parent", "sibling", "index"];
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum NewlinesBetween {
+    #[default]
+    Ignore,
+    Always,
+    Never,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+#[schemars(rename = "ImportOrderAlphabetizeOrder")]
+enum AlphabetizeOrder {
+    #[default]
+    Ignore,
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+#[schemars(rename = "ImportOrderOptions")]
+struct OrderOptions {
+    /// The order of the groups, groups in a nested array share the same rank.
+    groups: Option<Vec<OrderGroups>>,
+    /// Whether empty lines are required between groups, `ignore` by default.
+    #[serde(rename = "newlines-between")]
+    newlines_between: Option<OrderNewlinesBetween>,
+    /// The order of the imports within each group by module name.
+    alphabetize: Option<OrderAlphabetize>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+#[schemars(rename = "ImportOrderGroups")]
+enum OrderGroups {
+    Group(OrderGroup),
+    Groups(Vec<OrderGroup>),
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+#[schemars(rename = "ImportOrderGroup")]
+enum OrderGroup {
+    Builtin,
+    External,
+    Internal,
+    Unknown,
+    Parent,
+    Sibling,
+    Index,
+    Object,
+    Type,
+}
+
+impl OrderGroup {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Builtin => "builtin",
+            Self::External => "external",
+            Self::Internal => "internal",
+            Self::Unknown => "unknown",
+            Self::Parent => "parent",
+            Self::Sibling => "sibling",
+            Self::Index => "index",
+            Self::Object => "object",
+            Self::Type => "type",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+#[schemars(rename = "ImportOrderNewlinesBetween")]
+enum OrderNewlinesBetween {
+    Ignore,
+    Always,
+    /// Not supported yet, treated like `ignore`.
+    AlwaysAndInsideGroups,
+    Never,
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+#[schemars(rename = "ImportOrderAlphabetize")]
+struct OrderAlphabetize {
+    /// `asc`, `desc`, or `ignore` (default).
+    order: AlphabetizeOrder,
+    /// Whether the module names are compared case-insensitively, `false` by default.
+    case_insensitive: bool,
+}
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/order.md>
+#[derive(Debug, Default, Clone)]
+pub struct Order(Box<OrderConfig>);
+
+#[derive(Debug, Clone)]
+pub struct OrderConfig {
+    /// Rank of the groups by name, e.g. `builtin`, or `type` for `import type` declarations.
+    ranks: FxHashMap<String, usize>,
+    /// Rank of the groups missing from the `groups` option, which come last.
+    omitted_rank: usize,
+    newlines_between: NewlinesBetween,
+    alphabetize: AlphabetizeOrder,
+    case_insensitive: bool,
+}
+
+impl Default for OrderConfig {
+    fn default() -> Self {
+        Self::new(&DEFAULT_GROUPS.map(|group| vec![group.to_string()]))
+    }
+}
+
+impl OrderConfig {
+    fn new(groups: &[Vec<String>]) -> Self {
+        let mut ranks = FxHashMap::default();
+        for (rank, group) in groups.iter().enumerate() {
+            for name in group {
+                ranks.entry(name.clone()).or_insert(rank);
+            }
+        }
+        Self {
+            ranks,
+            omitted_rank: groups.len(),
+            newlines_between: NewlinesBetween::default(),
+            alphabetize: AlphabetizeOrder::default(),
+            case_insensitive: false,
+        }
+    }
+}
+
+impl std::ops::Deref for Order {
+    type Target = OrderConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces a convention in the order of `import` declarations, by the kind of module they import:
+    /// 1. `builtin`: Node.js builtin modules, e.g. `fs` or `node:path`.
+    /// 2. `external`: packages, e.g. `react` or `@scope/package`.
+    /// 3. `internal`: packages resolved outside of `node_modules`, e.g. with the `paths` of `--tsconfig`.
+    /// 4. `parent`: modules in parent directories, e.g. `../foo`.
+    /// 5. `sibling`: modules in the same directory or its subdirectories, e.g. `./foo`.
+    /// 6. `index`: the index of the current directory, e.g. `./` or `./index.js`.
+    ///
+    /// Imports without bindings like `import './polyfill'` are ignored, as their order may matter.
+    ///
+    /// ### Options
+    ///
+    /// * `groups`: the order of the groups, `["builtin", "external", "parent", "sibling", "index"]` by default.
+    ///   Groups in a nested array share the same rank, e.g. `["builtin", ["parent", "sibling"]]`.
+    ///   Groups missing from the option come last. The `type` group contains the `import type` declarations.
+    /// * `newlines-between`: `"ignore"` (default), `"always"` to require an empty line between groups and none within them,
+    ///   or `"never"` to forbid empty lines between imports.
+    /// * `alphabetize`: `{ "order": "asc" | "desc" | "ignore", "caseInsensitive": boolean }`,
+    ///   the order of the imports within each group by module name.
+    ///
+    /// Imports out of order are fixed by sorting the import declarations,
+    /// unless other statements or comments are between them.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // reported, the builtin module `fs` should be imported before the sibling module `./foo`
+    /// import foo from './foo';
+    /// import fs from 'fs';
+    /// ```
+    Order,
+    style,
+    ast_types = []
+);
+
+/// An import declaration with its rank, imports of lower rank come first.
+struct RankedImport<'a, 'b> {
+    decl: &'b ImportDeclaration<'a>,
+    /// Index of the declaration in the program body.
+    index: usize,
+    group_rank: usize,
+    /// Position of the module name among the sorted names of its group, `0` without `alphabetize`.
+    alphabet_rank: usize,
+}
+
+impl RankedImport<'_, '_> {
+    fn name(&self) -> &str {
+        self.decl.source.value.as_str()
+    }
+
+    fn rank(&self) -> (usize, usize) {
+        (self.group_rank, self.alphabet_rank)
+    }
+}
+
+impl Rule for Order {
+    fn from_configuration(value: Value) -> Self {
+        let options: OrderOptions = rule_option(&value, 0);
+
+        let mut order = options
+            .groups
+            .map(|groups| {
+                let groups = groups
+                    .iter()
+                    .map(|group| match group {
+                        OrderGroups::Group(group) => vec![group.as_str().to_string()],
+                        OrderGroups::Groups(groups) => {
+                            groups.iter().map(|group| group.as_str().to_string()).collect()
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                OrderConfig::new(&groups)
+            })
+            .unwrap_or_default();
+
+        order.newlines_between = match options.newlines_between {
+            Some(OrderNewlinesBetween::Always) => NewlinesBetween::Always,
+            Some(OrderNewlinesBetween::Never) => NewlinesBetween::Never,
+            _ => NewlinesBetween::Ignore,
+        };
+
+        if let Some(alphabetize) = options.alphabetize {
+            order.alphabetize = alphabetize.order;
+            order.case_insensitive = alphabetize.case_insensitive;
+        }
+
+        Self(Box::new(order))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<OrderOptions>()])
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(root) = ctx.nodes().root_node() else {
+            return;
+        };
+        let AstKind::Program(program) = root.kind() else { unreachable!() };
+
+        let module_record = ctx.module_record();
+        let mut imports = program
+            .body
+            .iter()
+            .enumerate()
+            .filter_map(|(index, statement)| {
+                let Statement::ImportDeclaration(decl) = statement else {
+                    return None;
+                };
+                decl.specifiers.as_ref()?;
+                let specifier = decl.source.value.as_str();
+                let resolved_path = module_record
+                    .resolved_modules
+                    .get(specifier)
+                    .and_then(|resolved_path| resolved_path.value().clone());
+                let import_type = ImportType::of(specifier, resolved_path.as_deref());
+                let type_rank =
+                    if decl.import_kind.is_type() { self.ranks.get("type") } else { None };
+                let group_rank = type_rank
+                    .or_else(|| self.ranks.get(import_type.as_str()))
+                    .copied()
+                    .unwrap_or(self.omitted_rank);
+                Some(RankedImport { decl, index, group_rank, alphabet_rank: 0 })
+            })
+            .collect::<Vec<_>>();
+
+        if imports.len() < 2 {
+            return;
+        }
+
+        self.rank_alphabetically(&mut imports);
+        self.report_out_of_order(&imports, ctx);
+        if self.newlines_between != NewlinesBetween::Ignore {
+            self.report_newlines_between(&imports, ctx);
+        }
+    }
+}
+
+impl Order {
+    fn rank_alphabetically(&self, imports: &mut [RankedImport]) {
+        if self.alphabetize == AlphabetizeOrder::Ignore {
+            return;
+        }
+        let key = |import: &RankedImport| {
+            if self.case_insensitive {
+                import.name().to_lowercase()
+            } else {
+                import.name().to_string()
+            }
+        };
+        let mut names =
+            imports.iter().map(|import| (import.group_rank, key(import))).collect::<Vec<_>>();
+        names.sort_unstable_by(|(a_rank, a), (b_rank, b)| {
+            a_rank.cmp(b_rank).then_with(|| match self.alphabetize {
+                AlphabetizeOrder::Desc => b.cmp(a),
+                _ => a.cmp(b),
+            })
+        });
+        names.dedup();
+        for import in &mut *imports {
+            let name = (import.group_rank, key(import));
+            let position = names.iter().position(|other| *other == name).unwrap_or_default();
+            // Positions increase across groups, but only their order within a group matters.
+            import.alphabet_rank = position;
+        }
+    }
+
+    /// Report the imports of lower rank than an import before them, like eslint-plugin-import,
+    /// which reports them as "after" imports of lower rank instead when this reports fewer imports.
+    fn report_out_of_order(&self, imports: &[RankedImport], ctx: &LintContext) {
+        let out_of_order = find_out_of_order(imports.iter().map(RankedImport::rank));
+        if out_of_order.is_empty() {
+            return;
+        }
+        let reversed_out_of_order =
+            find_out_of_order(imports.iter().rev().map(|import| Reverse(import.rank())));
+
+        let fix_span = sort_fix_span(imports, ctx);
+        let report = |import: &RankedImport, order: &str, other: &RankedImport| {
+            let diagnostic =
+                out_of_order_diagnostic(import.name(), order, other.name(), import.decl.span);
+            match fix_span {
+                Some(span) => ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                    fixer.replace(span, self.sorted_imports(imports, ctx))
+                }),
+                None => ctx.diagnostic(diagnostic),
+            }
+        };
+
+        if reversed_out_of_order.len() < out_of_order.len() {
+            for i in reversed_out_of_order {
+                let import = &imports[imports.len() - 1 - i];
+                // The last import before which `import` should occur.
+                if let Some(other) = imports.iter().rev().find(|other| other.rank() < import.rank())
+                {
+                    report(import, "after", other);
+                }
+            }
+        } else {
+            for i in out_of_order {
+                let import = &imports[i];
+                // The first import after which `import` should occur.
+                if let Some(other) = imports.iter().find(|other| other.rank() > import.rank()) {
+                    report(import, "before", other);
+                }
+            }
+        }
+    }
+
+    /// The source text of the import declarations sorted by rank, separated by new lines,
+    /// and by empty lines between groups with `newlines-between: always`.
+    fn sorted_imports(&self, imports: &[RankedImport], ctx: &LintContext) -> String {
+        let mut sorted = imports.iter().collect::<Vec<_>>();
+        sorted.sort_by_key(|import| import.rank());
+        let mut text = String::new();
+        for (i, import) in sorted.iter().enumerate() {
+            if i > 0 {
+                text.push('\n');
+                if self.newlines_between == NewlinesBetween::Always
+                    && sorted[i - 1].group_rank != import.group_rank
+                {
+                    text.push('\n');
+                }
+            }
+            text.push_str(ctx.source_range(import.decl.span));
+        }
+        text
+    }
+
+    fn report_newlines_between(&self, imports: &[RankedImport], ctx: &LintContext) {
+        for pair in imports.windows(2) {
+            let (previous, current) = (&pair[0], &pair[1]);
+            let between = Span::new(previous.decl.span.end, current.decl.span.start);
+            let empty_lines = count_empty_lines(ctx.source_range(between));
+            let is_fixable = current.index == previous.index + 1
+                && !ctx.semantic().trivias().has_comments_between(between);
+
+            if self.newlines_between == NewlinesBetween::Always
+                && previous.group_rank != current.group_rank
+            {
+                if empty_lines == 0 {
+                    let diagnostic = missing_newline_diagnostic(previous.decl.span);
+                    if is_fixable {
+                        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                            fixer.insert_text_after_range(previous.decl.span, "\n")
+                        });
+                    } else {
+                        ctx.diagnostic(diagnostic);
+                    }
+                }
+                continue;
+            }
+
+            if empty_lines > 0 {
+                let diagnostic = if self.newlines_between == NewlinesBetween::Always {
+                    newline_within_group_diagnostic(previous.decl.span)
+                } else {
+                    newline_between_groups_diagnostic(previous.decl.span)
+                };
+                if is_fixable {
+                    // Keep the indentation of the current import.
+                    let indentation =
+                        ctx.source_range(between).rsplit('\n').next().unwrap_or_default();
+                    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                        fixer.replace(between, format!("\n{indentation}"))
+                    });
+                } else {
+                    ctx.diagnostic(diagnostic);
+                }
+            }
+        }
+    }
+}
+
+/// The span of all import declarations, if they can be sorted by rewriting it,
+/// i.e. when no other statements or comments are between them.
+fn sort_fix_span(imports: &[RankedImport], ctx: &LintContext) -> Option<Span> {
+    let (first, last) = (imports.first()?, imports.last()?);
+    if last.index - first.index + 1 != imports.len() {
+        return None;
+    }
+    let span = first.decl.span.merge(&last.decl.span);
+    (!ctx.semantic().trivias().has_comments_between(span)).then_some(span)
+}
+
+/// The indices of the ranks lower than the highest rank before them.
+fn find_out_of_order<T: Ord, I: Iterator<Item = T>>(ranks: I) -> Vec<usize> {
+    let mut max_rank: Option<T> = None;
+    let mut out_of_order = vec![];
+    for (i, rank) in ranks.enumerate() {
+        match &max_rank {
+            Some(max) if rank < *max => out_of_order.push(i),
+            Some(max) if rank == *max => {}
+            _ => max_rank = Some(rank),
+        }
+    }
+    out_of_order
+}
+
+/// The number of empty lines in `text`, which is between two statements.
+fn count_empty_lines(text: &str) -> usize {
+    let lines = text.split('\n').collect::<Vec<_>>();
+    if lines.len() < 3 {
+        return 0;
+    }
+    lines[1..lines.len() - 1].iter().filter(|line| line.trim().is_empty()).count()
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            r"
+import fs from 'fs';
+import React from 'react';
+import foo from '../foo';
+import bar from './bar';
+import index from './';",
+            None,
+        ),
+        (
+            r"
+import fs from 'fs';
+import './polyfill';
+import { foo } from '@org/package';
+import bar from './bar';",
+            None,
+        ),
+        (
+            r"
+import fs from 'fs';
+import path from 'path';",
+            None,
+        ),
+        (
+            r"
+import bar from './bar';
+import fs from 'fs';",
+            Some(json!([{ "groups": [["sibling", "builtin"]] }])),
+        ),
+        (
+            r"
+import bar from './bar';
+import React from 'react';
+import fs from 'fs';",
+            Some(json!([{ "groups": ["sibling", "external"] }])),
+        ),
+        (
+            r"
+import fs from 'fs';
+
+import React from 'react';
+
+import bar from './bar';
+import baz from './baz';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            r"
+import fs from 'fs';
+import React from 'react';
+import bar from './bar';",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            r"
+import path from 'path';
+import fs from 'fs';",
+            None,
+        ),
+        (
+            r"
+import fs from 'fs';
+import path from 'path';
+import chai from 'chai';
+import React from 'react';",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            r"
+import React from 'react';
+import chai from 'chai';",
+            Some(json!([{ "alphabetize": { "order": "desc" } }])),
+        ),
+        (
+            r"
+import a from './A';
+import b from './b';",
+            Some(json!([{ "alphabetize": { "order": "asc", "caseInsensitive": true } }])),
+        ),
+    ];
+
+    let fail = vec![
+        (
+            r"
+import bar from './bar';
+import fs from 'fs';",
+            None,
+        ),
+        (
+            r"
+import foo from '../foo';
+import React from 'react';
+import bar from './bar';",
+            None,
+        ),
+        (
+            r"
+import index from './';
+import bar from './bar';
+import foo from '../foo';
+import React from 'react';
+import fs from 'fs';",
+            None,
+        ),
+        (
+            r"
+import bar from './bar';
+// comment
+import fs from 'fs';",
+            None,
+        ),
+        (
+            r"
+import fs from 'fs';
+import bar from './bar';",
+            Some(json!([{ "groups": ["sibling", "builtin"] }])),
+        ),
+        (
+            r"
+import fs from 'fs';
+import React from 'react';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            r"
+import fs from 'fs';
+
+import path from 'path';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            r"
+import fs from 'fs';
+
+import React from 'react';",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            r"
+import path from 'path';
+import fs from 'fs';",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+        (
+            r"
+import chai from 'chai';
+import React from 'react';",
+            Some(json!([{ "alphabetize": { "order": "desc" } }])),
+        ),
+        (
+            r"
+import b from './b';
+import a from './A';",
+            Some(json!([{ "alphabetize": { "order": "asc", "caseInsensitive": true } }])),
+        ),
+    ];
+
+    let fix = vec![
+        (
+            "import bar from './bar';\nimport fs from 'fs';",
+            "import fs from 'fs';\nimport bar from './bar';",
+            None,
+        ),
+        (
+            "import foo from '../foo';\nimport React from 'react';\nimport bar from './bar';",
+            "import React from 'react';\nimport foo from '../foo';\nimport bar from './bar';",
+            None,
+        ),
+        (
+            "import bar from './bar';\nimport React from 'react';\nimport fs from 'fs';",
+            "import fs from 'fs';\n\nimport React from 'react';\n\nimport bar from './bar';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\nimport React from 'react';",
+            "import fs from 'fs';\n\nimport React from 'react';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\n\n\nimport path from 'path';",
+            "import fs from 'fs';\nimport path from 'path';",
+            Some(json!([{ "newlines-between": "always" }])),
+        ),
+        (
+            "import fs from 'fs';\n\nimport React from 'react';",
+            "import fs from 'fs';\nimport React from 'react';",
+            Some(json!([{ "newlines-between": "never" }])),
+        ),
+        (
+            "import path from 'path';\nimport fs from 'fs';",
+            "import fs from 'fs';\nimport path from 'path';",
+            Some(json!([{ "alphabetize": { "order": "asc" } }])),
+        ),
+    ];
+
+    Tester::new(Order::NAME, pass, fail)
+        .change_rule_path("index.js")
+        .with_import_plugin(true)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -7,14 +7,16 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use regex::Regex;
 use rustc_hash::FxHashSet;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     ast_util::get_declaration_of_variable,
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, get_node_name, is_type_of_jest_fn_call, JestFnKind,
-        JestGeneralFnKind, PossibleJestNode,
+        collect_possible_jest_call_node, get_node_name, is_type_of_jest_fn_call,
+        may_contain_jest_fn_call, rule_option, JestFnKind, JestGeneralFnKind, PossibleJestNode,
     },
 };
 
@@ -50,6 +52,17 @@ impl Default for ExpectExpectConfig {
     }
 }
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct ExpectExpectOptions {
+    /// Names of the functions making assertions, `["expect"]` by default.
+    /// They may contain `*` wildcards, e.g. `request.*.expect`.
+    assert_function_names: Option<Vec<String>>,
+    /// Names of the functions, besides `it` and `test`, declaring tests.
+    #[serde(default)]
+    additional_test_block_functions: Vec<String>,
+}
+
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -73,23 +86,12 @@ declare_oxc_lint!(
 
 impl Rule for ExpectExpect {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let default_assert_function_names = vec![String::from("expect")];
-        let config = value.get(0);
-
-        let assert_function_names = config
-            .and_then(|config| config.get("assertFunctionNames"))
-            .and_then(serde_json::Value::as_array)
-            .map_or(default_assert_function_names, |v| {
-                v.iter().filter_map(serde_json::Value::as_str).map(convert_pattern).collect()
-            });
-
-        let additional_test_block_functions = config
-            .and_then(|config| config.get("additionalTestBlockFunctions"))
-            .and_then(serde_json::Value::as_array)
-            .map(|v| {
-                v.iter().filter_map(serde_json::Value::as_str).map(ToString::to_string).collect()
-            })
-            .unwrap_or_default();
+        let ExpectExpectOptions { assert_function_names, additional_test_block_functions } =
+            rule_option(&value, 0);
+        let assert_function_names = assert_function_names.map_or_else(
+            || vec![String::from("expect")],
+            |names| names.iter().map(|name| convert_pattern(name)).collect(),
+        );
 
         Self(Box::new(ExpectExpectConfig {
             assert_function_names,
@@ -97,6 +99,14 @@ impl Rule for ExpectExpect {
         }))
     }
 
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<ExpectExpectOptions>()])
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         for possible_jest_node in &collect_possible_jest_call_node(ctx) {
             run(self, possible_jest_node, ctx);
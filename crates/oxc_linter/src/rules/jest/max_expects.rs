@@ -3,11 +3,15 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use rustc_hash::FxHashMap;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{collect_possible_jest_call_node, PossibleJestNode},
+    utils::{
+        collect_possible_jest_call_node, may_contain_jest_fn_call, rule_option, PossibleJestNode,
+    },
 };
 
 fn exceeded_max_assertion(x0: usize, x1: usize, span2: Span) -> OxcDiagnostic {
@@ -16,8 +20,10 @@ fn exceeded_max_assertion(x0: usize, x1: usize, span2: Span) -> OxcDiagnostic {
         .with_labels([span2.into()])
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct MaxExpects {
+    /// The maximum number of assertions per test, `5` by default.
     pub max: usize,
 }
 
@@ -65,14 +71,15 @@ declare_oxc_lint!(
 
 impl Rule for MaxExpects {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let max = value
-            .get(0)
-            .and_then(|config| config.get("max"))
-            .and_then(serde_json::Value::as_number)
-            .and_then(serde_json::Number::as_u64)
-            .map_or(5, |v| usize::try_from(v).unwrap_or(5));
+        rule_option(&value, 0)
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<Self>()])
+    }
 
-        Self { max }
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
     }
 
     fn run_once(&self, ctx: &LintContext) {
@@ -3,13 +3,15 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::ScopeId;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, is_type_of_jest_fn_call, JestFnKind, JestGeneralFnKind,
-        PossibleJestNode,
+        collect_possible_jest_call_node, is_type_of_jest_fn_call, may_contain_jest_fn_call,
+        rule_option, JestFnKind, JestGeneralFnKind, PossibleJestNode,
     },
 };
 
@@ -21,8 +23,10 @@ fn exceeded_max_depth(current: usize, max: usize, span0: Span) -> OxcDiagnostic
     .with_labels([span0.into()])
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct MaxNestedDescribe {
+    /// The maximum depth of nested `describe` calls, `5` by default.
     pub max: usize,
 }
 
@@ -119,14 +123,15 @@ declare_oxc_lint!(
 
 impl Rule for MaxNestedDescribe {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let max = value
-            .get(0)
-            .and_then(|config| config.get("max"))
-            .and_then(serde_json::Value::as_number)
-            .and_then(serde_json::Number::as_u64)
-            .map_or(5, |v| usize::try_from(v).unwrap_or(5));
+        rule_option(&value, 0)
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<Self>()])
+    }
 
-        Self { max }
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
     }
 
     fn run_once(&self, ctx: &LintContext) {
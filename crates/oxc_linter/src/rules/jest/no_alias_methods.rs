@@ -6,7 +6,10 @@ use oxc_span::Span;
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{collect_possible_jest_call_node, parse_expect_jest_fn_call, PossibleJestNode},
+    utils::{
+        collect_possible_jest_call_node, may_contain_jest_fn_call, parse_expect_jest_fn_call,
+        PossibleJestNode,
+    },
 };
 
 fn no_alias_methods_diagnostic(x0: &str, x1: &str, span2: Span) -> OxcDiagnostic {
@@ -47,6 +50,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoAliasMethods {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         for possible_jest_node in &collect_possible_jest_call_node(ctx) {
             run(possible_jest_node, ctx);
@@ -40,7 +40,8 @@ declare_oxc_lint!(
     /// // test.skip('foo', () => {});
     /// ```
     NoCommentedOutTests,
-    suspicious
+    suspicious,
+    ast_types = []
 );
 
 impl Rule for NoCommentedOutTests {
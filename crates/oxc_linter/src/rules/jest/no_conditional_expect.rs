@@ -9,8 +9,8 @@ use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, is_type_of_jest_fn_call, parse_expect_jest_fn_call,
-        JestFnKind, JestGeneralFnKind, PossibleJestNode,
+        collect_possible_jest_call_node, is_type_of_jest_fn_call, may_contain_jest_fn_call,
+        parse_expect_jest_fn_call, JestFnKind, JestGeneralFnKind, PossibleJestNode,
     },
 };
 
@@ -59,6 +59,10 @@ declare_oxc_lint!(
 struct InConditional(bool);
 
 impl Rule for NoConditionalExpect {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         for node in &collect_possible_jest_call_node(ctx) {
             run(node, ctx);
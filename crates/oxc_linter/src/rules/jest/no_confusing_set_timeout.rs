@@ -9,7 +9,10 @@ use oxc_span::{GetSpan, Span};
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{collect_possible_jest_call_node, parse_jest_fn_call, PossibleJestNode},
+    utils::{
+        collect_possible_jest_call_node, may_contain_jest_fn_call, parse_jest_fn_call,
+        PossibleJestNode,
+    },
 };
 
 fn no_global_set_timeout_diagnostic(span0: Span) -> OxcDiagnostic {
@@ -74,10 +77,15 @@ declare_oxc_lint!(
     /// });
     /// ```
     NoConfusingSetTimeout,
-    style
+    style,
+    ast_types = []
 );
 
 impl Rule for NoConfusingSetTimeout {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let scopes = ctx.scopes();
         let symbol_table = ctx.symbols();
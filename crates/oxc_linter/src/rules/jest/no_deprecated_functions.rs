@@ -5,8 +5,10 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use phf::{phf_map, Map};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, utils::rule_option};
 
 fn deprecated_function(x0: &str, x1: &str, span2: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(
@@ -37,6 +39,19 @@ impl std::ops::Deref for NoDeprecatedFunctions {
     }
 }
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default)]
+struct NoDeprecatedFunctionsOptions {
+    /// The Jest setup of the project.
+    jest: NoDeprecatedFunctionsJest,
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+struct NoDeprecatedFunctionsJest {
+    /// The version of Jest, `29` by default.
+    version: Option<String>,
+}
+
 declare_oxc_lint!(
     /// ### What it does
     /// Over the years Jest has accrued some debt in the form of functions that have
@@ -90,11 +105,10 @@ const DEPRECATED_FUNCTIONS_MAP: Map<&'static str, (usize, &'static str)> = phf_m
 
 impl Rule for NoDeprecatedFunctions {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let version = value
-            .get(0)
-            .and_then(|v| v.get("jest"))
-            .and_then(|v| v.get("version"))
-            .and_then(|v| serde_json::Value::as_str(v))
+        let NoDeprecatedFunctionsOptions { jest } = rule_option(&value, 0);
+        let version = jest
+            .version
+            .as_deref()
             // Todo: Fixed Me
             // Currently set the default version to the (maybe) latest, to help to find more problems in
             // the codebase. In the future, the version should come from the cli option or the config files,
@@ -108,6 +122,10 @@ impl Rule for NoDeprecatedFunctions {
         }))
     }
 
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<NoDeprecatedFunctionsOptions>()])
+    }
+
     fn run<'a>(&self, node: &oxc_semantic::AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::MemberExpression(mem_expr) = node.kind() else {
             return;
@@ -7,8 +7,9 @@ use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, get_test_plugin_name, parse_general_jest_fn_call,
-        JestFnKind, JestGeneralFnKind, ParsedGeneralJestFnCall, PossibleJestNode,
+        collect_possible_jest_call_node, get_test_plugin_name, may_contain_jest_fn_call,
+        parse_general_jest_fn_call, JestFnKind, JestGeneralFnKind, ParsedGeneralJestFnCall,
+        PossibleJestNode,
     },
 };
 
@@ -92,6 +93,10 @@ impl Message {
 }
 
 impl Rule for NoDisabledTests {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let plugin_name = get_test_plugin_name(ctx);
 
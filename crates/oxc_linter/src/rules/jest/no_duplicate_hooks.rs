@@ -10,8 +10,8 @@ use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, parse_jest_fn_call, JestFnKind, JestGeneralFnKind,
-        ParsedJestFnCallNew, PossibleJestNode,
+        collect_possible_jest_call_node, may_contain_jest_fn_call, parse_jest_fn_call, JestFnKind,
+        JestGeneralFnKind, ParsedJestFnCallNew, PossibleJestNode,
     },
 };
 
@@ -102,6 +102,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoDuplicateHooks {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let Some(root_node) = ctx.nodes().root_node() else {
             return;
@@ -32,7 +32,8 @@ declare_oxc_lint!(
     /// });
     /// ```
     NoExport,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for NoExport {
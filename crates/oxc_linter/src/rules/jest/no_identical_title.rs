@@ -13,8 +13,8 @@ use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, parse_general_jest_fn_call, JestFnKind, JestGeneralFnKind,
-        PossibleJestNode,
+        collect_possible_jest_call_node, may_contain_jest_fn_call, parse_general_jest_fn_call,
+        JestFnKind, JestGeneralFnKind, PossibleJestNode,
     },
     AstNode,
 };
@@ -55,10 +55,15 @@ declare_oxc_lint!(
     ///  });
     /// ```
     NoIdenticalTitle,
-    style
+    style,
+    ast_types = []
 );
 
 impl Rule for NoIdenticalTitle {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let possible_jest_nodes = collect_possible_jest_call_node(ctx);
         let mut title_to_span_mapping = HashMap::new();
@@ -9,11 +9,16 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use regex::Regex;
 use rustc_hash::FxHashMap;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{collect_possible_jest_call_node, parse_expect_jest_fn_call, PossibleJestNode},
+    utils::{
+        collect_possible_jest_call_node, may_contain_jest_fn_call, parse_expect_jest_fn_call,
+        rule_option, PossibleJestNode,
+    },
 };
 
 fn no_snapshot(x0: usize, span0: Span) -> OxcDiagnostic {
@@ -48,6 +53,18 @@ impl Deref for NoLargeSnapshots {
     }
 }
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct NoLargeSnapshotsOptions {
+    /// The maximum number of lines of external snapshots, `50` by default.
+    max_size: Option<usize>,
+    /// The maximum number of lines of inline snapshots, `maxSize` by default.
+    inline_max_size: Option<usize>,
+    /// Names of the snapshots which are never reported, by absolute path of their snapshot file.
+    #[serde(default)]
+    allowed_snapshots: FxHashMap<String, Vec<String>>,
+}
+
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -131,29 +148,22 @@ declare_oxc_lint!(
 
 impl Rule for NoLargeSnapshots {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let config = value.get(0);
-
-        let max_size = config
-            .and_then(|c| c.get("maxSize"))
-            .and_then(serde_json::Value::as_number)
-            .and_then(serde_json::Number::as_u64)
-            .map_or(50, |v| usize::try_from(v).unwrap_or(50));
-
-        let inline_max_size = config
-            .and_then(|c| c.get("inlineMaxSize"))
-            .and_then(serde_json::Value::as_number)
-            .and_then(serde_json::Number::as_u64)
-            .map_or(max_size, |v| usize::try_from(v).unwrap_or(max_size));
-
-        let allowed_snapshots = config
-            .and_then(|c| c.get("allowedSnapshots"))
-            .and_then(serde_json::Value::as_object)
-            .and_then(Self::compile_allowed_snapshots)
-            .unwrap_or_default();
+        let NoLargeSnapshotsOptions { max_size, inline_max_size, allowed_snapshots } =
+            rule_option(&value, 0);
+        let max_size = max_size.unwrap_or(50);
+        let inline_max_size = inline_max_size.unwrap_or(max_size);
 
         Self(Box::new(NoLargeSnapshotsConfig { max_size, inline_max_size, allowed_snapshots }))
     }
 
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<NoLargeSnapshotsOptions>()])
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let is_snap = ctx.file_path().to_str().map_or(false, |p| {
             Path::new(p).extension().map_or(false, |ext| ext.eq_ignore_ascii_case("snap"))
@@ -276,29 +286,6 @@ impl NoLargeSnapshots {
         let end = span.end as usize;
         ctx.source_text()[start..=end].lines().count() - 1
     }
-
-    #[allow(clippy::unnecessary_wraps)]
-    pub fn compile_allowed_snapshots(
-        matchers: &serde_json::Map<String, serde_json::Value>,
-    ) -> Option<FxHashMap<String, Vec<String>>> {
-        Some(
-            matchers
-                .iter()
-                .map(|(key, value)| {
-                    let serde_json::Value::Array(configs) = value else {
-                        return (String::from(key), vec![]);
-                    };
-
-                    let configs = configs
-                        .iter()
-                        .filter_map(|c| c.as_str().map(std::string::ToString::to_string))
-                        .collect();
-
-                    (String::from(key), configs)
-                })
-                .collect(),
-        )
-    }
 }
 
 #[test]
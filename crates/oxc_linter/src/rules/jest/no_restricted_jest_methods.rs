@@ -3,13 +3,15 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use rustc_hash::FxHashMap;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, is_type_of_jest_fn_call, JestFnKind, JestGeneralFnKind,
-        PossibleJestNode,
+        collect_possible_jest_call_node, is_type_of_jest_fn_call, may_contain_jest_fn_call,
+        rule_option, JestFnKind, JestGeneralFnKind, PossibleJestNode,
     },
 };
 
@@ -45,6 +47,10 @@ impl std::ops::Deref for NoRestrictedJestMethods {
     }
 }
 
+/// Messages to report, or `null`, by name of the restricted `jest` methods.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+struct NoRestrictedJestMethodsOptions(FxHashMap<String, Option<String>>);
+
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -73,15 +79,21 @@ declare_oxc_lint!(
 
 impl Rule for NoRestrictedJestMethods {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let restricted_jest_methods = &value
-            .get(0)
-            .and_then(serde_json::Value::as_object)
-            .and_then(Self::compile_restricted_jest_methods)
-            .unwrap_or_default();
-
-        Self(Box::new(NoRestrictedJestMethodsConfig {
-            restricted_jest_methods: restricted_jest_methods.clone(),
-        }))
+        let NoRestrictedJestMethodsOptions(messages) = rule_option(&value, 0);
+        let restricted_jest_methods = messages
+            .into_iter()
+            .map(|(name, message)| (name, message.unwrap_or_default()))
+            .collect();
+
+        Self(Box::new(NoRestrictedJestMethodsConfig { restricted_jest_methods }))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<NoRestrictedJestMethodsOptions>()])
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
     }
 
     fn run_once(&self, ctx: &LintContext) {
@@ -140,20 +152,6 @@ impl NoRestrictedJestMethods {
             );
         }
     }
-
-    #[allow(clippy::unnecessary_wraps)]
-    pub fn compile_restricted_jest_methods(
-        matchers: &serde_json::Map<String, serde_json::Value>,
-    ) -> Option<FxHashMap<String, String>> {
-        Some(
-            matchers
-                .iter()
-                .map(|(key, value)| {
-                    (String::from(key), String::from(value.as_str().unwrap_or_default()))
-                })
-                .collect(),
-        )
-    }
 }
 
 #[test]
@@ -6,13 +6,16 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use phf::phf_set;
 use rustc_hash::FxHashMap;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, is_type_of_jest_fn_call, parse_expect_jest_fn_call,
-        JestFnKind, KnownMemberExpressionProperty, PossibleJestNode,
+        collect_possible_jest_call_node, is_type_of_jest_fn_call, may_contain_jest_fn_call,
+        parse_expect_jest_fn_call, rule_option, JestFnKind, KnownMemberExpressionProperty,
+        PossibleJestNode,
     },
 };
 
@@ -48,6 +51,10 @@ impl std::ops::Deref for NoRestrictedMatchers {
     }
 }
 
+/// Messages to report, or `null`, by restricted matcher or modifier chain, e.g. `not.toBe`.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+struct NoRestrictedMatchersOptions(FxHashMap<String, Option<String>>);
+
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -81,15 +88,21 @@ const MODIFIER_NAME: phf::Set<&'static str> = phf_set!["not", "rejects", "resolv
 
 impl Rule for NoRestrictedMatchers {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let restricted_matchers = &value
-            .get(0)
-            .and_then(serde_json::Value::as_object)
-            .and_then(Self::compile_restricted_matchers)
-            .unwrap_or_default();
-
-        Self(Box::new(NoRestrictedMatchersConfig {
-            restricted_matchers: restricted_matchers.clone(),
-        }))
+        let NoRestrictedMatchersOptions(messages) = rule_option(&value, 0);
+        let restricted_matchers = messages
+            .into_iter()
+            .map(|(name, message)| (name, message.unwrap_or_default()))
+            .collect();
+
+        Self(Box::new(NoRestrictedMatchersConfig { restricted_matchers }))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<NoRestrictedMatchersOptions>()])
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
     }
 
     fn run_once(&self, ctx: &LintContext<'_>) {
@@ -151,20 +164,6 @@ impl NoRestrictedMatchers {
 
         chain_call == restriction
     }
-
-    #[allow(clippy::unnecessary_wraps)]
-    pub fn compile_restricted_matchers(
-        matchers: &serde_json::Map<String, serde_json::Value>,
-    ) -> Option<FxHashMap<String, String>> {
-        Some(
-            matchers
-                .iter()
-                .map(|(key, value)| {
-                    (String::from(key), String::from(value.as_str().unwrap_or_default()))
-                })
-                .collect(),
-        )
-    }
 }
 
 #[test]
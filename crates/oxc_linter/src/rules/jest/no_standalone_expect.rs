@@ -5,14 +5,16 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::AstNodeId;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, get_node_name, parse_expect_jest_fn_call,
-        parse_general_jest_fn_call, JestFnKind, JestGeneralFnKind, KnownMemberExpressionParentKind,
-        ParsedExpectFnCall, PossibleJestNode,
+        collect_possible_jest_call_node, get_node_name, may_contain_jest_fn_call,
+        parse_expect_jest_fn_call, parse_general_jest_fn_call, rule_option, JestFnKind,
+        JestGeneralFnKind, KnownMemberExpressionParentKind, ParsedExpectFnCall, PossibleJestNode,
     },
     AstNode,
 };
@@ -29,8 +31,10 @@ fn no_standalone_expect_diagnostic(span0: Span) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct NoStandaloneExpect(Box<NoStandaloneExpectConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoStandaloneExpectConfig {
+    /// Names of the functions, besides `it` and `test`, declaring tests.
     additional_test_block_functions: Vec<String>,
 }
 
@@ -64,16 +68,15 @@ declare_oxc_lint!(
 
 impl Rule for NoStandaloneExpect {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let additional_test_block_functions = value
-            .get(0)
-            .and_then(|v| v.get("additionalTestBlockFunctions"))
-            .and_then(serde_json::Value::as_array)
-            .map(|v| {
-                v.iter().filter_map(serde_json::Value::as_str).map(ToString::to_string).collect()
-            })
-            .unwrap_or_default();
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<NoStandaloneExpectConfig>()])
+    }
 
-        Self(Box::new(NoStandaloneExpectConfig { additional_test_block_functions }))
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
     }
 
     fn run_once(&self, ctx: &LintContext<'_>) {
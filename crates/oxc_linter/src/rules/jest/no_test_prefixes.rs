@@ -7,8 +7,9 @@ use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, parse_general_jest_fn_call, JestGeneralFnKind,
-        KnownMemberExpressionProperty, ParsedGeneralJestFnCall, PossibleJestNode,
+        collect_possible_jest_call_node, may_contain_jest_fn_call, parse_general_jest_fn_call,
+        JestGeneralFnKind, KnownMemberExpressionProperty, ParsedGeneralJestFnCall,
+        PossibleJestNode,
     },
 };
 
@@ -47,6 +48,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoTestPrefixes {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         for node in &collect_possible_jest_call_node(ctx) {
             run(node, ctx);
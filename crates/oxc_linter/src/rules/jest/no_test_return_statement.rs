@@ -11,7 +11,10 @@ use oxc_span::{GetSpan, Span};
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{is_type_of_jest_fn_call, JestFnKind, JestGeneralFnKind, PossibleJestNode},
+    utils::{
+        is_type_of_jest_fn_call, may_contain_jest_fn_call, JestFnKind, JestGeneralFnKind,
+        PossibleJestNode,
+    },
 };
 
 fn no_test_return_statement_diagnostic(span0: Span) -> OxcDiagnostic {
@@ -40,9 +43,14 @@ declare_oxc_lint!(
     /// ```
     NoTestReturnStatement,
     style,
+    ast_types = [CallExpression, Function]
 );
 
 impl Rule for NoTestReturnStatement {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::CallExpression(call_expr) => {
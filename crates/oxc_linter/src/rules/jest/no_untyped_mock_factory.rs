@@ -9,7 +9,7 @@ use oxc_span::Span;
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{collect_possible_jest_call_node, PossibleJestNode},
+    utils::{collect_possible_jest_call_node, may_contain_jest_fn_call, PossibleJestNode},
 };
 
 fn add_type_parameter_to_module_mock_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
@@ -89,6 +89,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoUntypedMockFactory {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
+    }
+
     fn run_once(&self, ctx: &LintContext<'_>) {
         if !ctx.source_type().is_typescript() {
             return;
@@ -6,7 +6,10 @@ use oxc_span::Span;
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{collect_possible_jest_call_node, parse_expect_jest_fn_call, PossibleJestNode},
+    utils::{
+        collect_possible_jest_call_node, may_contain_jest_fn_call, parse_expect_jest_fn_call,
+        PossibleJestNode,
+    },
 };
 
 fn use_to_be_called_with(span0: Span) -> OxcDiagnostic {
@@ -48,6 +51,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferCalledWith {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
+    }
+
     fn run_once(&self, ctx: &LintContext<'_>) {
         for possible_jest_node in &collect_possible_jest_call_node(ctx) {
             Self::run(possible_jest_node, ctx);
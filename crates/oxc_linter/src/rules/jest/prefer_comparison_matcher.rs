@@ -12,8 +12,8 @@ use crate::{
     fixer::RuleFixer,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, is_equality_matcher, parse_expect_jest_fn_call,
-        KnownMemberExpressionProperty, PossibleJestNode,
+        collect_possible_jest_call_node, is_equality_matcher, may_contain_jest_fn_call,
+        parse_expect_jest_fn_call, KnownMemberExpressionProperty, PossibleJestNode,
     },
 };
 
@@ -59,6 +59,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferComparisonMatcher {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         for possible_jest_node in &collect_possible_jest_call_node(ctx) {
             Self::run(possible_jest_node, ctx);
@@ -10,7 +10,10 @@ use oxc_syntax::operator::BinaryOperator;
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{collect_possible_jest_call_node, parse_expect_jest_fn_call, PossibleJestNode},
+    utils::{
+        collect_possible_jest_call_node, may_contain_jest_fn_call, parse_expect_jest_fn_call,
+        PossibleJestNode,
+    },
 };
 
 fn use_equality_matcher_diagnostic(span0: Span) -> OxcDiagnostic {
@@ -45,6 +48,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferEqualityMatcher {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         for possible_jest_node in &collect_possible_jest_call_node(ctx) {
             Self::run(possible_jest_node, ctx);
@@ -10,8 +10,8 @@ use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, is_type_of_jest_fn_call, JestFnKind, JestGeneralFnKind,
-        PossibleJestNode,
+        collect_possible_jest_call_node, is_type_of_jest_fn_call, may_contain_jest_fn_call,
+        JestFnKind, JestGeneralFnKind, PossibleJestNode,
     },
 };
 
@@ -142,6 +142,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferHooksOnTop {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let mut hooks_contexts: HashMap<ScopeId, bool> = HashMap::default();
         let mut possibles_jest_nodes = collect_possible_jest_call_node(ctx);
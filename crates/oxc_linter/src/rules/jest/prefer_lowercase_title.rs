@@ -2,13 +2,15 @@ use oxc_ast::{ast::Argument, AstKind};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, parse_jest_fn_call, JestFnKind, JestGeneralFnKind,
-        ParsedJestFnCallNew, PossibleJestNode,
+        collect_possible_jest_call_node, may_contain_jest_fn_call, parse_jest_fn_call, rule_option,
+        JestFnKind, JestGeneralFnKind, ParsedJestFnCallNew, PossibleJestNode,
     },
 };
 
@@ -18,10 +20,14 @@ fn unexpected_lowercase(x0: &str, span1: Span) -> OxcDiagnostic {
         .with_labels([span1.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct PreferLowercaseTitleConfig {
+    /// Title prefixes which are allowed to start with a capital letter.
     allowed_prefixes: Vec<String>,
+    /// The functions whose titles are not checked, e.g. `["describe"]`.
     ignore: Vec<String>,
+    /// Ignore the titles of the top level `describe` calls.
     ignore_top_level_describe: bool,
 }
 
@@ -133,31 +139,15 @@ declare_oxc_lint!(
 
 impl Rule for PreferLowercaseTitle {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
-        let ignore_top_level_describe = obj
-            .and_then(|config| config.get("ignoreTopLevelDescribe"))
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or(false);
-        let ignore = obj
-            .and_then(|config| config.get("ignore"))
-            .and_then(serde_json::Value::as_array)
-            .map(|v| {
-                v.iter().filter_map(serde_json::Value::as_str).map(ToString::to_string).collect()
-            })
-            .unwrap_or_default();
-        let allowed_prefixes = obj
-            .and_then(|config| config.get("allowedPrefixes"))
-            .and_then(serde_json::Value::as_array)
-            .map(|v| {
-                v.iter().filter_map(serde_json::Value::as_str).map(ToString::to_string).collect()
-            })
-            .unwrap_or_default();
-
-        Self(Box::new(PreferLowercaseTitleConfig {
-            allowed_prefixes,
-            ignore,
-            ignore_top_level_describe,
-        }))
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<PreferLowercaseTitleConfig>()])
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
     }
 
     fn run_once(&self, ctx: &LintContext) {
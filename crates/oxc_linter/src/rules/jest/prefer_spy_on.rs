@@ -14,7 +14,9 @@ use crate::{
     context::LintContext,
     fixer::RuleFixer,
     rule::Rule,
-    utils::{get_node_name, parse_general_jest_fn_call, PossibleJestNode},
+    utils::{
+        get_node_name, may_contain_jest_fn_call, parse_general_jest_fn_call, PossibleJestNode,
+    },
 };
 
 fn use_jest_spy_on(span0: Span) -> OxcDiagnostic {
@@ -53,9 +55,14 @@ declare_oxc_lint!(
     /// ```
     PreferSpyOn,
     style,
+    ast_types = [AssignmentExpression]
 );
 
 impl Rule for PreferSpyOn {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::AssignmentExpression(assign_expr) = node.kind() else {
             return;
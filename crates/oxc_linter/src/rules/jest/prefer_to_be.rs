@@ -10,8 +10,9 @@ use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, is_equality_matcher, parse_expect_jest_fn_call,
-        KnownMemberExpressionProperty, ParsedExpectFnCall, PossibleJestNode,
+        collect_possible_jest_call_node, is_equality_matcher, may_contain_jest_fn_call,
+        parse_expect_jest_fn_call, KnownMemberExpressionProperty, ParsedExpectFnCall,
+        PossibleJestNode,
     },
 };
 
@@ -85,6 +86,10 @@ enum PreferToBeKind {
 }
 
 impl Rule for PreferToBe {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         for possible_jest_node in &collect_possible_jest_call_node(ctx) {
             Self::run(possible_jest_node, ctx);
@@ -10,8 +10,8 @@ use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, is_equality_matcher, parse_expect_jest_fn_call,
-        KnownMemberExpressionParentKind, PossibleJestNode,
+        collect_possible_jest_call_node, is_equality_matcher, may_contain_jest_fn_call,
+        parse_expect_jest_fn_call, KnownMemberExpressionParentKind, PossibleJestNode,
     },
 };
 
@@ -54,6 +54,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferToContain {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         for possible_jest_node in &collect_possible_jest_call_node(ctx) {
             Self::run(possible_jest_node, ctx);
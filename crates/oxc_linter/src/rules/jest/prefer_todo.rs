@@ -11,8 +11,8 @@ use crate::{
     fixer::{Fix, RuleFixer},
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, is_type_of_jest_fn_call, JestFnKind, JestGeneralFnKind,
-        PossibleJestNode,
+        collect_possible_jest_call_node, is_type_of_jest_fn_call, may_contain_jest_fn_call,
+        JestFnKind, JestGeneralFnKind, PossibleJestNode,
     },
 };
 
@@ -52,6 +52,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferTodo {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         for possible_jest_node in &collect_possible_jest_call_node(ctx) {
             run(possible_jest_node, ctx);
@@ -5,13 +5,16 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::ScopeId;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, parse_jest_fn_call, JestFnKind, JestGeneralFnKind,
-        ParsedGeneralJestFnCall, ParsedJestFnCallNew, PossibleJestNode,
+        collect_possible_jest_call_node, may_contain_jest_fn_call, parse_jest_fn_call, rule_option,
+        JestFnKind, JestGeneralFnKind, ParsedGeneralJestFnCall, ParsedJestFnCallNew,
+        PossibleJestNode,
     },
 };
 
@@ -39,8 +42,10 @@ fn unexpected_hook(span0: Span) -> OxcDiagnostic {
     .with_labels([span0.into()])
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct RequireTopLevelDescribe {
+    /// The maximum number of top level `describe` calls, unlimited by default.
     pub max_number_of_top_level_describes: usize,
 }
 
@@ -112,14 +117,15 @@ declare_oxc_lint!(
 
 impl Rule for RequireTopLevelDescribe {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let max_number_of_top_level_describes = value
-            .get(0)
-            .and_then(|config| config.get("maxNumberOfTopLevelDescribes"))
-            .and_then(serde_json::Value::as_number)
-            .and_then(serde_json::Number::as_u64)
-            .map_or(usize::MAX, |v| usize::try_from(v).unwrap_or(usize::MAX));
+        rule_option(&value, 0)
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<Self>()])
+    }
 
-        Self { max_number_of_top_level_describes }
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
     }
 
     fn run_once(&self, ctx: &LintContext) {
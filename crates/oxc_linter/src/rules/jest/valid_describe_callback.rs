@@ -10,8 +10,8 @@ use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, parse_general_jest_fn_call, JestFnKind, JestGeneralFnKind,
-        PossibleJestNode,
+        collect_possible_jest_call_node, may_contain_jest_fn_call, parse_general_jest_fn_call,
+        JestFnKind, JestGeneralFnKind, PossibleJestNode,
     },
 };
 
@@ -63,6 +63,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for ValidDescribeCallback {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         for node in &collect_possible_jest_call_node(ctx) {
             run(node, ctx);
@@ -5,12 +5,15 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, parse_expect_jest_fn_call, ExpectError, PossibleJestNode,
+        collect_possible_jest_call_node, may_contain_jest_fn_call, parse_expect_jest_fn_call,
+        rule_option, ExpectError, PossibleJestNode,
     },
     AstNode,
 };
@@ -24,11 +27,16 @@ fn valid_expect_diagnostic(x0: &str, x1: &str, span2: Span) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct ValidExpect(Box<ValidExpectConfig>);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct ValidExpectConfig {
+    /// Matchers which must be awaited, `["toResolve", "toReject"]` by default.
     async_matchers: Vec<String>,
+    /// The minimum number of arguments of `expect`, `1` by default.
     min_args: usize,
+    /// The maximum number of arguments of `expect`, `1` by default.
     max_args: usize,
+    /// Require the asynchronous assertions to be awaited, rather than returned.
     always_await: bool,
 }
 
@@ -71,33 +79,15 @@ declare_oxc_lint!(
 
 impl Rule for ValidExpect {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let default_async_matchers = vec![String::from("toResolve"), String::from("toReject")];
-        let config = value.get(0);
-
-        let async_matchers = config
-            .and_then(|config| config.get("asyncMatchers"))
-            .and_then(serde_json::Value::as_array)
-            .map_or(default_async_matchers, |v| {
-                v.iter().filter_map(serde_json::Value::as_str).map(String::from).collect()
-            });
-        let min_args = config
-            .and_then(|config| config.get("minArgs"))
-            .and_then(serde_json::Value::as_number)
-            .and_then(serde_json::Number::as_u64)
-            .map_or(1, |v| usize::try_from(v).unwrap_or(1));
-
-        let max_args = config
-            .and_then(|config| config.get("maxArgs"))
-            .and_then(serde_json::Value::as_number)
-            .and_then(serde_json::Number::as_u64)
-            .map_or(1, |v| usize::try_from(v).unwrap_or(1));
-
-        let always_await = config
-            .and_then(|config| config.get("alwaysAwait"))
-            .and_then(serde_json::Value::as_bool)
-            .unwrap_or(false);
-
-        Self(Box::new(ValidExpectConfig { async_matchers, min_args, max_args, always_await }))
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<ValidExpectConfig>()])
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
     }
 
     fn run_once(&self, ctx: &LintContext) {
@@ -8,13 +8,15 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use regex::Regex;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_possible_jest_call_node, parse_general_jest_fn_call, JestFnKind, JestGeneralFnKind,
-        PossibleJestNode,
+        collect_possible_jest_call_node, may_contain_jest_fn_call, parse_general_jest_fn_call,
+        rule_option, JestFnKind, JestGeneralFnKind, PossibleJestNode,
     },
 };
 
@@ -44,6 +46,43 @@ impl std::ops::Deref for ValidTitle {
     }
 }
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+struct ValidTitleOptions {
+    /// Allow the `describe` titles which are not strings.
+    ignore_type_of_describe_name: bool,
+    /// Allow the titles with leading or trailing spaces.
+    ignore_spaces: bool,
+    /// Words which titles must not contain.
+    disallowed_words: Vec<String>,
+    /// A pattern which titles must not match.
+    must_not_match: Option<ValidTitleMatcher>,
+    /// A pattern which titles must match.
+    must_match: Option<ValidTitleMatcher>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum ValidTitleMatcher {
+    Pattern(ValidTitlePattern),
+    ByFunction(ValidTitleMatchers),
+}
+
+/// A regular expression, or a regular expression followed by the message to report.
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum ValidTitlePattern {
+    Pattern(String),
+    WithMessage(#[schemars(length(min = 1, max = 2))] Vec<String>),
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ValidTitleMatchers {
+    describe: Option<ValidTitlePattern>,
+    test: Option<ValidTitlePattern>,
+    it: Option<ValidTitlePattern>,
+}
+
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -72,40 +111,32 @@ declare_oxc_lint!(
 
 impl Rule for ValidTitle {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let config = value.get(0);
-        let get_as_bool = |name: &str| -> bool {
-            config
-                .and_then(|v| v.get(name))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default()
-        };
-
-        let ignore_type_of_describe_name = get_as_bool("ignoreTypeOfDescribeName");
-        let ignore_space = get_as_bool("ignoreSpaces");
-        let disallowed_words = config
-            .and_then(|v| v.get("disallowedWords"))
-            .and_then(|v| v.as_array())
-            .map(|v| {
-                v.iter().filter_map(|v| v.as_str().map(std::string::ToString::to_string)).collect()
-            })
-            .unwrap_or_default();
-        let must_not_match_patterns = config
-            .and_then(|v| v.get("mustNotMatch"))
-            .and_then(compile_matcher_patterns)
-            .unwrap_or_default();
-        let must_match_patterns = config
-            .and_then(|v| v.get("mustMatch"))
-            .and_then(compile_matcher_patterns)
-            .unwrap_or_default();
+        let ValidTitleOptions {
+            ignore_type_of_describe_name,
+            ignore_spaces,
+            disallowed_words,
+            must_not_match,
+            must_match,
+        } = rule_option(&value, 0);
         Self(Box::new(ValidTitleConfig {
             ignore_type_of_describe_name,
             disallowed_words,
-            ignore_space,
-            must_not_match_patterns,
-            must_match_patterns,
+            ignore_space: ignore_spaces,
+            must_not_match_patterns: must_not_match
+                .map(compile_matcher_patterns)
+                .unwrap_or_default(),
+            must_match_patterns: must_match.map(compile_matcher_patterns).unwrap_or_default(),
         }))
     }
 
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<ValidTitleOptions>()])
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        may_contain_jest_fn_call(ctx)
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         for node in &collect_possible_jest_call_node(ctx) {
             self.run(node, ctx);
@@ -188,12 +219,6 @@ enum MatchKind {
     Test,
 }
 
-#[derive(Copy, Clone)]
-enum MatcherPattern<'a> {
-    String(&'a serde_json::Value),
-    Vec(&'a Vec<serde_json::Value>),
-}
-
 impl MatchKind {
     fn from(name: &str) -> Option<Self> {
         match name {
@@ -206,69 +231,42 @@ impl MatchKind {
 }
 
 fn compile_matcher_patterns(
-    matcher_patterns: &serde_json::Value,
-) -> Option<HashMap<MatchKind, CompiledMatcherAndMessage>> {
-    matcher_patterns
-        .as_array()
-        .map_or_else(
-            || {
-                // for `{ "describe": "/pattern/" }`
-                let obj = matcher_patterns.as_object()?;
-                let mut map: HashMap<MatchKind, CompiledMatcherAndMessage> = HashMap::new();
-                for (key, value) in obj {
-                    let Some(v) = compile_matcher_pattern(MatcherPattern::String(value)) else {
-                        continue;
-                    };
-                    if let Some(kind) = MatchKind::from(key) {
-                        map.insert(kind, v);
-                    }
-                }
-
-                Some(map)
-            },
-            |value| {
-                // for `["/pattern/", "message"]`
-                let mut map: HashMap<MatchKind, CompiledMatcherAndMessage> = HashMap::new();
-                let v = &compile_matcher_pattern(MatcherPattern::Vec(value))?;
-                map.insert(MatchKind::Describe, v.clone());
-                map.insert(MatchKind::Test, v.clone());
-                map.insert(MatchKind::It, v.clone());
-                Some(map)
-            },
-        )
-        .map_or_else(
-            || {
-                // for `"/pattern/"`
-                let string = matcher_patterns.as_str()?;
-                let mut map: HashMap<MatchKind, CompiledMatcherAndMessage> = HashMap::new();
-                let v = &compile_matcher_pattern(MatcherPattern::String(
-                    &serde_json::Value::String(string.to_string()),
-                ))?;
-                map.insert(MatchKind::Describe, v.clone());
-                map.insert(MatchKind::Test, v.clone());
-                map.insert(MatchKind::It, v.clone());
-                Some(map)
-            },
-            Some,
-        )
-}
-
-fn compile_matcher_pattern(pattern: MatcherPattern) -> Option<CompiledMatcherAndMessage> {
-    match pattern {
-        MatcherPattern::String(pattern) => {
-            let reg_str = format!("(?u){}", pattern.as_str()?);
-            let reg = Regex::new(&reg_str).ok()?;
-            Some((reg, None))
+    matcher: ValidTitleMatcher,
+) -> HashMap<MatchKind, CompiledMatcherAndMessage> {
+    match matcher {
+        // for `"/pattern/"` and `["/pattern/", "message"]`
+        ValidTitleMatcher::Pattern(pattern) => {
+            let Some(compiled) = compile_matcher_pattern(pattern) else {
+                return HashMap::new();
+            };
+            HashMap::from([
+                (MatchKind::Describe, compiled.clone()),
+                (MatchKind::Test, compiled.clone()),
+                (MatchKind::It, compiled),
+            ])
         }
-        MatcherPattern::Vec(pattern) => {
-            let reg_str = pattern.first().and_then(|v| v.as_str()).map(|v| format!("(?u){v}"))?;
-            let reg = Regex::new(&reg_str).ok()?;
-            let message = pattern.get(1).map(std::string::ToString::to_string);
-            Some((reg, message))
+        // for `{ "describe": "/pattern/" }`
+        ValidTitleMatcher::ByFunction(ValidTitleMatchers { describe, test, it }) => {
+            [(MatchKind::Describe, describe), (MatchKind::Test, test), (MatchKind::It, it)]
+                .into_iter()
+                .filter_map(|(kind, pattern)| Some((kind, compile_matcher_pattern(pattern?)?)))
+                .collect()
         }
     }
 }
 
+fn compile_matcher_pattern(pattern: ValidTitlePattern) -> Option<CompiledMatcherAndMessage> {
+    let (pattern, message) = match pattern {
+        ValidTitlePattern::Pattern(pattern) => (pattern, None),
+        ValidTitlePattern::WithMessage(pattern) => {
+            let mut pattern = pattern.into_iter();
+            (pattern.next()?, pattern.next())
+        }
+    };
+    let reg = Regex::new(&format!("(?u){pattern}")).ok()?;
+    Some((reg, message))
+}
+
 fn validate_title(
     title: &str,
     span: Span,
@@ -48,7 +48,8 @@ declare_oxc_lint!(
     /// /** @access invalidlevel */
     /// ```
     CheckAccess,
-    restriction
+    restriction,
+    ast_types = []
 );
 
 const ACCESS_LEVELS: phf::Set<&'static str> = phf_set! {
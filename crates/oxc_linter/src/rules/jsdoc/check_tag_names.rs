@@ -2,12 +2,13 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use phf::phf_set;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
 use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{should_ignore_as_internal, should_ignore_as_private},
+    utils::{rule_option, should_ignore_as_internal, should_ignore_as_private},
 };
 
 fn check_tag_names_diagnostic(span0: Span, x1: &str) -> OxcDiagnostic {
@@ -42,15 +43,19 @@ declare_oxc_lint!(
     ///  */
     /// ```
     CheckTagNames,
-    correctness
+    correctness,
+    ast_types = []
 );
 
-#[derive(Debug, Default, Clone, Deserialize)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
 struct CheckTagnamesConfig {
+    /// Names of the additional tags which are allowed.
     #[serde(default, rename = "definedTags")]
     defined_tags: Vec<String>,
+    /// Allow the tags of JSX pragmas, e.g. `@jsx`.
     #[serde(default, rename = "jsxTags")]
     jsx_tags: bool,
+    /// Report the tags which are redundant with types, e.g. `@type` in TypeScript files.
     #[serde(default)]
     typed: bool,
 }
@@ -192,11 +197,11 @@ const OUTSIDE_AMBIENT_INVALID_TAGS_IF_TYPED: phf::Set<&'static str> = phf_set! {
 
 impl Rule for CheckTagNames {
     fn from_configuration(value: serde_json::Value) -> Self {
-        value
-            .as_array()
-            .and_then(|arr| arr.first())
-            .and_then(|value| serde_json::from_value(value.clone()).ok())
-            .map_or_else(Self::default, |value| Self(Box::new(value)))
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<CheckTagnamesConfig>()])
     }
 
     fn run_once(&self, ctx: &LintContext) {
@@ -2,9 +2,14 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use phf::phf_set;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
 use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, utils::should_ignore_as_private};
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{rule_option, should_ignore_as_private},
+};
 
 fn empty_tags_diagnostic(span0: Span, x1: &str) -> OxcDiagnostic {
     OxcDiagnostic::warn(
@@ -55,7 +60,8 @@ declare_oxc_lint!(
     /// /** @private bar */
     /// ```
     EmptyTags,
-    restriction
+    restriction,
+    ast_types = []
 );
 
 const EMPTY_TAGS: phf::Set<&'static str> = phf_set! {
@@ -79,19 +85,20 @@ const EMPTY_TAGS: phf::Set<&'static str> = phf_set! {
     "static",
 };
 
-#[derive(Debug, Default, Clone, Deserialize)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
 struct EmptyTagsConfig {
+    /// Names of the additional tags which must be empty.
     #[serde(default)]
     tags: Vec<String>,
 }
 
 impl Rule for EmptyTags {
     fn from_configuration(value: serde_json::Value) -> Self {
-        value
-            .as_array()
-            .and_then(|arr| arr.first())
-            .and_then(|value| serde_json::from_value(value.clone()).ok())
-            .map_or_else(Self::default, |value| Self(Box::new(value)))
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<EmptyTagsConfig>()])
     }
 
     fn run_once(&self, ctx: &LintContext) {
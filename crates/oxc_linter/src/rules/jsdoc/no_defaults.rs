@@ -1,13 +1,17 @@
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
 use serde::Deserialize;
 
 use crate::{
     ast_util::is_function_node,
     context::LintContext,
     rule::Rule,
-    utils::{get_function_nearest_jsdoc_node, should_ignore_as_internal, should_ignore_as_private},
+    utils::{
+        get_function_nearest_jsdoc_node, rule_option, should_ignore_as_internal,
+        should_ignore_as_private,
+    },
     AstNode,
 };
 
@@ -45,19 +49,20 @@ declare_oxc_lint!(
     correctness
 );
 
-#[derive(Debug, Default, Clone, Deserialize)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
 struct NoDefaultsConfig {
+    /// Also report the optional parameter names, e.g. `[foo]`.
     #[serde(default, rename = "noOptionalParamNames")]
     no_optional_param_names: bool,
 }
 
 impl Rule for NoDefaults {
     fn from_configuration(value: serde_json::Value) -> Self {
-        value
-            .as_array()
-            .and_then(|arr| arr.first())
-            .and_then(|value| serde_json::from_value(value.clone()).ok())
-            .map_or_else(Self::default, |value| Self(Box::new(value)))
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<NoDefaultsConfig>()])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -7,13 +7,14 @@ use oxc_macros::declare_oxc_lint;
 use oxc_semantic::{AstNode, JSDoc};
 use regex::Regex;
 use rustc_hash::{FxHashMap, FxHashSet};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
 use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        collect_params, get_function_nearest_jsdoc_node, should_ignore_as_avoid,
+        collect_params, get_function_nearest_jsdoc_node, rule_option, should_ignore_as_avoid,
         should_ignore_as_internal, should_ignore_as_private, ParamKind,
     },
 };
@@ -42,22 +43,30 @@ declare_oxc_lint!(
     pedantic,
 );
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 struct RequireParamConfig {
+    /// Tags exempting functions from `@param`, `["inheritdoc"]` by default.
     #[serde(default = "default_exempted_by", rename = "exemptedBy")]
     exempted_by: Vec<String>,
+    /// Check the constructors.
     #[serde(default = "default_true", rename = "checkConstructors")]
     check_constructors: bool,
+    /// Check the getters.
     #[serde(default, rename = "checkGetters")]
     check_getters: bool,
+    /// Check the setters.
     #[serde(default, rename = "checkSetters")]
     check_setters: bool,
+    /// Require `@param` for the destructured parameters, `true` by default.
     #[serde(default = "default_true", rename = "checkDestructuredRoots")]
     check_destructured_roots: bool,
+    /// Require `@param` for the properties of destructured parameters, `true` by default.
     #[serde(default = "default_true", rename = "checkDestructured")]
     check_destructured: bool,
+    /// Require `@param` for the rest properties of destructured parameters.
     #[serde(default, rename = "checkRestProperty")]
     check_rest_property: bool,
+    /// A regular expression of the types whose properties are not required to be documented.
     #[serde(default = "default_check_types_pattern", rename = "checkTypesPattern")]
     check_types_pattern: String,
     // TODO: Support this config
@@ -95,11 +104,11 @@ lazy_static! {
 
 impl Rule for RequireParam {
     fn from_configuration(value: serde_json::Value) -> Self {
-        value
-            .as_array()
-            .and_then(|arr| arr.first())
-            .and_then(|value| serde_json::from_value(value.clone()).ok())
-            .map_or_else(Self::default, |value| Self(Box::new(value)))
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<RequireParamConfig>()])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -46,7 +46,8 @@ declare_oxc_lint!(
     ///  */
     /// ```
     RequireProperty,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for RequireProperty {
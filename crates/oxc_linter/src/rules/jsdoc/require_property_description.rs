@@ -41,7 +41,8 @@ declare_oxc_lint!(
     ///  */
     /// ```
     RequirePropertyDescription,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for RequirePropertyDescription {
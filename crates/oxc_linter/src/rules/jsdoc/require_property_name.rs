@@ -41,7 +41,8 @@ declare_oxc_lint!(
     ///  */
     /// ```
     RequirePropertyName,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for RequirePropertyName {
@@ -8,14 +8,15 @@ use oxc_semantic::{JSDoc, JSDocTag};
 use oxc_span::Span;
 use phf::phf_set;
 use rustc_hash::FxHashMap;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
 use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        get_function_nearest_jsdoc_node, should_ignore_as_avoid, should_ignore_as_internal,
-        should_ignore_as_private,
+        get_function_nearest_jsdoc_node, rule_option, should_ignore_as_avoid,
+        should_ignore_as_internal, should_ignore_as_private,
     },
 };
 
@@ -60,18 +61,24 @@ declare_oxc_lint!(
     /// ```
     RequireReturns,
     pedantic,
+    ast_types = []
 );
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 struct RequireReturnsConfig {
+    /// Tags exempting functions from `@returns`, `["inheritdoc"]` by default.
     #[serde(default = "default_exempted_by", rename = "exemptedBy")]
     exempted_by: Vec<String>,
+    /// Check the constructors.
     #[serde(default, rename = "checkConstructors")]
     check_constructors: bool,
+    /// Check the getters, `true` by default.
     #[serde(default = "default_true", rename = "checkGetters")]
     check_getters: bool,
+    /// Require `@returns` even on the functions which return nothing.
     #[serde(default, rename = "forceRequireReturn")]
     force_require_return: bool,
+    /// Require `@returns` on the async functions which return nothing.
     #[serde(default, rename = "forceReturnsWithAsync")]
     force_returns_with_async: bool,
 }
@@ -95,11 +102,11 @@ fn default_exempted_by() -> Vec<String> {
 
 impl Rule for RequireReturns {
     fn from_configuration(value: serde_json::Value) -> Self {
-        value
-            .as_array()
-            .and_then(|arr| arr.first())
-            .and_then(|value| serde_json::from_value(value.clone()).ok())
-            .map_or_else(Self::default, |value| Self(Box::new(value)))
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<RequireReturnsConfig>()])
     }
 
     fn run_once(&self, ctx: &LintContext) {
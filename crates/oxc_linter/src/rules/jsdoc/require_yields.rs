@@ -4,14 +4,15 @@ use oxc_macros::declare_oxc_lint;
 use oxc_semantic::{JSDoc, JSDocTag};
 use oxc_span::Span;
 use phf::phf_set;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
 use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
-        get_function_nearest_jsdoc_node, should_ignore_as_avoid, should_ignore_as_internal,
-        should_ignore_as_private,
+        get_function_nearest_jsdoc_node, rule_option, should_ignore_as_avoid,
+        should_ignore_as_internal, should_ignore_as_private,
     },
     AstNode,
 };
@@ -63,12 +64,15 @@ declare_oxc_lint!(
     correctness
 );
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
 struct RequireYieldsConfig {
+    /// Tags exempting generators from `@yields`, `["inheritdoc"]` by default.
     #[serde(default = "default_exempted_by", rename = "exemptedBy")]
     exempted_by: Vec<String>,
+    /// Require `@yields` even on the generators which yield nothing.
     #[serde(default, rename = "forceRequireYields")]
     force_require_yields: bool,
+    /// Require `@yields` when a `@generator` tag is present.
     #[serde(default, rename = "withGeneratorTag")]
     with_generator_tag: bool,
 }
@@ -88,11 +92,11 @@ fn default_exempted_by() -> Vec<String> {
 
 impl Rule for RequireYields {
     fn from_configuration(value: serde_json::Value) -> Self {
-        value
-            .as_array()
-            .and_then(|arr| arr.first())
-            .and_then(|value| serde_json::from_value(value.clone()).ok())
-            .map_or_else(Self::default, |value| Self(Box::new(value)))
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<RequireYieldsConfig>()])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
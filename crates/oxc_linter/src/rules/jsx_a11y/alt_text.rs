@@ -5,13 +5,15 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
         get_element_type, get_prop_value, get_string_literal_prop_value, has_jsx_prop_lowercase,
-        object_has_accessible_child,
+        object_has_accessible_child, rule_option,
     },
     AstNode,
 };
@@ -98,6 +100,32 @@ impl std::default::Default for AltTextConfig {
     }
 }
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+struct AltTextOptions {
+    /// The elements which are checked, all of them by default.
+    elements: Option<Vec<AltTextElement>>,
+    /// Custom components checked like `img`.
+    img: Vec<String>,
+    /// Custom components checked like `object`.
+    object: Vec<String>,
+    /// Custom components checked like `area`.
+    area: Vec<String>,
+    /// Custom components checked like `input[type="image"]`.
+    #[serde(rename = "input[type=\"image\"]")]
+    input_type_image: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+enum AltTextElement {
+    Img,
+    Object,
+    Area,
+    #[serde(rename = "input[type=\"image\"]")]
+    InputTypeImage,
+}
+
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -129,44 +157,32 @@ declare_oxc_lint!(
     /// <img src="flower.jpg" alt="A close-up of a white daisy">
     /// ```
     AltText,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for AltText {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let mut alt_text = AltTextConfig::default();
-        if let Some(config) = value.get(0) {
-            if let Some(elements) = config.get("elements").and_then(|v| v.as_array()) {
-                alt_text =
-                    AltTextConfig { img: None, object: None, area: None, input_type_image: None };
-                for el in elements {
-                    match el.as_str() {
-                        Some("img") => alt_text.img = Some(vec![]),
-                        Some("object") => alt_text.object = Some(vec![]),
-                        Some("area") => alt_text.area = Some(vec![]),
-                        Some("input[type=\"image\"]") => alt_text.input_type_image = Some(vec![]),
-                        _ => {}
-                    }
-                }
-            }
+        let AltTextOptions { elements, img, object, area, input_type_image } =
+            rule_option(&value, 0);
+        // Only the listed elements are checked, with their custom components.
+        let checked = |element: AltTextElement, components: Vec<String>| {
+            elements
+                .as_ref()
+                .map_or(true, |elements| elements.contains(&element))
+                .then_some(components)
+        };
 
-            for (tags, field) in [
-                (&mut alt_text.img, "img"),
-                (&mut alt_text.object, "object"),
-                (&mut alt_text.area, "area"),
-                (&mut alt_text.input_type_image, "input[type=\"image\"]"),
-            ] {
-                if let (Some(tags), Some(elements)) =
-                    (tags, config.get(field).and_then(|v| v.as_array()))
-                {
-                    tags.extend(
-                        elements.iter().filter_map(|v| v.as_str().map(ToString::to_string)),
-                    );
-                }
-            }
-        }
+        Self(Box::new(AltTextConfig {
+            img: checked(AltTextElement::Img, img),
+            object: checked(AltTextElement::Object, object),
+            area: checked(AltTextElement::Area, area),
+            input_type_image: checked(AltTextElement::InputTypeImage, input_type_image),
+        }))
+    }
 
-        Self(Box::new(alt_text))
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<AltTextOptions>()])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -35,7 +35,8 @@ declare_oxc_lint!(
     /// <input aria-labelledby="address_label" />
     /// ```
     AriaProps,
-    correctness
+    correctness,
+    ast_types = [JSXAttributeItem]
 );
 impl Rule for AriaProps {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -5,12 +5,14 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     globals::{HTML_TAG, VALID_ARIA_ROLES},
     rule::Rule,
-    utils::{get_element_type, get_prop_value, has_jsx_prop},
+    utils::{get_element_type, get_prop_value, has_jsx_prop, rule_option},
     AstNode,
 };
 
@@ -23,9 +25,13 @@ fn aria_role_diagnostic(span0: Span, x1: &str) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct AriaRole(Box<AriaRoleConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct AriaRoleConfig {
+    /// Ignore the custom components, only checking DOM elements.
+    #[serde(rename = "ignoreNonDOM")]
     ignore_non_dom: bool,
+    /// Roles which are allowed even though they are not valid ARIA roles.
     allowed_invalid_roles: Vec<String>,
 }
 
@@ -86,34 +92,17 @@ declare_oxc_lint!(
     ///  <Foo role={role}></Foo>       <!-- Bad: ignoreNonDOM is set to false or not set -->
     /// ```
     AriaRole,
-    correctness
+    correctness,
+    ast_types = [JSXElement]
 );
 
 impl Rule for AriaRole {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let Some(value) = value.as_array() else {
-            return Self::default();
-        };
-        let mut ignore_non_dom = false;
-        let mut allowed_invalid_roles: Vec<String> = vec![];
-
-        let _ = value.iter().find(|v| {
-            if let serde_json::Value::Object(obj) = v {
-                if let Some(serde_json::Value::Bool(val)) = obj.get("ignoreNonDOM") {
-                    ignore_non_dom = *val;
-                }
-
-                if let Some(serde_json::Value::Array(val)) = obj.get("allowedInvalidRoles") {
-                    allowed_invalid_roles =
-                        val.iter().map(|v| v.as_str().unwrap().to_string()).collect();
-                }
-
-                return true;
-            }
-            false
-        });
+        Self(Box::new(rule_option(&value, 0)))
+    }
 
-        Self(Box::new(AriaRoleConfig { ignore_non_dom, allowed_invalid_roles }))
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<AriaRoleConfig>()])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -165,13 +154,13 @@ fn test() {
     use crate::tester::Tester;
 
     fn ignore_non_dom_schema() -> serde_json::Value {
-        serde_json::json!([2,{
+        serde_json::json!([{
             "ignoreNonDOM": true
         }])
     }
 
     fn allowed_invalid_roles() -> serde_json::Value {
-        serde_json::json!([2,{
+        serde_json::json!([{
             "allowedInvalidRoles": ["invalid-role", "other-invalid-role"],
         }])
     }
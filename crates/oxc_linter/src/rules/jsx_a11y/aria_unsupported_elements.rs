@@ -27,7 +27,8 @@ declare_oxc_lint! {
     /// ```
     ///
     AriaUnsupportedElements,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 }
 
 #[derive(Debug, Default, Clone)]
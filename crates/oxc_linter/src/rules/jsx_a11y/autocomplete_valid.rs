@@ -6,11 +6,13 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use phf::{phf_map, phf_set};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{get_element_type, has_jsx_prop_lowercase},
+    utils::{get_element_type, has_jsx_prop_lowercase, rule_option},
     AstNode,
 };
 
@@ -20,6 +22,13 @@ fn autocomplete_valid_diagnostic(span: Span, autocomplete: &str) -> OxcDiagnosti
         .with_label(span)
 }
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+struct AutocompleteValidOptions {
+    /// Custom components checked like `input`.
+    input_components: Vec<String>,
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct AutocompleteValid(Box<AutocompleteValidConfig>);
 declare_oxc_lint!(
@@ -38,7 +47,8 @@ declare_oxc_lint!(
     /// <input autocomplete="name" />
     /// ```
     AutocompleteValid,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -158,15 +168,7 @@ fn is_valid_autocomplete_value(value: &str) -> bool {
 
 impl Rule for AutocompleteValid {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let mut input_components: Vec<String> = vec!["input".to_string()];
-        if let Some(config) = value.get(0) {
-            if let Some(serde_json::Value::Array(components)) = config.get("inputComponents") {
-                input_components = components
-                    .iter()
-                    .filter_map(|c| c.as_str().map(std::string::ToString::to_string))
-                    .collect();
-            }
-        }
+        let AutocompleteValidOptions { mut input_components } = rule_option(&value, 0);
 
         // Add default input component
         input_components.push("input".to_string());
@@ -174,6 +176,10 @@ impl Rule for AutocompleteValid {
         Self(Box::new(AutocompleteValidConfig { input_components }))
     }
 
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<AutocompleteValidOptions>()])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXOpeningElement(jsx_el) = node.kind() {
             let Some(name) = &get_element_type(ctx, jsx_el) else {
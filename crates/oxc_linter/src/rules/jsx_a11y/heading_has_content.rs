@@ -2,11 +2,15 @@ use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{get_element_type, is_hidden_from_screen_reader, object_has_accessible_child},
+    utils::{
+        get_element_type, is_hidden_from_screen_reader, object_has_accessible_child, rule_option,
+    },
     AstNode,
 };
 
@@ -19,8 +23,10 @@ fn heading_has_content_diagnostic(span0: Span) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct HeadingHasContent(Box<HeadingHasContentConfig>);
 
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct HeadingHasContentConfig {
+    /// Custom components checked like headings.
     components: Option<Vec<String>>,
 }
 
@@ -55,7 +61,8 @@ declare_oxc_lint!(
     /// <h1>Foo</h1>
     /// ```
     HeadingHasContent,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 // always including <h1> thru <h6>
@@ -63,18 +70,11 @@ const DEFAULT_COMPONENTS: [&str; 6] = ["h1", "h2", "h3", "h4", "h5", "h6"];
 
 impl Rule for HeadingHasContent {
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self(Box::new(HeadingHasContentConfig {
-            components: value
-                .get(0)
-                .and_then(|v| v.get("components"))
-                .and_then(serde_json::Value::as_array)
-                .map(|v| {
-                    v.iter()
-                        .filter_map(serde_json::Value::as_str)
-                        .map(ToString::to_string)
-                        .collect()
-                }),
-        }))
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<HeadingHasContentConfig>()])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -49,7 +49,8 @@ declare_oxc_lint!(
     /// <html lang="en" />
     /// ```
     HtmlHasLang,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for HtmlHasLang {
@@ -6,12 +6,15 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use regex::Regex;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     rule::Rule,
     utils::{
         get_element_type, get_prop_value, has_jsx_prop_lowercase, is_hidden_from_screen_reader,
+        rule_option,
     },
     AstNode,
 };
@@ -49,6 +52,15 @@ impl Default for ImgRedundantAltConfig {
     }
 }
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default)]
+struct ImgRedundantAltOptions {
+    /// Custom components checked like `img`.
+    components: Vec<String>,
+    /// Additional words which are redundant in `alt`, besides "image", "photo" and "picture".
+    words: Vec<String>,
+}
+
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -79,31 +91,26 @@ declare_oxc_lint!(
     /// <img src="baz" alt={`Baz taking a ${photo}`} /> // This is valid since photo is a variable name.
     /// ```
     ImgRedundantAlt,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 const COMPONENTS_FIXED_TO_VALIDATE: [&str; 1] = ["img"];
 const REDUNDANT_WORDS: [&str; 3] = ["image", "photo", "picture"];
 
 impl Rule for ImgRedundantAlt {
     fn from_configuration(value: serde_json::Value) -> Self {
+        let ImgRedundantAltOptions { components, words } = rule_option(&value, 0);
         let mut img_redundant_alt = ImgRedundantAltConfig::default();
-        if let Some(config) = value.get(0) {
-            if let Some(components) = config.get("components").and_then(|v| v.as_array()) {
-                img_redundant_alt
-                    .types_to_validate
-                    .extend(components.iter().filter_map(|v| v.as_str().map(ToString::to_string)));
-            }
-
-            if let Some(words) = config.get("words").and_then(|v| v.as_array()) {
-                img_redundant_alt
-                    .redundant_words
-                    .extend(words.iter().filter_map(|v| v.as_str().map(ToString::to_string)));
-            }
-        }
+        img_redundant_alt.types_to_validate.extend(components);
+        img_redundant_alt.redundant_words.extend(words);
 
         Self(Box::new(img_redundant_alt))
     }
 
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<ImgRedundantAltOptions>()])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_el) = node.kind() else {
             return;
@@ -5,8 +5,15 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, utils::get_element_type, AstNode};
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_element_type, rule_option},
+    AstNode,
+};
 
 fn media_has_caption_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint-plugin-jsx-a11y(media-has-caption): Missing <track> element with captions inside <audio> or <video> element")
@@ -34,6 +41,17 @@ impl Default for MediaHasCaptionConfig {
     }
 }
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default)]
+struct MediaHasCaptionOptions {
+    /// Custom components checked like `audio`.
+    audio: Vec<String>,
+    /// Custom components checked like `video`.
+    video: Vec<String>,
+    /// Custom components accepted like `track`.
+    track: Vec<String>,
+}
+
 declare_oxc_lint!(
     /// ### What it does
     /// Checks if `<audio>` and `<video>` elements have a `<track>` element for captions.
@@ -54,39 +72,25 @@ declare_oxc_lint!(
     /// <video></video>
     /// ```
     MediaHasCaption,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for MediaHasCaption {
     fn from_configuration(value: serde_json::Value) -> Self {
+        let MediaHasCaptionOptions { audio, video, track } = rule_option(&value, 0);
         let mut config = MediaHasCaptionConfig::default();
-
-        if let Some(arr) = value.as_array() {
-            for v in arr {
-                if let serde_json::Value::Object(rule_config) = v {
-                    if let Some(audio) = rule_config.get("audio").and_then(|v| v.as_array()) {
-                        config
-                            .audio
-                            .extend(audio.iter().filter_map(|v| v.as_str().map(String::from)));
-                    }
-                    if let Some(video) = rule_config.get("video").and_then(|v| v.as_array()) {
-                        config
-                            .video
-                            .extend(video.iter().filter_map(|v| v.as_str().map(String::from)));
-                    }
-                    if let Some(track) = rule_config.get("track").and_then(|v| v.as_array()) {
-                        config
-                            .track
-                            .extend(track.iter().filter_map(|v| v.as_str().map(String::from)));
-                    }
-                    break;
-                }
-            }
-        }
+        config.audio.extend(audio);
+        config.video.extend(video);
+        config.track.extend(track);
 
         Self(Box::new(config))
     }
 
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<MediaHasCaptionOptions>()])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_el) = node.kind() else {
             return;
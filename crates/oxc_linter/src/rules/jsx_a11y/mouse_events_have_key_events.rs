@@ -2,12 +2,14 @@ use oxc_ast::{ast::JSXAttributeValue, AstKind};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     globals::HTML_TAG,
     rule::Rule,
-    utils::{get_element_type, get_prop_value, has_jsx_prop},
+    utils::{get_element_type, get_prop_value, has_jsx_prop, rule_option},
     AstNode,
 };
 
@@ -26,9 +28,12 @@ fn miss_on_blur(span0: Span, x1: &str) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct MouseEventsHaveKeyEvents(Box<MouseEventsHaveKeyEventsConfig>);
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct MouseEventsHaveKeyEventsConfig {
+    /// Handlers requiring `onFocus`, `["onMouseOver"]` by default.
     hover_in_handlers: Vec<String>,
+    /// Handlers requiring `onBlur`, `["onMouseOut"]` by default.
     hover_out_handlers: Vec<String>,
 }
 
@@ -60,38 +65,17 @@ declare_oxc_lint!(
     /// <div onMouseOver={() => void 0} />
     /// ```
     MouseEventsHaveKeyEvents,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for MouseEventsHaveKeyEvents {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let mut config = MouseEventsHaveKeyEventsConfig::default();
-
-        if let Some(hover_in_handlers_config) = value
-            .get(0)
-            .and_then(|v| v.get("hoverInHandlers"))
-            .and_then(serde_json::Value::as_array)
-        {
-            config.hover_in_handlers = hover_in_handlers_config
-                .iter()
-                .filter_map(serde_json::Value::as_str)
-                .map(ToString::to_string)
-                .collect();
-        }
-
-        if let Some(hover_out_handlers_config) = value
-            .get(0)
-            .and_then(|v| v.get("hoverOutHandlers"))
-            .and_then(serde_json::Value::as_array)
-        {
-            config.hover_out_handlers = hover_out_handlers_config
-                .iter()
-                .filter_map(serde_json::Value::as_str)
-                .map(ToString::to_string)
-                .collect();
-        }
+        Self(Box::new(rule_option(&value, 0)))
+    }
 
-        Self(Box::new(config))
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<MouseEventsHaveKeyEventsConfig>()])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -34,7 +34,8 @@ declare_oxc_lint!(
     /// <div />
     /// ```
     NoAccessKey,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for NoAccessKey {
@@ -2,12 +2,14 @@ use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     globals::HTML_TAG,
     rule::Rule,
-    utils::{get_element_type, has_jsx_prop},
+    utils::{get_element_type, has_jsx_prop, rule_option},
     AstNode,
 };
 
@@ -17,8 +19,11 @@ fn no_autofocus_diagnostic(span0: Span) -> OxcDiagnostic {
         .with_labels([span0.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoAutofocus {
+    /// Ignore the custom components, only checking DOM elements.
+    #[serde(rename = "ignoreNonDOM")]
     ignore_non_dom: bool,
 }
 
@@ -58,7 +63,8 @@ declare_oxc_lint!(
     /// ```
     ///
     NoAutofocus,
-    correctness
+    correctness,
+    ast_types = [JSXElement]
 );
 
 impl NoAutofocus {
@@ -69,22 +75,11 @@ impl NoAutofocus {
 
 impl Rule for NoAutofocus {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let mut no_focus = Self::default();
-
-        if let Some(arr) = value.as_array() {
-            if arr.iter().any(|v| {
-                if let serde_json::Value::Object(obj) = v {
-                    if let Some(serde_json::Value::Bool(val)) = obj.get("ignoreNonDOM") {
-                        return *val;
-                    }
-                }
-                false
-            }) {
-                no_focus.set_option(true);
-            }
-        }
+        rule_option(&value, 0)
+    }
 
-        no_focus
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<Self>()])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -114,7 +109,7 @@ impl Rule for NoAutofocus {
 fn test() {
     use crate::tester::Tester;
     fn config() -> serde_json::Value {
-        serde_json::json!([2,{
+        serde_json::json!([{
             "ignoreNonDOM": true
         }])
     }
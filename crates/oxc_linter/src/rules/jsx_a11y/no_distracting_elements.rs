@@ -45,7 +45,8 @@ declare_oxc_lint!(
     /// <Blink />
     /// ```
     NoDistractingElements,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for NoDistractingElements {
@@ -41,7 +41,8 @@ declare_oxc_lint!(
     /// <nav />
     /// ```
     NoRedundantRoles,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 static DEFAULT_ROLE_EXCEPTIONS: phf::Map<&'static str, &'static str> = phf_map! {
@@ -40,7 +40,8 @@ declare_oxc_lint!(
     /// <button />
     /// ```
     PreferTagOverRole,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl PreferTagOverRole {
@@ -33,7 +33,8 @@ declare_oxc_lint!(
     /// <div role="checkbox" aria-checked="false" />
     /// ```
     RoleHasRequiredAriaProps,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 static ROLE_TO_REQUIRED_ARIA_PROPS: phf::Map<&'static str, phf::Set<&'static str>> = phf_map! {
@@ -6,7 +6,7 @@ use oxc_span::Span;
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{get_string_literal_prop_value, has_jsx_prop_lowercase},
+    utils::{get_string_literal_prop_value, has_jsx_prop_lowercase, is_nextjs_jsx_module},
     AstNode,
 };
 
@@ -30,10 +30,15 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     GoogleFontPreconnect,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for GoogleFontPreconnect {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        is_nextjs_jsx_module(ctx)
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_opening_element) = node.kind() else {
             return;
@@ -7,7 +7,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use rustc_hash::FxHashSet;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::is_nextjs_jsx_module, AstNode};
 
 fn inline_script_id_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint-plugin-next(inline-script-id): `next/script` components with inline content must specify an `id` attribute.")
@@ -29,10 +29,15 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     InlineScriptId,
-    correctness
+    correctness,
+    ast_types = [ImportDefaultSpecifier]
 );
 
 impl Rule for InlineScriptId {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        is_nextjs_jsx_module(ctx)
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ImportDefaultSpecifier(specifier) = node.kind() else {
             return;
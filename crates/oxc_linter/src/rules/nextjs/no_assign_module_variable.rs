@@ -3,7 +3,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::is_nextjs_module, AstNode};
 
 fn no_assign_module_variable_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(
@@ -27,10 +27,15 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoAssignModuleVariable,
-    correctness
+    correctness,
+    ast_types = [VariableDeclaration]
 );
 
 impl Rule for NoAssignModuleVariable {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        is_nextjs_module(ctx)
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::VariableDeclaration(variable_decl) = node.kind() else {
             return;
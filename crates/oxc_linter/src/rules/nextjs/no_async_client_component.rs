@@ -6,7 +6,10 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{ast_util::get_declaration_of_variable, context::LintContext, rule::Rule};
+use crate::{
+    ast_util::get_declaration_of_variable, context::LintContext, rule::Rule,
+    utils::is_nextjs_module,
+};
 
 fn no_async_client_component_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint-plugin-next(no-async-client-component): Prevent client components from being async functions.")
@@ -28,10 +31,15 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoAsyncClientComponent,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for NoAsyncClientComponent {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        is_nextjs_module(ctx)
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let Some(root) = ctx.nodes().root_node() else {
             return;
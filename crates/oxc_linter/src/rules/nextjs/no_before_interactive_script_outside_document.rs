@@ -9,7 +9,9 @@ use oxc_span::Span;
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{get_next_script_import_local_name, is_document_page, is_in_app_dir},
+    utils::{
+        get_next_script_import_local_name, is_document_page, is_in_app_dir, is_nextjs_jsx_module,
+    },
     AstNode,
 };
 
@@ -33,10 +35,15 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoBeforeInteractiveScriptOutsideDocument,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for NoBeforeInteractiveScriptOutsideDocument {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        is_nextjs_jsx_module(ctx)
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXOpeningElement(jsx_el) = node.kind() {
             let Some(file_path) = ctx.file_path().to_str() else {
@@ -6,7 +6,12 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, utils::get_string_literal_prop_value, AstNode};
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_string_literal_prop_value, is_nextjs_jsx_module},
+    AstNode,
+};
 
 fn no_css_tags_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint-plugin-next(no-css-tags): Do not include stylesheets manually.")
@@ -28,10 +33,15 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoCssTags,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for NoCssTags {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        is_nextjs_jsx_module(ctx)
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_opening_element) = node.kind() else {
             return;
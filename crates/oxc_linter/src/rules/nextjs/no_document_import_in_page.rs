@@ -3,7 +3,12 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, utils::is_document_page, AstNode};
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{is_document_page, is_nextjs_module},
+    AstNode,
+};
 
 fn no_document_import_in_page_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint-plugin-next(no-document-import-in-page): `<Document />` from `next/document` should not be imported outside of `pages/_document.js`. See: https://nextjs.org/docs/messages/no-document-import-in-page").with_help("Prevent importing `next/document` outside of `pages/_document.js`.").with_labels([span0.into()])
@@ -23,10 +28,15 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoDocumentImportInPage,
-    correctness
+    correctness,
+    ast_types = [ModuleDeclaration]
 );
 
 impl Rule for NoDocumentImportInPage {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        is_nextjs_module(ctx)
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ModuleDeclaration(ModuleDeclaration::ImportDeclaration(import_decl)) =
             node.kind()
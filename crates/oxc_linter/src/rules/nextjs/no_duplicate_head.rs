@@ -3,7 +3,7 @@ use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::Reference;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, utils::is_nextjs_jsx_module};
 
 #[derive(Debug, Default, Clone)]
 pub struct NoDuplicateHead;
@@ -36,10 +36,15 @@ declare_oxc_lint!(
     ///export default MyDocument
     /// ```
     NoDuplicateHead,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for NoDuplicateHead {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        is_nextjs_jsx_module(ctx)
+    }
+
     fn run_on_symbol(&self, symbol_id: oxc_semantic::SymbolId, ctx: &LintContext<'_>) {
         let symbols = ctx.symbols();
         let name = symbols.get_name(symbol_id);
@@ -3,7 +3,12 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, utils::is_in_app_dir, AstNode};
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{is_in_app_dir, is_nextjs_jsx_module},
+    AstNode,
+};
 
 fn no_head_element_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint-plugin-next(no-head-element): Do not use `<head>` element. Use `<Head />` from `next/head` instead.")
@@ -25,17 +30,19 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoHeadElement,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for NoHeadElement {
-    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+    fn should_run(&self, ctx: &LintContext) -> bool {
         let Some(full_file_path) = ctx.file_path().to_str() else {
-            return;
+            return false;
         };
-        if is_in_app_dir(full_file_path) {
-            return;
-        }
+        is_nextjs_jsx_module(ctx) && !is_in_app_dir(full_file_path)
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXOpeningElement(elem) = node.kind() {
             let JSXElementName::Identifier(id) = &elem.name else {
                 return;
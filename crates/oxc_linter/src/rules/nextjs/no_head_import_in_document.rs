@@ -3,7 +3,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::is_nextjs_module, AstNode};
 
 fn no_head_import_in_document_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint-plugin-next(no-head-import-in-document): Prevent usage of `next/head` in `pages/_document.js`.")
@@ -25,10 +25,15 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoHeadImportInDocument,
-    correctness
+    correctness,
+    ast_types = [ModuleDeclaration]
 );
 
 impl Rule for NoHeadImportInDocument {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        is_nextjs_module(ctx)
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ModuleDeclaration(ModuleDeclaration::ImportDeclaration(import_decl)) =
             node.kind()
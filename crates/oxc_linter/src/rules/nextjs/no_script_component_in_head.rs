@@ -6,7 +6,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::is_nextjs_jsx_module, AstNode};
 
 fn no_script_component_in_head_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint-plugin-next(no-script-component-in-head): Prevent usage of `next/script` in `next/head` component.")
@@ -28,10 +28,15 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoScriptComponentInHead,
-    correctness
+    correctness,
+    ast_types = [ModuleDeclaration]
 );
 
 impl Rule for NoScriptComponentInHead {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        is_nextjs_jsx_module(ctx)
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ModuleDeclaration(ModuleDeclaration::ImportDeclaration(import_decl)) =
             node.kind()
@@ -6,7 +6,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::is_nextjs_jsx_module, AstNode};
 
 fn no_styled_jsx_in_document_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint-plugin-next(no-styled-jsx-in-document): `styled-jsx` should not be used in `pages/_document.js`")
@@ -31,9 +31,14 @@ declare_oxc_lint!(
     /// ```
     NoStyledJsxInDocument,
     correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for NoStyledJsxInDocument {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        is_nextjs_jsx_module(ctx)
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(element) = node.kind() else {
             return;
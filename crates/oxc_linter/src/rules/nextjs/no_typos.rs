@@ -7,7 +7,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use phf::phf_set;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::is_nextjs_module, AstNode};
 
 fn no_typos_diagnostic(x0: &str, x1: &str, span2: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!(
@@ -35,7 +35,8 @@ declare_oxc_lint!(
     /// export async function getServurSideProps(){};
     /// ```
     NoTypos,
-    correctness
+    correctness,
+    ast_types = [ModuleDeclaration]
 );
 
 const NEXTJS_DATA_FETCHING_FUNCTIONS: phf::Set<&'static str> = phf_set! {
@@ -48,16 +49,20 @@ const NEXTJS_DATA_FETCHING_FUNCTIONS: phf::Set<&'static str> = phf_set! {
 const THRESHOLD: i32 = 1;
 
 impl Rule for NoTypos {
-    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        if !is_nextjs_module(ctx) {
+            return false;
+        }
         let Some(path) = ctx.file_path().to_str() else {
-            return;
+            return false;
         };
         let Some(path_after_pages) = path.split("pages").nth(1) else {
-            return;
+            return false;
         };
-        if path_after_pages.starts_with("/api") {
-            return;
-        }
+        !path_after_pages.starts_with("/api")
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::ModuleDeclaration(ModuleDeclaration::ExportNamedDeclaration(en_decl)) =
             node.kind()
         {
@@ -8,7 +8,11 @@ use oxc_semantic::AstNode;
 use oxc_span::Span;
 use phf::{phf_set, Set};
 
-use crate::{context::LintContext, rule::Rule, utils::get_next_script_import_local_name};
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_next_script_import_local_name, is_nextjs_jsx_module},
+};
 
 fn no_unwanted_polyfillio_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("eslint-plugin-next(no-unwanted-polyfillio): No duplicate polyfills from Polyfill.io are allowed. {x0} already shipped with Next.js."))
@@ -33,7 +37,8 @@ declare_oxc_lint!(
     /// <script src='https://polyfill.io/v3/polyfill.min.js?features=WeakSet%2CPromise%2CPromise.prototype.finally%2Ces2015%2Ces5%2Ces6'></script>
     /// ```
     NoUnwantedPolyfillio,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 // Keep in sync with next.js polyfills file : https://github.com/vercel/next.js/blob/master/packages/next-polyfill-nomodule/src/index.js
@@ -104,6 +109,10 @@ const NEXT_POLYFILLED_FEATURES: Set<&'static str> = phf_set! {
 };
 
 impl Rule for NoUnwantedPolyfillio {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        is_nextjs_jsx_module(ctx)
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXOpeningElement(jsx_el) = node.kind() {
             let tag_name = if let JSXElementName::Identifier(ident) = &jsx_el.name {
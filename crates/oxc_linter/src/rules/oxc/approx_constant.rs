@@ -31,7 +31,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     ApproxConstant,
-    suspicious
+    suspicious,
+    ast_types = [NumericLiteral]
 );
 
 impl Rule for ApproxConstant {
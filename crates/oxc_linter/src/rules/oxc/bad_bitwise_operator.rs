@@ -50,8 +50,9 @@ declare_oxc_lint!(
     /// input |= '';
     /// ```
     BadBitwiseOperator,
-    restriction // Restricted because there are false positives for enum bitflags in TypeScript,
-                // e.g. in the vscode repo
+    restriction, // Restricted because there are false positives for enum bitflags in TypeScript,
+                 // e.g. in the vscode repo
+    ast_types = [BinaryExpression, AssignmentExpression]
 );
 
 impl Rule for BadBitwiseOperator {
@@ -35,7 +35,8 @@ declare_oxc_lint!(
     /// a.charAt(4) === '\n';
     /// ```
     BadCharAtComparison,
-    correctness
+    correctness,
+    ast_types = [CallExpression]
 );
 
 impl Rule for BadCharAtComparison {
@@ -33,7 +33,8 @@ declare_oxc_lint!(
     /// Math.max(1000, Math.min(0, z));
     /// ```
     BadMinMaxFunc,
-    correctness
+    correctness,
+    ast_types = [CallExpression]
 );
 
 impl Rule for BadMinMaxFunc {
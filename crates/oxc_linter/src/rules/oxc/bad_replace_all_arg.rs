@@ -40,7 +40,8 @@ declare_oxc_lint!(
     /// withSpaces.replaceAll(/\s+/g, ',');
     /// ```
     BadReplaceAllArg,
-    correctness
+    correctness,
+    ast_types = [CallExpression]
 );
 
 impl Rule for BadReplaceAllArg {
@@ -0,0 +1,328 @@
+use itertools::Itertools;
+use oxc_ast::{
+    ast::{
+        ArrowFunctionExpression, BreakStatement, CatchClause, ConditionalExpression,
+        ContinueStatement, DoWhileStatement, Expression, ForInStatement, ForOfStatement,
+        ForStatement, Function, IfStatement, LogicalExpression, Statement, SwitchStatement,
+        WhileStatement,
+    },
+    visit::walk::{
+        walk_catch_clause, walk_do_while_statement, walk_for_in_statement, walk_for_of_statement,
+        walk_for_statement, walk_switch_statement, walk_while_statement,
+    },
+    AstKind, Visit,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ScopeFlags;
+use oxc_span::Span;
+use oxc_syntax::operator::LogicalOperator;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_function_name_with_kind, rule_option},
+    AstNode,
+};
+
+fn cognitive_complexity_diagnostic(
+    name: &str,
+    complexity: usize,
+    max: usize,
+    span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "oxc(cognitive-complexity): {name} has a cognitive complexity of {complexity}. Maximum allowed is {max}."
+    ))
+    .with_help("Reduce the nesting and the breaks in the linear flow of this function, e.g. by extracting some of its logic into separate functions.")
+    .with_labels([span.into()])
+}
+
+/// The maximum cognitive complexity, given as a number or as `{ "max": 15 }`.
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum CognitiveComplexityOption {
+    Max(usize),
+    Object(CognitiveComplexityObjectOption),
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema)]
+struct CognitiveComplexityObjectOption {
+    /// The maximum cognitive complexity, `15` by default.
+    max: Option<usize>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CognitiveComplexity(Box<CognitiveComplexityConfig>);
+
+#[derive(Debug, Clone)]
+pub struct CognitiveComplexityConfig {
+    max: usize,
+}
+
+impl std::ops::Deref for CognitiveComplexity {
+    type Target = CognitiveComplexityConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Default for CognitiveComplexityConfig {
+    fn default() -> Self {
+        Self { max: 15 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce a maximum cognitive complexity allowed in a function, as defined by SonarSource.
+    ///
+    /// Unlike the cyclomatic complexity of `eslint/complexity`, which counts the paths through
+    /// a function, the cognitive complexity measures how hard the function is to understand:
+    ///
+    /// * `if`, conditional expressions, `switch`, loops and `catch` add 1, plus 1 for every
+    ///   level of nesting they are in.
+    /// * `else if` and `else` add 1, regardless of the nesting.
+    /// * Every sequence of like logical operators adds 1, e.g. `a && b && c` adds 1 and
+    ///   `a && b || c` adds 2.
+    /// * `break` and `continue` to a label add 1.
+    ///
+    /// Nested functions are measured on their own.
+    ///
+    /// References:
+    ///
+    /// * <https://www.sonarsource.com/docs/CognitiveComplexity.pdf>
+    /// * <https://github.com/SonarSource/eslint-plugin-sonarjs/blob/master/docs/rules/cognitive-complexity.md>
+    ///
+    /// ### Options
+    ///
+    /// The maximum complexity, `15` by default, given as a number or as `{ "max": 15 }`.
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// // with `3`
+    /// function foo(items) {
+    ///     for (const item of items) { // +1
+    ///         if (item.enabled) { // +2 (nesting = 1)
+    ///             if (item.value && item.visible) { // +3 (nesting = 2), +1
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    CognitiveComplexity,
+    pedantic,
+    ast_types = [Function, ArrowFunctionExpression]
+);
+
+impl Rule for CognitiveComplexity {
+    fn from_configuration(value: Value) -> Self {
+        let max = match rule_option(&value, 0) {
+            Some(CognitiveComplexityOption::Max(max)) => max,
+            Some(CognitiveComplexityOption::Object(option)) => option.max.unwrap_or(15),
+            None => 15,
+        };
+
+        Self(Box::new(CognitiveComplexityConfig { max }))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<CognitiveComplexityOption>()])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (body, span) = match node.kind() {
+            AstKind::Function(func) => match &func.body {
+                Some(body) => (body, func.span),
+                None => return,
+            },
+            AstKind::ArrowFunctionExpression(arrow) => (&arrow.body, arrow.span),
+            _ => return,
+        };
+
+        let mut visitor = CognitiveComplexityVisitor::default();
+        visitor.visit_function_body(body);
+        if visitor.complexity > self.max {
+            ctx.diagnostic(cognitive_complexity_diagnostic(
+                &get_function_name_with_kind(node, ctx),
+                visitor.complexity,
+                self.max,
+                span,
+            ));
+        }
+    }
+}
+
+#[derive(Default)]
+struct CognitiveComplexityVisitor {
+    complexity: usize,
+    nesting: usize,
+}
+
+impl CognitiveComplexityVisitor {
+    /// Adds a structure which is harder to understand the deeper it is nested.
+    fn increase_with_nesting(&mut self) {
+        self.complexity += 1 + self.nesting;
+    }
+
+    fn nested<F: FnOnce(&mut Self)>(&mut self, visit: F) {
+        self.nesting += 1;
+        visit(self);
+        self.nesting -= 1;
+    }
+}
+
+impl<'a> Visit<'a> for CognitiveComplexityVisitor {
+    fn visit_if_statement(&mut self, stmt: &IfStatement<'a>) {
+        self.increase_with_nesting();
+        self.visit_expression(&stmt.test);
+        self.nested(|visitor| visitor.visit_statement(&stmt.consequent));
+
+        let mut alternate = stmt.alternate.as_ref();
+        while let Some(stmt) = alternate {
+            self.complexity += 1;
+            if let Statement::IfStatement(else_if) = stmt {
+                self.visit_expression(&else_if.test);
+                self.nested(|visitor| visitor.visit_statement(&else_if.consequent));
+                alternate = else_if.alternate.as_ref();
+            } else {
+                self.nested(|visitor| visitor.visit_statement(stmt));
+                alternate = None;
+            }
+        }
+    }
+
+    fn visit_conditional_expression(&mut self, expr: &ConditionalExpression<'a>) {
+        self.increase_with_nesting();
+        self.visit_expression(&expr.test);
+        self.nested(|visitor| {
+            visitor.visit_expression(&expr.consequent);
+            visitor.visit_expression(&expr.alternate);
+        });
+    }
+
+    fn visit_switch_statement(&mut self, stmt: &SwitchStatement<'a>) {
+        self.increase_with_nesting();
+        self.nested(|visitor| walk_switch_statement(visitor, stmt));
+    }
+
+    fn visit_for_statement(&mut self, stmt: &ForStatement<'a>) {
+        self.increase_with_nesting();
+        self.nested(|visitor| walk_for_statement(visitor, stmt));
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &ForInStatement<'a>) {
+        self.increase_with_nesting();
+        self.nested(|visitor| walk_for_in_statement(visitor, stmt));
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &ForOfStatement<'a>) {
+        self.increase_with_nesting();
+        self.nested(|visitor| walk_for_of_statement(visitor, stmt));
+    }
+
+    fn visit_while_statement(&mut self, stmt: &WhileStatement<'a>) {
+        self.increase_with_nesting();
+        self.nested(|visitor| walk_while_statement(visitor, stmt));
+    }
+
+    fn visit_do_while_statement(&mut self, stmt: &DoWhileStatement<'a>) {
+        self.increase_with_nesting();
+        self.nested(|visitor| walk_do_while_statement(visitor, stmt));
+    }
+
+    fn visit_catch_clause(&mut self, clause: &CatchClause<'a>) {
+        self.increase_with_nesting();
+        self.nested(|visitor| walk_catch_clause(visitor, clause));
+    }
+
+    fn visit_break_statement(&mut self, stmt: &BreakStatement<'a>) {
+        if stmt.label.is_some() {
+            self.complexity += 1;
+        }
+    }
+
+    fn visit_continue_statement(&mut self, stmt: &ContinueStatement<'a>) {
+        if stmt.label.is_some() {
+            self.complexity += 1;
+        }
+    }
+
+    fn visit_logical_expression(&mut self, expr: &LogicalExpression<'a>) {
+        let mut operators = vec![];
+        let mut operands = vec![];
+        flatten_logical_expression(&expr.left, &mut operators, &mut operands);
+        operators.push(expr.operator);
+        flatten_logical_expression(&expr.right, &mut operators, &mut operands);
+        self.complexity += operators.into_iter().dedup().count();
+        for operand in operands {
+            self.visit_expression(operand);
+        }
+    }
+
+    fn visit_arrow_expression(&mut self, _expr: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: Option<ScopeFlags>) {}
+}
+
+/// Collects the operators and operands of a chain of logical expressions in source order,
+/// e.g. `&&`, `||` and `a`, `b`, `c` for `a && b || c`.
+fn flatten_logical_expression<'a, 'b>(
+    expr: &'b Expression<'a>,
+    operators: &mut Vec<LogicalOperator>,
+    operands: &mut Vec<&'b Expression<'a>>,
+) {
+    if let Expression::LogicalExpression(logical) = expr.without_parenthesized() {
+        flatten_logical_expression(&logical.left, operators, operands);
+        operators.push(logical.operator);
+        flatten_logical_expression(&logical.right, operators, operands);
+    } else {
+        operands.push(expr);
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function foo() { return 1; }", Some(json!([0]))),
+        ("function foo(a) { if (a) {} }", Some(json!([1]))),
+        ("function foo(a, b) { if (a && b) {} }", Some(json!([2]))),
+        ("function foo(a, b, c) { if (a && b && c) {} }", Some(json!([2]))),
+        ("function foo(a) { return a ? 1 : 2; }", Some(json!([1]))),
+        ("function foo(a) { if (a) { return () => { if (a) {} }; } }", Some(json!([1]))),
+        ("function foo() { try { bar(); } finally { baz(); } }", Some(json!([0]))),
+        ("function foo(a) { for (;;) { if (a) { break; } else { continue; } } }", Some(json!([4]))),
+        ("function foo(a) { if (a) {} }", Some(json!([{ "max": 1 }]))),
+        ("if (a) { if (b) { if (c) {} } }", Some(json!([0]))),
+        ("function foo(a) { if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} }", None),
+    ];
+
+    let fail = vec![
+        ("function foo(a) { if (a) {} }", Some(json!([0]))),
+        ("function foo(a, b) { if (a) { if (b) {} } }", Some(json!([2]))),
+        ("function foo(a, b) { if (a) {} else if (b) {} else {} }", Some(json!([2]))),
+        ("function foo(a, b, c) { return a && b || c && a; }", Some(json!([2]))),
+        ("function foo(a, b, c) { return a && (b && c); }", Some(json!([0]))),
+        ("function foo(items) { for (const item of items) { if (item) { continue; } } }", Some(json!([2]))),
+        ("function foo(a) { outer: for (;;) { while (a) { break outer; } } }", Some(json!([3]))),
+        ("function foo(a) { switch (a) { case 1: return a ? 1 : 2; } }", Some(json!([2]))),
+        ("function foo(a) { try { a(); } catch (e) { if (e) {} } }", Some(json!([2]))),
+        ("var foo = (a) => { do { if (a) {} } while (a); }", Some(json!([2]))),
+        ("class A { foo(a) { while (a) { if (a) {} } } }", Some(json!([2]))),
+        ("function foo(a) { if (a) { return () => { if (a) { if (a) {} } }; } }", Some(json!([2]))),
+        ("function foo(a) { if (a) {} }", Some(json!([{ "max": 0 }]))),
+        ("function foo(a) { if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} if (a) {} }", None),
+    ];
+
+    Tester::new(CognitiveComplexity::NAME, pass, fail).test_and_snapshot();
+}
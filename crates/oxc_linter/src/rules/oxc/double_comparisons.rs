@@ -39,6 +39,7 @@ declare_oxc_lint!(
     /// ```
     DoubleComparisons,
     correctness,
+    ast_types = [LogicalExpression]
 );
 
 #[allow(clippy::similar_names)]
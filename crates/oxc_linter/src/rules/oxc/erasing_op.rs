@@ -41,7 +41,8 @@ declare_oxc_lint!(
     /// let y = 0;
     /// ```
     ErasingOp,
-    correctness
+    correctness,
+    ast_types = [BinaryExpression]
 );
 
 impl Rule for ErasingOp {
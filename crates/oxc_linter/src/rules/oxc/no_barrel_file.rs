@@ -2,11 +2,15 @@ use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::ModuleRecord;
 use oxc_syntax::module_graph_visitor::{ModuleGraphVisitorBuilder, VisitFoldWhile};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, utils::rule_option};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoBarrelFile {
+    /// The number of modules a barrel file may load before being reported, `100` by default.
     threshold: usize,
 }
 
@@ -44,19 +48,18 @@ declare_oxc_lint!(
     /// export { foo } from 'foo';
     /// ```
     NoBarrelFile,
-    restriction
+    restriction,
+    ast_types = []
 );
 
 impl Rule for NoBarrelFile {
     #[allow(clippy::cast_possible_truncation)]
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self {
-            threshold: value
-                .get(0)
-                .and_then(|config| config.get("threshold"))
-                .and_then(serde_json::Value::as_u64)
-                .map_or(NoBarrelFile::default().threshold, |n| n as usize),
-        }
+        rule_option(&value, 0)
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<Self>()])
     }
 
     fn run_once(&self, ctx: &LintContext<'_>) {
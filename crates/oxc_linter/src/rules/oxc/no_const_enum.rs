@@ -36,6 +36,7 @@ declare_oxc_lint!(
     /// ```
     NoConstEnum,
     restriction,
+    ast_types = [TSEnumDeclaration]
 );
 
 impl Rule for NoConstEnum {
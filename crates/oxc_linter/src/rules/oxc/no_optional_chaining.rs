@@ -2,8 +2,10 @@ use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
 
 fn no_optional_chaining_diagnostic(span0: Span, x1: &str) -> OxcDiagnostic {
     if x1.is_empty() {
@@ -19,8 +21,10 @@ fn no_optional_chaining_diagnostic(span0: Span, x1: &str) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct NoOptionalChaining(Box<NoOptionalChainingConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoOptionalChainingConfig {
+    /// A custom help message to report.
     message: String,
 }
 
@@ -64,17 +68,16 @@ declare_oxc_lint!(
     ///
     NoOptionalChaining,
     restriction,
+    ast_types = [ChainExpression]
 );
 
 impl Rule for NoOptionalChaining {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let config = value.get(0);
-        let message = config
-            .and_then(|v| v.get("message"))
-            .and_then(serde_json::Value::as_str)
-            .unwrap_or_default();
+        Self(Box::new(rule_option(&value, 0)))
+    }
 
-        Self(Box::new(NoOptionalChainingConfig { message: message.to_string() }))
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<NoOptionalChainingConfig>()])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
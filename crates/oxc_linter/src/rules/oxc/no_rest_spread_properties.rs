@@ -2,8 +2,10 @@ use oxc_ast::{ast::AssignmentTarget, AstKind};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
 
 fn no_rest_spread_properties_diagnostic(span0: Span, x1: &str, x2: &str) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("oxc(no-rest-spread-properties): {x1} are not allowed. {x2}"))
@@ -13,9 +15,12 @@ fn no_rest_spread_properties_diagnostic(span0: Span, x1: &str, x2: &str) -> OxcD
 #[derive(Debug, Default, Clone)]
 pub struct NoRestSpreadProperties(Box<NoRestSpreadPropertiesOptions>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoRestSpreadPropertiesOptions {
+    /// A custom help message to report on object spreads.
     object_spread_message: String,
+    /// A custom help message to report on object rests.
     object_rest_message: String,
 }
 
@@ -60,24 +65,16 @@ declare_oxc_lint!(
     ///
     NoRestSpreadProperties,
     restriction,
+    ast_types = [SpreadElement, BindingRestElement, AssignmentTarget]
 );
 
 impl Rule for NoRestSpreadProperties {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let config = value.get(0);
-        let object_spread_message = config
-            .and_then(|v| v.get("objectSpreadMessage"))
-            .and_then(serde_json::Value::as_str)
-            .unwrap_or_default();
-        let object_rest_message = config
-            .and_then(|v| v.get("objectRestMessage"))
-            .and_then(serde_json::Value::as_str)
-            .unwrap_or_default();
+        Self(Box::new(rule_option(&value, 0)))
+    }
 
-        Self(Box::new(NoRestSpreadPropertiesOptions {
-            object_spread_message: object_spread_message.to_string(),
-            object_rest_message: object_rest_message.to_string(),
-        }))
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<NoRestSpreadPropertiesOptions>()])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
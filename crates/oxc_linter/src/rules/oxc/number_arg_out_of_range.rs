@@ -31,7 +31,8 @@ declare_oxc_lint!(
     /// var s = x.toString(1);
     /// ```
     NumberArgOutOfRange,
-    correctness
+    correctness,
+    ast_types = [CallExpression]
 );
 
 impl Rule for NumberArgOutOfRange {
@@ -55,7 +55,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     OnlyUsedInRecursion,
-    correctness
+    correctness,
+    ast_types = [Function]
 );
 
 impl Rule for OnlyUsedInRecursion {
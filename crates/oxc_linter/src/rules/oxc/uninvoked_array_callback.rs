@@ -38,7 +38,8 @@ declare_oxc_lint!(
     ///   const list = new Array(5).map(_ => createElement());
     /// ```
     UninvokedArrayCallback,
-    correctness
+    correctness,
+    ast_types = [NewExpression]
 );
 
 impl Rule for UninvokedArrayCallback {
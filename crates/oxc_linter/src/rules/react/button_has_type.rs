@@ -8,11 +8,13 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{get_prop_value, has_jsx_prop_lowercase, is_create_element_call},
+    utils::{get_prop_value, has_jsx_prop_lowercase, is_create_element_call, rule_option},
     AstNode,
 };
 
@@ -28,10 +30,14 @@ fn invalid_type_prop(span0: Span) -> OxcDiagnostic {
         .with_labels([span0.into()])
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct ButtonHasType {
+    /// Allow `type="button"`, `true` by default.
     button: bool,
+    /// Allow `type="submit"`, `true` by default.
     submit: bool,
+    /// Allow `type="reset"`, `true` by default.
     reset: bool,
 }
 
@@ -61,7 +67,8 @@ declare_oxc_lint!(
     /// <button type="submit" />
     /// ```
     ButtonHasType,
-    restriction
+    restriction,
+    ast_types = [JSXOpeningElement, CallExpression]
 );
 
 impl Rule for ButtonHasType {
@@ -132,19 +139,11 @@ impl Rule for ButtonHasType {
     }
 
     fn from_configuration(value: serde_json::Value) -> Self {
-        let value = value.as_array().and_then(|arr| arr.first()).and_then(|val| val.as_object());
+        rule_option(&value, 0)
+    }
 
-        Self {
-            button: value
-                .and_then(|val| val.get("button").and_then(serde_json::Value::as_bool))
-                .unwrap_or(true),
-            submit: value
-                .and_then(|val| val.get("submit").and_then(serde_json::Value::as_bool))
-                .unwrap_or(true),
-            reset: value
-                .and_then(|val| val.get("reset").and_then(serde_json::Value::as_bool))
-                .unwrap_or(true),
-        }
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<Self>()])
     }
 }
 
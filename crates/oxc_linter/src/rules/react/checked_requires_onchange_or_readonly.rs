@@ -5,11 +5,13 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{get_element_type, get_jsx_attribute_name, is_create_element_call},
+    utils::{get_element_type, get_jsx_attribute_name, is_create_element_call, rule_option},
     AstNode,
 };
 
@@ -25,9 +27,12 @@ fn exclusive_checked_attribute(span0: Span, span1: Span) -> OxcDiagnostic {
         .with_labels([span0.into(), span1.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct CheckedRequiresOnchangeOrReadonly {
+    /// Allow `checked` without `onChange` nor `readOnly`.
     ignore_missing_properties: bool,
+    /// Allow both `checked` and `defaultChecked`.
     ignore_exclusive_checked_attribute: bool,
 }
 
@@ -59,7 +64,8 @@ declare_oxc_lint!(
     /// React.createElement('input', { type: 'checkbox', defaultChecked: true });
     /// ```
     CheckedRequiresOnchangeOrReadonly,
-    pedantic
+    pedantic,
+    ast_types = [JSXOpeningElement, CallExpression]
 );
 
 impl Rule for CheckedRequiresOnchangeOrReadonly {
@@ -183,20 +189,11 @@ impl Rule for CheckedRequiresOnchangeOrReadonly {
     }
 
     fn from_configuration(value: serde_json::Value) -> Self {
-        let value = value.as_array().and_then(|arr| arr.first()).and_then(|val| val.as_object());
+        rule_option(&value, 0)
+    }
 
-        Self {
-            ignore_missing_properties: value
-                .and_then(|val| {
-                    val.get("ignoreMissingProperties").and_then(serde_json::Value::as_bool)
-                })
-                .unwrap_or(false),
-            ignore_exclusive_checked_attribute: value
-                .and_then(|val| {
-                    val.get("ignoreExclusiveCheckedAttribute").and_then(serde_json::Value::as_bool)
-                })
-                .unwrap_or(false),
-        }
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<Self>()])
     }
 }
 
@@ -0,0 +1,519 @@
+use itertools::Itertools;
+use oxc_ast::{
+    ast::{
+        ArrayExpressionElement, BindingPatternKind, Expression, MemberExpression,
+        VariableDeclarationKind,
+    },
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{AstNodeId, SymbolId};
+use oxc_span::{GetSpan, Span};
+use regex::Regex;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
+
+fn dependencies_diagnostic(
+    hook_name: &str,
+    problem: &str,
+    suggestion: &str,
+    span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint-plugin-react-hooks(exhaustive-deps): React Hook {hook_name} has {problem}"
+    ))
+    .with_help(format!("Update the dependencies array to be: {suggestion}"))
+    .with_label(span)
+}
+
+fn unknown_callback_diagnostic(hook_name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint-plugin-react-hooks(exhaustive-deps): React Hook {hook_name} received a function whose dependencies are unknown."
+    ))
+    .with_help("Pass an inline function instead.")
+    .with_label(span)
+}
+
+fn missing_array_diagnostic(hook_name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint-plugin-react-hooks(exhaustive-deps): React Hook {hook_name} does nothing when called with only one argument."
+    ))
+    .with_help("Did you forget to pass an array of dependencies?")
+    .with_label(span)
+}
+
+fn not_array_literal_diagnostic(hook_name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint-plugin-react-hooks(exhaustive-deps): React Hook {hook_name} was passed a dependency list that is not an array literal."
+    ))
+    .with_help("This means we can't statically verify whether you've passed the correct dependencies.")
+    .with_label(span)
+}
+
+fn complex_expression_diagnostic(hook_name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint-plugin-react-hooks(exhaustive-deps): React Hook {hook_name} has a complex expression in the dependency array."
+    ))
+    .with_help("Extract it to a separate variable so it can be statically checked.")
+    .with_label(span)
+}
+
+/// <https://github.com/facebook/react/blob/main/packages/eslint-plugin-react-hooks/src/ExhaustiveDeps.js>
+#[derive(Debug, Default, Clone)]
+pub struct ExhaustiveDeps(Box<ExhaustiveDepsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct ExhaustiveDepsConfig {
+    /// Custom hooks whose first argument is a callback with a dependency array as second argument.
+    additional_hooks: Option<Regex>,
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+struct ExhaustiveDepsOptions {
+    /// Regular expression of the names of custom hooks to check, e.g. `(useMyEffect|useMyMemo)`.
+    additional_hooks: Option<String>,
+}
+
+impl std::ops::Deref for ExhaustiveDeps {
+    type Target = ExhaustiveDepsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Verifies the dependency arrays of `useEffect`, `useLayoutEffect`, `useInsertionEffect`,
+    /// `useCallback`, `useMemo` and `useImperativeHandle`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A callback which reads a value of the component missing from its dependencies
+    /// keeps using the value of the render it was created in, after the value changed.
+    /// Values declared outside of the component never change between renders,
+    /// so they are not valid dependencies.
+    ///
+    /// The setters returned by `useState`, `useReducer` and `useTransition`,
+    /// the refs returned by `useRef`, and constants initialized with a string, number or `null`
+    /// are stable across renders, they may be omitted.
+    ///
+    /// ### Options
+    ///
+    /// * `additionalHooks`: a regular expression of the names of custom hooks to check,
+    ///   which take the callback as first argument and the dependency array as second argument.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function Counter({ step }) {
+    ///   const [count, setCount] = useState(0);
+    ///   // `step` is missing from the dependencies
+    ///   const increment = useCallback(() => setCount(count + step), [count]);
+    /// }
+    /// ```
+    ExhaustiveDeps,
+    nursery,
+    ast_types = [CallExpression]
+);
+
+impl Rule for ExhaustiveDeps {
+    fn from_configuration(value: Value) -> Self {
+        let ExhaustiveDepsOptions { additional_hooks } = rule_option(&value, 0);
+        let additional_hooks = additional_hooks.and_then(|pattern| Regex::new(&pattern).ok());
+        Self(Box::new(ExhaustiveDepsConfig { additional_hooks }))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<ExhaustiveDepsOptions>()])
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else { return };
+        let Some(hook_name) = call.callee_name() else { return };
+        let Some(callback_index) = self.callback_index(hook_name) else { return };
+        let Some(callback) = call.arguments.get(callback_index).and_then(|arg| arg.as_expression())
+        else {
+            return;
+        };
+        let dependencies =
+            call.arguments.get(callback_index + 1).and_then(|arg| arg.as_expression());
+        let is_effect = hook_name.ends_with("Effect");
+
+        let Some(dependencies) = dependencies else {
+            if matches!(hook_name, "useCallback" | "useMemo") {
+                ctx.diagnostic(missing_array_diagnostic(hook_name, call.span));
+            }
+            return;
+        };
+        let Expression::ArrayExpression(dependencies) = dependencies.without_parenthesized() else {
+            ctx.diagnostic(not_array_literal_diagnostic(hook_name, dependencies.span()));
+            return;
+        };
+        if !matches!(
+            callback.without_parenthesized(),
+            Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_)
+        ) {
+            if matches!(callback, Expression::Identifier(_)) {
+                ctx.diagnostic(unknown_callback_diagnostic(hook_name, callback.span()));
+            }
+            return;
+        }
+
+        // Values declared outside of the component are not dependencies.
+        let Some(component) = ctx
+            .nodes()
+            .ancestors(node.id())
+            .map(|id| ctx.nodes().get_node(id))
+            .find(|node| node.kind().is_function_like())
+        else {
+            return;
+        };
+        let component_span = component.kind().span();
+        let callback_span = callback.span();
+
+        let used = used_dependencies(ctx, component, callback_span);
+        let mut missing = used
+            .iter()
+            .filter(|(path, symbol_id)| {
+                !is_stable(ctx, *symbol_id)
+                    && !dependencies.elements.iter().any(|element| {
+                        declared_path(element).is_some_and(|declared| {
+                            path == &declared || path.starts_with(&format!("{declared}."))
+                        })
+                    })
+            })
+            .map(|(path, _)| path.clone())
+            .collect::<Vec<_>>();
+        missing.sort_unstable();
+        missing.dedup();
+
+        let mut declared = vec![];
+        let mut unnecessary = vec![];
+        let mut outer = vec![];
+        let mut duplicates = vec![];
+        for element in &dependencies.elements {
+            let Some(path) = declared_path(element) else {
+                if !matches!(element, ArrayExpressionElement::Elision(_)) {
+                    ctx.diagnostic(complex_expression_diagnostic(hook_name, element.span()));
+                }
+                continue;
+            };
+            if declared.contains(&path) {
+                duplicates.push(path);
+                continue;
+            }
+            let is_outer = root_symbol(ctx, element).map_or(true, |symbol_id| {
+                !contains(component_span, ctx.symbols().get_span(symbol_id))
+            });
+            let is_used = used.iter().any(|(used, _)| {
+                used == &path
+                    || used.starts_with(&format!("{path}."))
+                    || path.starts_with(&format!("{used}."))
+            });
+            if is_outer {
+                outer.push(path.clone());
+                unnecessary.push(path);
+            } else if !is_effect && !is_used {
+                unnecessary.push(path);
+            } else {
+                declared.push(path);
+            }
+        }
+
+        let problem = describe_problem(&missing, "a", "missing", "include")
+            .or_else(|| describe_problem(&unnecessary, "an", "unnecessary", "exclude"))
+            .or_else(|| describe_problem(&duplicates, "a", "duplicate", "omit"));
+        let Some(mut problem) = problem else { return };
+        if let Some(outer) = outer.first().filter(|_| missing.is_empty()) {
+            problem = format!(
+                "{problem} Outer scope values like '{outer}' aren't valid dependencies because mutating them doesn't re-render the component."
+            );
+        }
+
+        // Keep the order of the declared dependencies, unless they are sorted.
+        let is_sorted = declared.windows(2).all(|pair| pair[0] <= pair[1]);
+        let mut suggested = declared;
+        suggested.extend(missing);
+        if is_sorted {
+            suggested.sort_unstable();
+        }
+        // `props` covers `props.foo`.
+        let suggested = suggested
+            .iter()
+            .filter(|path| !suggested.iter().any(|other| path.starts_with(&format!("{other}."))))
+            .join(", ");
+        let suggestion = format!("[{suggested}]");
+        let diagnostic =
+            dependencies_diagnostic(hook_name, &problem, &suggestion, dependencies.span);
+        ctx.diagnostic_with_suggestion(diagnostic, |fixer| {
+            fixer.replace(dependencies.span, suggestion)
+        });
+    }
+}
+
+impl ExhaustiveDeps {
+    /// The position of the callback among the arguments of `hook_name`, `None` if it is not checked.
+    fn callback_index(&self, hook_name: &str) -> Option<usize> {
+        match hook_name {
+            "useEffect" | "useLayoutEffect" | "useInsertionEffect" | "useCallback" | "useMemo" => {
+                Some(0)
+            }
+            "useImperativeHandle" => Some(1),
+            _ if self.additional_hooks.as_ref().is_some_and(|hooks| hooks.is_match(hook_name)) => {
+                Some(0)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The dependencies read by the callback, as paths like `props.foo`,
+/// with the symbol they start with, declared in the component outside of the callback.
+fn used_dependencies(
+    ctx: &LintContext,
+    component: &AstNode,
+    callback_span: Span,
+) -> Vec<(String, SymbolId)> {
+    let symbols = ctx.symbols();
+    let component_span = component.kind().span();
+    let mut used = vec![];
+    for symbol_id in symbols.iter() {
+        let symbol_span = symbols.get_span(symbol_id);
+        // The name of a function declaration belongs to the scope around it.
+        if !contains(component_span, symbol_span)
+            || contains(callback_span, symbol_span)
+            || symbols.get_declaration(symbol_id) == component.id()
+        {
+            continue;
+        }
+        for reference in symbols.get_resolved_references(symbol_id) {
+            if !contains(callback_span, reference.span()) || !reference.is_read() {
+                continue;
+            }
+            let path = dependency_path(ctx, reference.node_id(), reference.name().as_str());
+            used.push((path, symbol_id));
+        }
+    }
+    used
+}
+
+/// The path of the member accesses on a reference, e.g. `props.foo.bar` for `props.foo.bar`,
+/// and `props.foo` for the method call `props.foo.bar()`.
+fn dependency_path(ctx: &LintContext, node_id: AstNodeId, name: &str) -> String {
+    let nodes = ctx.nodes();
+    let mut path = name.to_string();
+    let mut current = nodes.get_node(node_id);
+    while let Some(parent) = nodes.parent_node(current.id()) {
+        let AstKind::MemberExpression(member @ MemberExpression::StaticMemberExpression(expr)) =
+            parent.kind()
+        else {
+            break;
+        };
+        if member.object().span() != current.kind().span() {
+            break;
+        }
+        let is_callee = nodes.parent_kind(parent.id()).is_some_and(|kind| {
+            matches!(kind, AstKind::CallExpression(call) if call.callee.span() == member.span())
+        });
+        if is_callee {
+            break;
+        }
+        path.push('.');
+        path.push_str(expr.property.name.as_str());
+        current = parent;
+    }
+    path
+}
+
+/// The path of a declared dependency, `None` for expressions other than identifiers
+/// and static member accesses.
+fn declared_path(element: &ArrayExpressionElement) -> Option<String> {
+    fn path(expr: &Expression) -> Option<String> {
+        match expr.without_parenthesized() {
+            Expression::Identifier(ident) => Some(ident.name.to_string()),
+            Expression::StaticMemberExpression(member) => {
+                Some(format!("{}.{}", path(&member.object)?, member.property.name))
+            }
+            Expression::ChainExpression(chain) => match chain.expression.as_member_expression() {
+                Some(MemberExpression::StaticMemberExpression(member)) => {
+                    Some(format!("{}.{}", path(&member.object)?, member.property.name))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+    path(element.as_expression()?)
+}
+
+/// The symbol of the identifier a declared dependency starts with, `None` for globals.
+fn root_symbol(ctx: &LintContext, element: &ArrayExpressionElement) -> Option<SymbolId> {
+    let mut expr = element.as_expression()?.without_parenthesized();
+    loop {
+        match expr {
+            Expression::Identifier(ident) => {
+                let reference_id = ident.reference_id.get()?;
+                return ctx.symbols().get_reference(reference_id).symbol_id();
+            }
+            Expression::StaticMemberExpression(member) => expr = &member.object,
+            Expression::ChainExpression(chain) => {
+                expr = chain.expression.as_member_expression()?.object();
+            }
+            _ => return None,
+        }
+    }
+}
+
+/// Whether the value of `symbol_id` is the same in every render, e.g. a `setState` function.
+fn is_stable(ctx: &LintContext, symbol_id: SymbolId) -> bool {
+    let declaration = ctx.nodes().get_node(ctx.symbols().get_declaration(symbol_id));
+    let AstKind::VariableDeclarator(declarator) = declaration.kind() else {
+        return false;
+    };
+    let Some(init) = declarator.init.as_ref().map(Expression::get_inner_expression) else {
+        return false;
+    };
+    if declarator.kind == VariableDeclarationKind::Const
+        && matches!(
+            init,
+            Expression::StringLiteral(_)
+                | Expression::NumericLiteral(_)
+                | Expression::NullLiteral(_)
+        )
+    {
+        return true;
+    }
+    let Expression::CallExpression(call) = init else {
+        return false;
+    };
+    match (&declarator.id.kind, call.callee_name()) {
+        (BindingPatternKind::BindingIdentifier(_), Some("useRef")) => true,
+        (
+            BindingPatternKind::ArrayPattern(pattern),
+            Some("useState" | "useReducer" | "useTransition"),
+        ) => pattern.elements.get(1).and_then(Option::as_ref).is_some_and(|element| {
+            matches!(
+                &element.kind,
+                BindingPatternKind::BindingIdentifier(ident)
+                    if ident.symbol_id.get() == Some(symbol_id)
+            )
+        }),
+        _ => false,
+    }
+}
+
+fn contains(outer: Span, inner: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+/// The message of a problem with dependencies, like eslint-plugin-react-hooks:
+/// `a missing dependency: 'foo'. Either include it or remove the dependency array.`
+fn describe_problem(
+    dependencies: &[String],
+    article: &str,
+    label: &str,
+    verb: &str,
+) -> Option<String> {
+    if dependencies.is_empty() {
+        return None;
+    }
+    let mut names = dependencies.iter().map(|name| format!("'{name}'")).collect::<Vec<_>>();
+    names.sort_unstable();
+    let names = match names.as_slice() {
+        [name] => name.clone(),
+        [first, second] => format!("{first} and {second}"),
+        [init @ .., last] => format!("{}, and {last}", init.iter().join(", ")),
+        [] => unreachable!(),
+    };
+    Some(if dependencies.len() == 1 {
+        format!(
+            "{article} {label} dependency: {names}. Either {verb} it or remove the dependency array."
+        )
+    } else {
+        format!("{label} dependencies: {names}. Either {verb} them or remove the dependency array.")
+    })
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function MyComponent() { const local = {}; useEffect(() => { console.log(local); }); }", None),
+        ("function MyComponent() { useEffect(() => { const local = {}; console.log(local); }, []); }", None),
+        ("function MyComponent() { const local = someFunc(); useEffect(() => { console.log(local); }, [local]); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props.foo]); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props]); }", None),
+        ("function MyComponent(props) { useEffect(() => { props.onChange(); }, [props]); }", None),
+        ("function MyComponent({ foo }) { const bar = useMemo(() => foo * 2, [foo]); }", None),
+        ("const local = {}; function MyComponent() { useEffect(() => { console.log(local); }, []); }", None),
+        ("function MyComponent() { useEffect(() => { console.log(window.location); }, []); }", None),
+        ("function MyComponent() { const [count, setCount] = useState(0); const increment = useCallback(() => setCount((c) => c + 1), []); }", None),
+        ("function MyComponent() { const [state, dispatch] = useReducer(reducer, {}); useEffect(() => { dispatch({ type: 'init' }); }, []); }", None),
+        ("function MyComponent() { const ref = useRef(); useEffect(() => { ref.current = 1; }, []); }", None),
+        ("function MyComponent() { const [isPending, startTransition] = useTransition(); useEffect(() => { startTransition(() => {}); }, []); }", None),
+        ("function MyComponent() { const delay = 100; useEffect(() => { setTimeout(() => {}, delay); }, []); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props.foo, props.bar]); }", None),
+        ("function MyComponent(props) { useImperativeHandle(ref, () => ({ focus: () => props.onFocus() }), [props]); }", None),
+        ("function MyComponent(props) { useCustomEffect(() => { console.log(props.foo); }); }", Some(json!([{ "additionalHooks": "useCustomEffect" }]))),
+        ("function MyComponent(props) { useCustomEffect(() => { console.log(props.foo); }, [props.foo]); }", Some(json!([{ "additionalHooks": "useCustomEffect" }]))),
+        ("function MyComponent(props) { useCustomEffect(() => { console.log(props.foo); }, []); }", None),
+    ];
+
+    let fail = vec![
+        ("function MyComponent() { const local = someFunc(); useEffect(() => { console.log(local); }, []); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo, props.bar); }, []); }", None),
+        ("function MyComponent({ a, b, c }) { useEffect(() => { console.log(a, b, c); }, [b]); }", None),
+        ("function MyComponent(props) { useEffect(() => { console.log(props.foo); }, [props.foo.bar]); }", None),
+        ("function MyComponent({ foo, bar }) { const value = useMemo(() => foo, [foo, bar]); }", None),
+        ("function MyComponent() { const ref = useRef(); const value = useCallback(() => {}, [ref]); }", None),
+        ("const local = {}; function MyComponent() { useEffect(() => { console.log(local); }, [local]); }", None),
+        ("function MyComponent({ foo }) { useEffect(() => { console.log(foo); }, [foo, foo]); }", None),
+        ("function MyComponent({ foo }) { useEffect(() => { console.log(foo); }, [foo.bar()]); }", None),
+        ("function MyComponent({ foo }) { const value = useMemo(() => foo); }", None),
+        ("function MyComponent({ foo }) { useEffect(() => { console.log(foo); }, deps); }", None),
+        ("function MyComponent({ foo }) { useEffect(handler, [foo]); }", None),
+        ("function MyComponent({ foo }) { let delay = 100; useEffect(() => { setTimeout(() => {}, delay); }, [foo]); }", None),
+        ("function MyComponent(props) { useImperativeHandle(ref, () => ({ focus: () => props.onFocus() }), []); }", None),
+        ("function MyComponent(props) { useCustomEffect(() => { console.log(props.foo); }, []); }", Some(json!([{ "additionalHooks": "useCustomEffect" }]))),
+        ("function MyComponent(props) { React.useEffect(() => { console.log(props.foo); }, []); }", None),
+    ];
+
+    let fix = vec![
+        (
+            "function MyComponent() { const local = someFunc(); useEffect(() => { console.log(local); }, []); }",
+            "function MyComponent() { const local = someFunc(); useEffect(() => { console.log(local); }, [local]); }",
+            None,
+        ),
+        (
+            "function MyComponent({ a, b, c }) { useEffect(() => { console.log(a, b, c); }, [b]); }",
+            "function MyComponent({ a, b, c }) { useEffect(() => { console.log(a, b, c); }, [a, b, c]); }",
+            None,
+        ),
+        (
+            "function MyComponent({ foo, bar }) { const value = useMemo(() => foo, [foo, bar]); }",
+            "function MyComponent({ foo, bar }) { const value = useMemo(() => foo, [foo]); }",
+            None,
+        ),
+        (
+            "const local = {}; function MyComponent() { useEffect(() => { console.log(local); }, [local]); }",
+            "const local = {}; function MyComponent() { useEffect(() => { console.log(local); }, []); }",
+            None,
+        ),
+        (
+            "function MyComponent({ foo }) { useEffect(() => { console.log(foo); }, [foo, foo]); }",
+            "function MyComponent({ foo }) { useEffect(() => { console.log(foo); }, [foo]); }",
+            None,
+        ),
+    ];
+
+    Tester::new(ExhaustiveDeps::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -44,7 +44,8 @@ declare_oxc_lint!(
     /// [1, 2, 3]?.map(x => <BabelEslintApp key={x} />)
     /// ```
     JsxKey,
-    correctness
+    correctness,
+    ast_types = [JSXElement, JSXFragment]
 );
 
 impl Rule for JsxKey {
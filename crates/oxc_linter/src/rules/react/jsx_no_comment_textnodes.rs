@@ -46,7 +46,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     JsxNoCommentTextnodes,
-    suspicious
+    suspicious,
+    ast_types = [JSXText]
 );
 
 impl Rule for JsxNoCommentTextnodes {
@@ -40,7 +40,8 @@ declare_oxc_lint!(
     ///
     /// ```
     JsxNoDuplicateProps,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for JsxNoDuplicateProps {
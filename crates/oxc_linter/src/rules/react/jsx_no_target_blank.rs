@@ -10,8 +10,10 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
 
 fn target_blank_without_noreferrer(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint-plugin-react(jsx-no-target-blank): Using target=`_blank` without rel=`noreferrer` (which implies rel=`noopener`) is a security risk in older browsers: see https://mathiasbynens.github.io/rel-noopener/#recommendations")
@@ -31,16 +33,24 @@ fn explicit_props_in_spread_attributes(span0: Span) -> OxcDiagnostic {
 .with_labels([span0.into()])
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct JsxNoTargetBlank {
+    /// Whether links with a dynamic `href` are checked, `always` by default.
     enforce_dynamic_links: EnforceDynamicLinksEnum,
+    /// Report the elements with spread attributes, which may set `target="_blank"`.
     warn_on_spread_attributes: bool,
+    /// Only require `noopener`, allowing the referrer to be sent.
     allow_referrer: bool,
+    /// Check the link components, `true` by default.
     links: bool,
+    /// Check the form components.
     forms: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+#[schemars(rename = "JsxNoTargetBlankEnforceDynamicLinks")]
 enum EnforceDynamicLinksEnum {
     Always,
     Never,
@@ -115,7 +125,8 @@ declare_oxc_lint!(
     /// var Hello = <a target='_blank' href={dynamicLink}></a>
     /// ```
     JsxNoTargetBlank,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 impl Rule for JsxNoTargetBlank {
@@ -215,33 +226,11 @@ impl Rule for JsxNoTargetBlank {
     }
 
     fn from_configuration(value: serde_json::Value) -> Self {
-        let value = value.as_array().and_then(|arr| arr.first()).and_then(|val| val.as_object());
+        rule_option(&value, 0)
+    }
 
-        Self {
-            enforce_dynamic_links: value
-                .and_then(|val| val.get("enforceDynamicLinks").and_then(serde_json::Value::as_str))
-                .map_or(EnforceDynamicLinksEnum::Always, |str| {
-                    if str == "always" {
-                        EnforceDynamicLinksEnum::Always
-                    } else {
-                        EnforceDynamicLinksEnum::Never
-                    }
-                }),
-            warn_on_spread_attributes: value
-                .and_then(|val| {
-                    val.get("warnOnSpreadAttributes").and_then(serde_json::Value::as_bool)
-                })
-                .unwrap_or(false),
-            links: value
-                .and_then(|val| val.get("links").and_then(serde_json::Value::as_bool))
-                .unwrap_or(true),
-            forms: value
-                .and_then(|val| val.get("forms").and_then(serde_json::Value::as_bool))
-                .unwrap_or(false),
-            allow_referrer: value
-                .and_then(|val| val.get("allowReferrer").and_then(serde_json::Value::as_bool))
-                .unwrap_or(false),
-        }
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<Self>()])
     }
 }
 
@@ -30,7 +30,8 @@ declare_oxc_lint!(
     /// const C = <B />
     /// ```
     JsxNoUndef,
-    correctness
+    correctness,
+    ast_types = [JSXOpeningElement]
 );
 
 fn get_resolvable_ident<'a>(node: &'a JSXElementName<'a>) -> Option<&'a JSXIdentifier> {
@@ -53,6 +54,10 @@ fn get_member_ident<'a>(expr: &'a JSXMemberExpression<'a>) -> &'a JSXIdentifier
 }
 
 impl Rule for JsxNoUndef {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        ctx.source_type().is_jsx()
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXOpeningElement(elem) = &node.kind() {
             if let Some(ident) = get_resolvable_ident(&elem.name) {
@@ -35,7 +35,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     NoDanger,
-    restriction
+    restriction,
+    ast_types = [JSXElement, CallExpression]
 );
 
 impl Rule for NoDanger {
@@ -80,7 +80,8 @@ declare_oxc_lint!(
     ///  }
     /// ```
     NoDirectMutationState,
-    correctness
+    correctness,
+    ast_types = [AssignmentExpression, UpdateExpression]
 );
 
 impl Rule for NoDirectMutationState {
@@ -35,7 +35,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoFindDomNode,
-    correctness
+    correctness,
+    ast_types = [CallExpression]
 );
 
 impl Rule for NoFindDomNode {
@@ -38,7 +38,8 @@ declare_oxc_lint!(
     /// };
     /// ```
     NoIsMounted,
-    correctness
+    correctness,
+    ast_types = [CallExpression]
 );
 
 impl Rule for NoIsMounted {
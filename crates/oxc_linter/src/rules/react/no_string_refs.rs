@@ -8,11 +8,13 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{get_parent_es5_component, get_parent_es6_component},
+    utils::{get_parent_es5_component, get_parent_es6_component, rule_option},
     AstNode,
 };
 
@@ -26,8 +28,10 @@ fn string_in_ref_deprecated(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint-plugin-react(no-string-refs): Using string literals in ref attributes is deprecated.").with_help("Using reference callback instead").with_labels([span0.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoStringRefs {
+    /// Also report the template literals used as refs.
     no_template_literals: bool,
 }
 
@@ -67,7 +71,8 @@ declare_oxc_lint!(
     /// });
     /// ```
     NoStringRefs,
-    correctness
+    correctness,
+    ast_types = [JSXAttributeItem, MemberExpression]
 );
 
 fn contains_string_literal(
@@ -100,10 +105,11 @@ fn is_literal_ref_attribute(attr: &JSXAttribute, no_template_literals: bool) ->
 
 impl Rule for NoStringRefs {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let no_template_literals =
-            value.get("noTemplateLiterals").and_then(serde_json::Value::as_bool).unwrap_or(false);
+        rule_option(&value, 0)
+    }
 
-        Self { no_template_literals }
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<Self>()])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -225,7 +231,7 @@ fn test() {
                 }
               });
             ",
-            Some(serde_json::json!({ "noTemplateLiterals": true })),
+            Some(serde_json::json!([{ "noTemplateLiterals": true }])),
         ),
         (
             "
@@ -238,7 +244,7 @@ fn test() {
                 }
               });
             ",
-            Some(serde_json::json!({ "noTemplateLiterals": true })),
+            Some(serde_json::json!([{ "noTemplateLiterals": true }])),
         ),
         (
             "
@@ -248,7 +254,7 @@ fn test() {
                 }
               });
             ",
-            Some(serde_json::json!({ "noTemplateLiterals": true })),
+            Some(serde_json::json!([{ "noTemplateLiterals": true }])),
         ),
         (
             "
@@ -281,7 +287,7 @@ fn test() {
                 }
               }
             ",
-            Some(serde_json::json!({ "noTemplateLiterals": true })),
+            Some(serde_json::json!([{ "noTemplateLiterals": true }])),
         ),
     ];
 
@@ -34,10 +34,15 @@ declare_oxc_lint!(
     ///
     /// ```
     ReactInJsxScope,
-    suspicious
+    suspicious,
+    ast_types = [JSXOpeningElement, JSXFragment]
 );
 
 impl Rule for ReactInJsxScope {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        ctx.source_type().is_jsx()
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let node_span = match node.kind() {
             AstKind::JSXOpeningElement(v) => v.name.span(),
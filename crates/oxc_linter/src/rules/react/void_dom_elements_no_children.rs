@@ -45,7 +45,8 @@ declare_oxc_lint!(
     /// React.createElement('div', { dangerouslySetInnerHTML: { __html: 'HTML' } })
     /// ```
     VoidDomElementsNoChildren,
-    correctness
+    correctness,
+    ast_types = [JSXElement, CallExpression]
 );
 
 const VOID_DOM_ELEMENTS: phf::Set<&'static str> = phf_set![
@@ -33,7 +33,8 @@ declare_oxc_lint!(
     /// <Item callback={this.props.jsx} />
     /// ```
     JsxNoJsxAsProp,
-    perf
+    perf,
+    ast_types = [JSXElement]
 );
 
 impl Rule for JsxNoJsxAsProp {
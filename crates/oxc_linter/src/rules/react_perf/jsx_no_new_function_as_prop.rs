@@ -36,7 +36,8 @@ declare_oxc_lint!(
     /// <Item callback={this.props.callback} />
     /// ```
     JsxNoNewFunctionAsProp,
-    perf
+    perf,
+    ast_types = [JSXElement]
 );
 
 impl Rule for JsxNoNewFunctionAsProp {
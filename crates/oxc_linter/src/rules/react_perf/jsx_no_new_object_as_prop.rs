@@ -40,7 +40,8 @@ declare_oxc_lint!(
     /// <Item config={staticConfig} />
     /// ```
     JsxNoNewObjectAsProp,
-    perf
+    perf,
+    ast_types = [JSXElement]
 );
 
 impl Rule for JsxNoNewObjectAsProp {
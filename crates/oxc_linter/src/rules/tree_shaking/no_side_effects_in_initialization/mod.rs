@@ -166,7 +166,8 @@ declare_oxc_lint!(
     /// /*@__PURE__*/ x();
     /// ```
     NoSideEffectsInInitialization,
-    nursery
+    nursery,
+    ast_types = []
 );
 
 impl Rule for NoSideEffectsInInitialization {
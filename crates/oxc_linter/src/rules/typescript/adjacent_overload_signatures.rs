@@ -75,7 +75,16 @@ declare_oxc_lint!(
     /// export function foo(sn: string | number): void;
     /// ```
     AdjacentOverloadSignatures,
-    style
+    style,
+    ast_types = [
+        Class,
+        TSTypeLiteral,
+        Program,
+        TSModuleBlock,
+        TSInterfaceDeclaration,
+        BlockStatement,
+        FunctionBody,
+    ]
 );
 
 #[derive(PartialEq, Debug)]
@@ -282,6 +291,10 @@ fn check_and_report(methods: &Vec<Option<Method>>, ctx: &LintContext<'_>) {
 }
 
 impl Rule for AdjacentOverloadSignatures {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        ctx.source_type().is_typescript()
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::Class(class) => {
@@ -6,8 +6,10 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::AstNode;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, utils::rule_option};
 
 #[derive(Debug, Default, Clone)]
 pub struct ArrayType(Box<ArrayTypeConfig>);
@@ -44,11 +46,12 @@ fn array_simple(x0: &str, x1: &str, x2: &str, span3: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("typescript-eslint(array-type): Array type using '{x1}<{x2}>' is forbidden for simple types. Use '{x0}{x2}[]' instead.")).with_labels([span3.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct ArrayTypeConfig {
-    // The array type expected for mutable cases.
+    /// The style of the mutable array types, `array` by default.
     default: ArrayOption,
-    // The array type expected for readonly cases. If omitted, the value for `default` will be used.
+    /// The style of the readonly array types, the style of `default` by default.
     readonly: Option<ArrayOption>,
 }
 
@@ -59,7 +62,9 @@ impl std::ops::Deref for ArrayType {
         &self.0
     }
 }
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+#[schemars(rename = "ArrayTypeOption")]
 pub enum ArrayOption {
     #[default]
     Array,
@@ -69,32 +74,15 @@ pub enum ArrayOption {
 
 impl Rule for ArrayType {
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self(Box::new(ArrayTypeConfig {
-            default: value
-                .get(0)
-                .and_then(|v| v.get("default"))
-                .and_then(serde_json::Value::as_str)
-                .map_or_else(
-                    || ArrayOption::Array,
-                    |s| match s {
-                        "array" => ArrayOption::Array,
-                        "generic" => ArrayOption::Generic,
-                        _ => ArrayOption::ArraySimple,
-                    },
-                ),
-            readonly: value
-                .get(0)
-                .and_then(|v| v.get("readonly"))
-                .and_then(serde_json::Value::as_str)
-                .map_or_else(
-                    || None,
-                    |s| match s {
-                        "array" => Some(ArrayOption::Array),
-                        "generic" => Some(ArrayOption::Generic),
-                        _ => Some(ArrayOption::ArraySimple),
-                    },
-                ),
-        }))
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<ArrayTypeConfig>()])
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        ctx.source_type().is_typescript()
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
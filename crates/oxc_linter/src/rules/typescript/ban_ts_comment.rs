@@ -3,8 +3,10 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use regex::Regex;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, utils::rule_option};
 
 fn comment(x0: &str, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("typescript-eslint(ban-ts-comment): Do not use @ts-{x0} because it alters compilation errors.")).with_labels([span1.into()])
@@ -66,29 +68,60 @@ pub enum DirectiveConfig {
     DescriptionFormat(Option<Regex>),
 }
 
-impl DirectiveConfig {
-    fn from_json(value: &serde_json::Value) -> Option<Self> {
-        match value {
-            serde_json::Value::Bool(b) => Some(Self::Boolean(*b)),
-            serde_json::Value::String(s) => {
-                if s == "allow-with-description" {
-                    Some(Self::RequireDescription)
-                } else {
-                    None
-                }
-            }
-            serde_json::Value::Object(o) => {
-                let re = o
-                    .get("descriptionFormat")
-                    .and_then(serde_json::Value::as_str)
-                    .and_then(|pattern| Regex::new(pattern).ok());
-                Some(Self::DescriptionFormat(re))
-            }
-            _ => None,
+impl From<BanTsCommentDirective> for DirectiveConfig {
+    fn from(directive: BanTsCommentDirective) -> Self {
+        match directive {
+            BanTsCommentDirective::Banned(banned) => Self::Boolean(banned),
+            BanTsCommentDirective::AllowWithDescription(_) => Self::RequireDescription,
+            BanTsCommentDirective::DescriptionFormat(format) => Self::DescriptionFormat(
+                format.description_format.and_then(|pattern| Regex::new(&pattern).ok()),
+            ),
         }
     }
 }
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct BanTsCommentOptions {
+    /// How `@ts-expect-error` is reported, `allow-with-description` by default.
+    #[serde(rename = "ts-expect-error")]
+    ts_expect_error: Option<BanTsCommentDirective>,
+    /// How `@ts-ignore` is reported, `true` by default.
+    #[serde(rename = "ts-ignore")]
+    ts_ignore: Option<BanTsCommentDirective>,
+    /// How `@ts-nocheck` is reported, `true` by default.
+    #[serde(rename = "ts-nocheck")]
+    ts_nocheck: Option<BanTsCommentDirective>,
+    /// How `@ts-check` is reported, `false` by default.
+    #[serde(rename = "ts-check")]
+    ts_check: Option<BanTsCommentDirective>,
+    /// The minimum length of the descriptions, `3` by default.
+    minimum_description_length: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum BanTsCommentDirective {
+    /// Whether the directive is banned.
+    Banned(bool),
+    /// Allow the directive when followed by a description.
+    AllowWithDescription(BanTsCommentAllowWithDescription),
+    DescriptionFormat(BanTsCommentDescriptionFormat),
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+enum BanTsCommentAllowWithDescription {
+    AllowWithDescription,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct BanTsCommentDescriptionFormat {
+    /// Allow the directive when followed by a description matching this regular expression.
+    description_format: Option<String>,
+}
+
 declare_oxc_lint!(
     /// ### What it does
     /// This rule lets you set which directive comments you want to allow in your codebase.
@@ -105,40 +138,34 @@ declare_oxc_lint!(
     /// }
     /// ```
     BanTsComment,
-    pedantic
+    pedantic,
+    ast_types = []
 );
 
 impl Rule for BanTsComment {
     fn from_configuration(value: serde_json::Value) -> Self {
+        let BanTsCommentOptions {
+            ts_expect_error,
+            ts_ignore,
+            ts_nocheck,
+            ts_check,
+            minimum_description_length,
+        } = rule_option(&value, 0);
+        let default = BanTsCommentConfig::default();
         Self(Box::new(BanTsCommentConfig {
-            ts_expect_error: value
-                .get(0)
-                .and_then(|x| x.get("ts-expect-error"))
-                .and_then(DirectiveConfig::from_json)
-                .unwrap_or(DirectiveConfig::RequireDescription),
-            ts_ignore: value
-                .get(0)
-                .and_then(|x| x.get("ts-ignore"))
-                .and_then(DirectiveConfig::from_json)
-                .unwrap_or(DirectiveConfig::Boolean(true)),
-            ts_nocheck: value
-                .get(0)
-                .and_then(|x| x.get("ts-nocheck"))
-                .and_then(DirectiveConfig::from_json)
-                .unwrap_or(DirectiveConfig::Boolean(true)),
-            ts_check: value
-                .get(0)
-                .and_then(|x| x.get("ts-check"))
-                .and_then(DirectiveConfig::from_json)
-                .unwrap_or(DirectiveConfig::Boolean(false)),
-            minimum_description_length: value
-                .get(0)
-                .and_then(|x| x.get("minimumDescriptionLength"))
-                .and_then(serde_json::Value::as_u64)
-                .unwrap_or(3),
+            ts_expect_error: ts_expect_error.map_or(default.ts_expect_error, DirectiveConfig::from),
+            ts_ignore: ts_ignore.map_or(default.ts_ignore, DirectiveConfig::from),
+            ts_nocheck: ts_nocheck.map_or(default.ts_nocheck, DirectiveConfig::from),
+            ts_check: ts_check.map_or(default.ts_check, DirectiveConfig::from),
+            minimum_description_length: minimum_description_length
+                .unwrap_or(default.minimum_description_length),
         }))
     }
 
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<BanTsCommentOptions>()])
+    }
+
     fn run_once(&self, ctx: &LintContext) {
         let comments = ctx.semantic().trivias().comments();
         for (kind, span) in comments {
@@ -30,7 +30,8 @@ declare_oxc_lint!(
     /// someCode();
     /// ```
     BanTslintComment,
-    style
+    style,
+    ast_types = []
 );
 
 impl Rule for BanTslintComment {
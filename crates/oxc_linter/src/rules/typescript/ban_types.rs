@@ -50,7 +50,8 @@ declare_oxc_lint!(
     /// let bar: Boolean = true;
     /// ```
     BanTypes,
-    pedantic
+    pedantic,
+    ast_types = [TSTypeReference, TSTypeLiteral]
 );
 
 impl Rule for BanTypes {
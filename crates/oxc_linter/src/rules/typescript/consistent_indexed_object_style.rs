@@ -5,8 +5,10 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
 
 fn consistent_indexed_object_style_diagnostic(a: &str, b: &str, span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!(
@@ -21,7 +23,9 @@ pub struct ConsistentIndexedObjectStyle {
     is_record_mode: bool,
 }
 
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+#[schemars(rename = "ConsistentIndexedObjectStyleOption")]
 enum ConsistentIndexedObjectStyleConfig {
     #[default]
     Record,
@@ -60,16 +64,18 @@ declare_oxc_lint!(
 
 impl Rule for ConsistentIndexedObjectStyle {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let config = value.get(0).and_then(serde_json::Value::as_str).map_or_else(
-            ConsistentIndexedObjectStyleConfig::default,
-            |value| match value {
-                "record" => ConsistentIndexedObjectStyleConfig::Record,
-                _ => ConsistentIndexedObjectStyleConfig::IndexSignature,
-            },
-        );
+        let config: ConsistentIndexedObjectStyleConfig = rule_option(&value, 0);
         Self { is_record_mode: config == ConsistentIndexedObjectStyleConfig::Record }
     }
 
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<ConsistentIndexedObjectStyleConfig>()])
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        ctx.source_type().is_typescript()
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if self.is_record_mode {
             match node.kind() {
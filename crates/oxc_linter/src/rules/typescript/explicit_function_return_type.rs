@@ -11,6 +11,8 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use oxc_syntax::operator::UnaryOperator;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     ast_util::outermost_paren_parent,
@@ -19,24 +21,50 @@ use crate::{
     rules::eslint::array_callback_return::return_checker::{
         check_statement, StatementReturnStatus,
     },
+    utils::rule_option,
     AstNode,
 };
 
 #[derive(Debug, Default, Clone)]
 pub struct ExplicitFunctionReturnType(Box<ExplicitFunctionReturnTypeConfig>);
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct ExplicitFunctionReturnTypeConfig {
+    /// Allow the function expressions which are not declarations, e.g. callbacks.
     allow_expressions: bool,
+    /// Allow the function expressions whose type is known, e.g. typed variables, `true` by default.
     allow_typed_function_expressions: bool,
+    /// Allow the arrow functions returning an `as const` assertion, `true` by default.
     allow_direct_const_assertion_in_arrow_functions: bool,
+    /// Allow the concise arrow functions starting with `void`.
     allow_concise_arrow_function_expressions_starting_with_void: bool,
+    /// Allow the functions without type parameters.
     allow_functions_without_type_parameters: bool,
+    /// Names of the functions which are never reported.
     allowed_names: HashSet<String>,
+    /// Allow the functions immediately returning another function, `true` by default.
     allow_higher_order_functions: bool,
+    /// Allow the immediately invoked function expressions.
+    #[serde(rename = "allowIIFEs")]
     allow_iifes: bool,
 }
 
+impl Default for ExplicitFunctionReturnTypeConfig {
+    fn default() -> Self {
+        Self {
+            allow_expressions: false,
+            allow_typed_function_expressions: true,
+            allow_direct_const_assertion_in_arrow_functions: true,
+            allow_concise_arrow_function_expressions_starting_with_void: false,
+            allow_functions_without_type_parameters: false,
+            allowed_names: HashSet::new(),
+            allow_higher_order_functions: true,
+            allow_iifes: false,
+        }
+    }
+}
+
 impl std::ops::Deref for ExplicitFunctionReturnType {
     type Target = ExplicitFunctionReturnTypeConfig;
 
@@ -58,6 +86,7 @@ declare_oxc_lint!(
     /// ```
     ExplicitFunctionReturnType,
     restriction,
+    ast_types = [Function, ArrowFunctionExpression]
 );
 
 fn explicit_function_return_type_diagnostic(span0: Span) -> OxcDiagnostic {
@@ -70,47 +99,15 @@ fn explicit_function_return_type_diagnostic(span0: Span) -> OxcDiagnostic {
 
 impl Rule for ExplicitFunctionReturnType {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let options: Option<&serde_json::Value> = value.get(0);
-        Self(Box::new(ExplicitFunctionReturnTypeConfig {
-            allow_expressions: options
-                .and_then(|x| x.get("allowExpressions"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-            allow_typed_function_expressions: options
-                .and_then(|x| x.get("allowTypedFunctionExpressions"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(true),
-            allow_direct_const_assertion_in_arrow_functions: options
-                .and_then(|x| x.get("allowDirectConstAssertionInArrowFunctions"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(true),
-            allow_concise_arrow_function_expressions_starting_with_void: options
-                .and_then(|x| x.get("allowConciseArrowFunctionExpressionsStartingWithVoid"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-            allow_functions_without_type_parameters: options
-                .and_then(|x| x.get("allowFunctionsWithoutTypeParameters"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-            allowed_names: options
-                .and_then(|x| x.get("allowedNames"))
-                .and_then(serde_json::Value::as_array)
-                .map(|v| {
-                    v.iter()
-                        .filter_map(serde_json::Value::as_str)
-                        .map(ToString::to_string)
-                        .collect()
-                })
-                .unwrap_or_default(),
-            allow_higher_order_functions: options
-                .and_then(|x| x.get("allowHigherOrderFunctions"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(true),
-            allow_iifes: options
-                .and_then(|x| x.get("allowIIFEs"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-        }))
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<ExplicitFunctionReturnTypeConfig>()])
+    }
+
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        ctx.source_type().is_typescript()
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
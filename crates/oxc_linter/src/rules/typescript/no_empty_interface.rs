@@ -2,9 +2,11 @@ use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 use serde_json::Value;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
 
 fn no_empty_interface_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(
@@ -17,8 +19,10 @@ fn no_empty_interface_extend_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("typescript-eslint(no-empty-interface): an interface declaring no members is equivalent to its supertype").with_labels([span0.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default)]
 pub struct NoEmptyInterface {
+    /// Allow the empty interfaces extending a single interface.
     allow_single_extends: bool,
 }
 
@@ -39,17 +43,17 @@ declare_oxc_lint!(
     /// interface Bar extends Foo {}
     /// ```
     NoEmptyInterface,
-    style
+    style,
+    ast_types = [TSInterfaceDeclaration]
 );
 
 impl Rule for NoEmptyInterface {
     fn from_configuration(value: Value) -> Self {
-        let allow_single_extends =
-            value.get(0).map_or(Self::default().allow_single_extends, |config| {
-                config.get("allow_single_extends").and_then(Value::as_bool).unwrap_or_default()
-            });
+        rule_option(&value, 0)
+    }
 
-        Self { allow_single_extends }
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<Self>()])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -31,6 +31,10 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoExtraNonNullAssertion {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        ctx.source_type().is_typescript()
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let expr = match node.kind() {
             AstKind::TSNonNullExpression(expr) => {
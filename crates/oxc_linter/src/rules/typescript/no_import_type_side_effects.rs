@@ -52,9 +52,14 @@ declare_oxc_lint!(
     /// ```
     NoImportTypeSideEffects,
     restriction,
+    ast_types = [ImportDeclaration]
 );
 
 impl Rule for NoImportTypeSideEffects {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        ctx.source_type().is_typescript()
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ImportDeclaration(import_decl) = node.kind() else {
             return;
@@ -51,10 +51,15 @@ declare_oxc_lint!(
     // }
     /// ```
     NoMisusedNew,
-    correctness
+    correctness,
+    ast_types = [TSInterfaceDeclaration, TSMethodSignature, Class]
 );
 
 impl Rule for NoMisusedNew {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        ctx.source_type().is_typescript()
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::TSInterfaceDeclaration(interface_decl) => {
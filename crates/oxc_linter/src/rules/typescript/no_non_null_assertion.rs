@@ -23,6 +23,7 @@ declare_oxc_lint!(
     /// ```
     NoNonNullAssertion,
     restriction,
+    ast_types = [TSNonNullExpression]
 );
 
 fn no_non_null_assertion_diagnostic(span0: Span) -> OxcDiagnostic {
@@ -5,8 +5,10 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{CompactStr, GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
 
 fn no_this_alias_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("typescript-eslint(no-this-alias): Unexpected aliasing of 'this' to local variable.")
@@ -43,6 +45,15 @@ impl Default for NoThisAliasConfig {
     }
 }
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+struct NoThisAliasOptions {
+    /// Allow destructuring `this`, e.g. `const { props } = this`, `true` by default.
+    allow_destructuring: Option<bool>,
+    /// Names of the variables which may be assigned `this`.
+    #[serde(default)]
+    allow_names: Vec<String>,
+}
+
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -63,27 +74,18 @@ declare_oxc_lint!(
 
 impl Rule for NoThisAlias {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let obj = value.get(0);
-        let allowed_names = value
-            .get(0)
-            .and_then(|v| v.get("allow_names"))
-            .and_then(serde_json::Value::as_array)
-            .unwrap_or(&vec![])
-            .iter()
-            .map(serde_json::Value::as_str)
-            .filter(std::option::Option::is_some)
-            .map(|x| CompactStr::from(x.unwrap()))
-            .collect::<Vec<CompactStr>>();
-
+        let NoThisAliasOptions { allow_destructuring, allow_names } = rule_option(&value, 0);
         Self(Box::new(NoThisAliasConfig {
-            allow_destructuring: obj
-                .and_then(|v| v.get("allow_destructuring"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or_default(),
-            allow_names: allowed_names,
+            allow_destructuring: allow_destructuring
+                .unwrap_or(NoThisAliasConfig::default().allow_destructuring),
+            allow_names: allow_names.into_iter().map(CompactStr::from).collect(),
         }))
     }
 
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<NoThisAliasOptions>()])
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if !ctx.source_type().is_typescript() {
             return;
@@ -35,7 +35,8 @@ declare_oxc_lint!(
     /// ```
     ///
     NoUselessEmptyExport,
-    correctness
+    correctness,
+    ast_types = [ExportNamedDeclaration]
 );
 
 impl Rule for NoUselessEmptyExport {
@@ -30,7 +30,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     PreferEnumInitializers,
-    pedantic
+    pedantic,
+    ast_types = [TSEnumDeclaration]
 );
 
 impl Rule for PreferEnumInitializers {
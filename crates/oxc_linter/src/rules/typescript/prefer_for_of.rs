@@ -43,7 +43,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     PreferForOf,
-    style
+    style,
+    ast_types = [ForStatement]
 );
 
 trait SpanExt {
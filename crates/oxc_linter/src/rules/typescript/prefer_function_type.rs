@@ -74,7 +74,13 @@ declare_oxc_lint!(
     /// type Intersection = ((data: string) => number) & ((id: number) => string);
     /// ```
     PreferFunctionType,
-    style
+    style,
+    ast_types = [
+        TSInterfaceDeclaration,
+        ExportDefaultDeclaration,
+        TSTypeAnnotation,
+        TSTypeAliasDeclaration,
+    ]
 );
 
 fn has_one_super_type(decl: &TSInterfaceDeclaration) -> bool {
@@ -311,6 +317,10 @@ fn check_member(member: &TSSignature, node: &AstNode<'_>, ctx: &LintContext<'_>)
 }
 
 impl Rule for PreferFunctionType {
+    fn should_run(&self, ctx: &LintContext) -> bool {
+        ctx.source_type().is_typescript()
+    }
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::TSInterfaceDeclaration(decl) => {
@@ -3,8 +3,10 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
 
 fn prefer_literal_enum_member_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("typescript-eslint(prefer-literal-enum-member): Explicit enum value must only be a literal value (string, number, boolean, etc).")
@@ -12,8 +14,10 @@ fn prefer_literal_enum_member_diagnostic(span0: Span) -> OxcDiagnostic {
         .with_labels([span0.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct PreferLiteralEnumMember {
+    /// Allow the bitwise expressions of literals, e.g. `1 << 2`.
     allow_bitwise_expressions: bool,
 }
 
@@ -37,19 +41,17 @@ declare_oxc_lint!(
     /// }
     /// ```
     PreferLiteralEnumMember,
-    restriction
+    restriction,
+    ast_types = [TSEnumMember]
 );
 
 impl Rule for PreferLiteralEnumMember {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let options: Option<&serde_json::Value> = value.get(0);
+        rule_option(&value, 0)
+    }
 
-        Self {
-            allow_bitwise_expressions: options
-                .and_then(|x| x.get("allowBitwiseExpressions"))
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-        }
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<Self>()])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
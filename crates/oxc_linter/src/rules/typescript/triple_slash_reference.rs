@@ -7,8 +7,10 @@ use oxc_ast::{
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, utils::rule_option};
 
 fn triple_slash_reference_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!("typescript-eslint(triple-slash-reference): Do not use a triple slash reference for {x0}, use `import` style instead."))
@@ -19,25 +21,35 @@ fn triple_slash_reference_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
 #[derive(Debug, Default, Clone)]
 pub struct TripleSlashReference(Box<TripleSlashReferenceConfig>);
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct TripleSlashReferenceConfig {
+    /// Whether `/// <reference lib="..." />` is allowed, `always` by default.
     lib: LibOption,
+    /// Whether `/// <reference path="..." />` is allowed, `never` by default.
     path: PathOption,
+    /// Whether `/// <reference types="..." />` is allowed, `prefer-import` by default.
     types: TypesOption,
 }
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+#[schemars(rename = "TripleSlashReferenceLib")]
 enum LibOption {
     #[default]
     Always,
     Never,
 }
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+#[schemars(rename = "TripleSlashReferencePath")]
 enum PathOption {
     Always,
     #[default]
     Never,
 }
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+#[schemars(rename = "TripleSlashReferenceTypes")]
 enum TypesOption {
     Always,
     Never,
@@ -66,39 +78,17 @@ declare_oxc_lint!(
     /// globalThis.value;
     /// ```
     TripleSlashReference,
-    correctness
+    correctness,
+    ast_types = []
 );
 
 impl Rule for TripleSlashReference {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let options: Option<&serde_json::Value> = value.get(0);
-        Self(Box::new(TripleSlashReferenceConfig {
-            lib: options
-                .and_then(|x| x.get("lib"))
-                .and_then(serde_json::Value::as_str)
-                .map_or_else(LibOption::default, |value| match value {
-                    "always" => LibOption::Always,
-                    "never" => LibOption::Never,
-                    _ => LibOption::default(),
-                }),
-            path: options
-                .and_then(|x| x.get("path"))
-                .and_then(serde_json::Value::as_str)
-                .map_or_else(PathOption::default, |value| match value {
-                    "always" => PathOption::Always,
-                    "never" => PathOption::Never,
-                    _ => PathOption::default(),
-                }),
-            types: options
-                .and_then(|x| x.get("types"))
-                .and_then(serde_json::Value::as_str)
-                .map_or_else(TypesOption::default, |value| match value {
-                    "always" => TypesOption::Always,
-                    "never" => TypesOption::Never,
-                    "prefer-import" => TypesOption::PreferImport,
-                    _ => TypesOption::default(),
-                }),
-        }))
+        Self(Box::new(rule_option(&value, 0)))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<TripleSlashReferenceConfig>()])
     }
 
     fn run_once(&self, ctx: &LintContext) {
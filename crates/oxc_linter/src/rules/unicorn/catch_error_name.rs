@@ -6,8 +6,10 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::SymbolId;
 use oxc_span::{CompactStr, Span};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
 
 fn catch_error_name_diagnostic(x0: &str, x1: &str, span2: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!(
@@ -39,6 +41,15 @@ impl Default for CatchErrorNameConfig {
     }
 }
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default)]
+struct CatchErrorNameOptions {
+    /// The expected name of the caught errors, `error` by default.
+    name: Option<String>,
+    /// Names which are allowed besides `name`.
+    ignored: Vec<String>,
+}
+
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -60,26 +71,15 @@ declare_oxc_lint!(
 
 impl Rule for CatchErrorName {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let ignored_names = value
-            .get(0)
-            .and_then(|v| v.get("ignored"))
-            .and_then(serde_json::Value::as_array)
-            .unwrap_or(&vec![])
-            .iter()
-            .map(serde_json::Value::as_str)
-            .filter(std::option::Option::is_some)
-            .map(|x| CompactStr::from(x.unwrap()))
-            .collect::<Vec<CompactStr>>();
-
-        let allowed_name = CompactStr::from(
-            value
-                .get(0)
-                .and_then(|v| v.get("name"))
-                .and_then(serde_json::Value::as_str)
-                .unwrap_or("error"),
-        );
-
-        Self(Box::new(CatchErrorNameConfig { ignore: ignored_names, name: allowed_name }))
+        let CatchErrorNameOptions { name, ignored } = rule_option(&value, 0);
+        Self(Box::new(CatchErrorNameConfig {
+            ignore: ignored.into_iter().map(CompactStr::from).collect(),
+            name: name.map_or_else(|| CatchErrorNameConfig::default().name, CompactStr::from),
+        }))
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<CatchErrorNameOptions>()])
     }
 
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
@@ -28,7 +28,16 @@ declare_oxc_lint!(
     /// }
     /// ```
     EmptyBraceSpaces,
-    style
+    style,
+    ast_types = [
+        StaticBlock,
+        ObjectExpression,
+        FunctionBody,
+        Class,
+        BlockStatement,
+        CatchClause,
+        FinallyClause,
+    ]
 );
 
 impl Rule for EmptyBraceSpaces {
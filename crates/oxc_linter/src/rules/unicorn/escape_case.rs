@@ -36,7 +36,8 @@ declare_oxc_lint!(
     /// const foo = '\cA';
     /// ```
     EscapeCase,
-    pedantic
+    pedantic,
+    ast_types = [StringLiteral, TemplateLiteral, RegExpLiteral]
 );
 
 fn is_hex_char(c: char) -> bool {
@@ -8,11 +8,13 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::{BinaryOperator, LogicalOperator};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
 use crate::{
     context::LintContext,
     rule::Rule,
-    utils::{get_boolean_ancestor, is_boolean_node},
+    utils::{get_boolean_ancestor, is_boolean_node, rule_option},
     AstNode,
 };
 
@@ -34,22 +36,20 @@ fn zero(span0: Span, x1: &str, x2: &str, x3: Option<String>) -> OxcDiagnostic {
     d.with_labels([span0.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+#[schemars(rename = "ExplicitLengthCheckNonZero")]
 enum NonZero {
     #[default]
     GreaterThan,
     NotEqual,
 }
-impl NonZero {
-    pub fn from(raw: &str) -> Self {
-        match raw {
-            "not-equal" => Self::NotEqual,
-            _ => Self::GreaterThan,
-        }
-    }
-}
-#[derive(Debug, Default, Clone)]
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default)]
 pub struct ExplicitLengthCheck {
+    /// How to check that a length is not zero, `greater-than` by default.
+    #[serde(rename = "non-zero")]
     non_zero: NonZero,
 }
 
@@ -78,7 +78,8 @@ declare_oxc_lint!(
     /// const isEmpty = foo.length === 0;
     /// ```
     ExplicitLengthCheck,
-    pedantic
+    pedantic,
+    ast_types = [MemberExpression]
 );
 fn is_literal(expr: &Expression, value: f64) -> bool {
     matches!(expr, Expression::NumericLiteral(lit) if (lit.value - value).abs() < f64::EPSILON)
@@ -276,14 +277,11 @@ impl Rule for ExplicitLengthCheck {
     }
 
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self {
-            non_zero: value
-                .get(0)
-                .and_then(|v| v.get("non-zero"))
-                .and_then(serde_json::Value::as_str)
-                .map(NonZero::from)
-                .unwrap_or_default(),
-        }
+        rule_option(&value, 0)
+    }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<Self>()])
     }
 }
 
@@ -2,9 +2,10 @@ use convert_case::{Case, Casing};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
-use serde_json::Value;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, utils::rule_option};
 
 fn filename_case_diagnostic(span0: Span, x1: &str) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!(
@@ -35,6 +36,42 @@ impl Default for FilenameCase {
     }
 }
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default)]
+struct FilenameCaseOptions {
+    /// The case which filenames must be in, `kebabCase` by default.
+    case: Option<FilenameCaseKind>,
+    /// The cases which filenames may be in.
+    cases: Option<FilenameCases>,
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+enum FilenameCaseKind {
+    KebabCase,
+    CamelCase,
+    SnakeCase,
+    PascalCase,
+    UnderscoreCase,
+}
+
+#[allow(clippy::struct_field_names)]
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+struct FilenameCases {
+    /// Allow `kebab-case`.
+    kebab_case: Option<bool>,
+    /// Allow `camelCase`.
+    camel_case: Option<bool>,
+    /// Allow `snake_case`.
+    snake_case: Option<bool>,
+    /// Allow `PascalCase`.
+    pascal_case: Option<bool>,
+    /// Allow `_underscore_case`.
+    underscore_case: Option<bool>,
+}
+
 declare_oxc_lint!(
     /// ### What it does
     ///
@@ -45,55 +82,44 @@ declare_oxc_lint!(
     /// ### Example
     /// ```
     FilenameCase,
-    style
+    style,
+    ast_types = []
 );
 
 impl Rule for FilenameCase {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let Some(case_type) = value.get(0) else {
-            return Self::default();
-        };
-
-        if let Some(Value::String(s)) = case_type.get("case") {
-            return match s.as_str() {
-                "kebabCase" => Self { kebab_case: true, ..Self::default() },
-                "camelCase" => Self { camel_case: true, ..Self::default() },
-                "snakeCase" => Self { snake_case: true, ..Self::default() },
-                "pascalCase" => Self { pascal_case: true, ..Self::default() },
-                "underscoreCase" => Self { underscore_case: true, ..Self::default() },
-                _ => Self::default(),
+        let FilenameCaseOptions { case, cases } = rule_option(&value, 0);
+
+        if let Some(case) = case {
+            return match case {
+                FilenameCaseKind::KebabCase => Self { kebab_case: true, ..Self::default() },
+                FilenameCaseKind::CamelCase => Self { camel_case: true, ..Self::default() },
+                FilenameCaseKind::SnakeCase => Self { snake_case: true, ..Self::default() },
+                FilenameCaseKind::PascalCase => Self { pascal_case: true, ..Self::default() },
+                FilenameCaseKind::UnderscoreCase => {
+                    Self { underscore_case: true, ..Self::default() }
+                }
             };
         }
 
-        if let Some(Value::String(s)) = case_type.get("case") {
-            return match s.as_str() {
-                "kebabCase" => Self { kebab_case: true, ..Self::default() },
-                "camelCase" => Self { camel_case: true, ..Self::default() },
-                "snakeCase" => Self { snake_case: true, ..Self::default() },
-                "pascalCase" => Self { pascal_case: true, ..Self::default() },
-                "underscoreCase" => Self { underscore_case: true, ..Self::default() },
-                _ => Self::default(),
+        if let Some(cases) = cases {
+            let default = Self::default();
+            return Self {
+                kebab_case: cases.kebab_case.unwrap_or(default.kebab_case),
+                camel_case: cases.camel_case.unwrap_or(default.camel_case),
+                snake_case: cases.snake_case.unwrap_or(default.snake_case),
+                pascal_case: cases.pascal_case.unwrap_or(default.pascal_case),
+                underscore_case: cases.underscore_case.unwrap_or(default.underscore_case),
             };
         }
 
-        if let Some(Value::Object(map)) = case_type.get("cases") {
-            let mut filename_case = Self::default();
-            for (key, value) in map {
-                match (key.as_str(), value) {
-                    ("kebabCase", Value::Bool(b)) => filename_case.kebab_case = *b,
-                    ("camelCase", Value::Bool(b)) => filename_case.camel_case = *b,
-                    ("snakeCase", Value::Bool(b)) => filename_case.snake_case = *b,
-                    ("pascalCase", Value::Bool(b)) => filename_case.pascal_case = *b,
-                    ("underscoreCase", Value::Bool(b)) => filename_case.underscore_case = *b,
-                    _ => (),
-                }
-            }
-            return filename_case;
-        }
-
         Self::default()
     }
 
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<FilenameCaseOptions>()])
+    }
+
     fn run_once<'a>(&self, ctx: &LintContext<'_>) {
         let Some(filename) = ctx.file_path().file_stem().and_then(|s| s.to_str()) else {
             return;
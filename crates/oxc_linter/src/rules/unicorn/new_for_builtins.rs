@@ -52,7 +52,8 @@ declare_oxc_lint!(
     /// const bar = new Array(1, 2, 3);
     /// ```
     NewForBuiltins,
-    pedantic
+    pedantic,
+    ast_types = [NewExpression, CallExpression]
 );
 
 impl Rule for NewForBuiltins {
@@ -45,6 +45,7 @@ declare_oxc_lint!(
     /// ```
     NoArrayForEach,
     restriction,
+    ast_types = [CallExpression]
 );
 
 impl Rule for NoArrayForEach {
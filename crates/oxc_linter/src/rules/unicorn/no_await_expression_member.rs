@@ -33,7 +33,8 @@ declare_oxc_lint!(
     /// const [, secondElement] = await getArray();
     /// ```
     NoAwaitExpressionMember,
-    style
+    style,
+    ast_types = [MemberExpression]
 );
 
 impl Rule for NoAwaitExpressionMember {
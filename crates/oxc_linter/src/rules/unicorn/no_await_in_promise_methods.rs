@@ -42,7 +42,8 @@ declare_oxc_lint!(
     /// Promise.race([promise, anotherPromise]);
     /// ```
     NoAwaitInPromiseMethods,
-    correctness
+    correctness,
+    ast_types = [CallExpression]
 );
 
 impl Rule for NoAwaitInPromiseMethods {
@@ -41,7 +41,8 @@ declare_oxc_lint!(
     ///
     /// ```
     NoConsoleSpaces,
-    style
+    style,
+    ast_types = [CallExpression]
 );
 
 impl Rule for NoConsoleSpaces {
@@ -50,7 +50,8 @@ declare_oxc_lint!(
     /// });
     /// ```
     NoDocumentCookie,
-    correctness
+    correctness,
+    ast_types = [AssignmentExpression]
 );
 
 impl Rule for NoDocumentCookie {
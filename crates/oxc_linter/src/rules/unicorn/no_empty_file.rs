@@ -34,6 +34,7 @@ declare_oxc_lint!(
     ///
     NoEmptyFile,
     correctness,
+    ast_types = []
 );
 
 impl Rule for NoEmptyFile {
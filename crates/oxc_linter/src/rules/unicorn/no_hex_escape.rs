@@ -34,7 +34,8 @@ declare_oxc_lint!(
     /// const foo = `\u001B${bar}`;
     /// ```
     NoHexEscape,
-    pedantic
+    pedantic,
+    ast_types = [StringLiteral, TemplateLiteral, RegExpLiteral]
 );
 
 // \x -> \u00
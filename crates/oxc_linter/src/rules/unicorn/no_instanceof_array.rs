@@ -28,7 +28,8 @@ declare_oxc_lint!(
     /// [1,2,3] instanceof Array;
     /// ```
     NoInstanceofArray,
-    pedantic
+    pedantic,
+    ast_types = [BinaryExpression]
 );
 
 impl Rule for NoInstanceofArray {
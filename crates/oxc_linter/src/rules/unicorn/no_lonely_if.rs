@@ -38,7 +38,8 @@ declare_oxc_lint!(
     /// if (foo && bar) baz();
     /// ```
     NoLonelyIf,
-    pedantic
+    pedantic,
+    ast_types = [IfStatement]
 );
 
 impl Rule for NoLonelyIf {
@@ -41,7 +41,8 @@ declare_oxc_lint!(
     /// const foo = i > 5 ? (i < 100 ? true : false) : (i < 100 ? true : false);
     /// ```
     NoNestedTernary,
-    restriction
+    restriction,
+    ast_types = [ConditionalExpression]
 );
 
 impl Rule for NoNestedTernary {
@@ -37,7 +37,8 @@ declare_oxc_lint!(
     /// function foo({a = false} = {}) {}
     /// ```
     NoObjectAsDefaultParameter,
-    pedantic
+    pedantic,
+    ast_types = [AssignmentPattern]
 );
 
 impl Rule for NoObjectAsDefaultParameter {
@@ -34,6 +34,7 @@ declare_oxc_lint!(
     /// ```
     NoProcessExit,
     restriction,
+    ast_types = [CallExpression]
 );
 
 impl Rule for NoProcessExit {
@@ -47,7 +47,8 @@ declare_oxc_lint!(
     /// ```
     ///
     NoSinglePromiseInPromiseMethods,
-    correctness
+    correctness,
+    ast_types = [CallExpression]
 );
 
 impl Rule for NoSinglePromiseInPromiseMethods {
@@ -3,15 +3,22 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{ast_util::get_declaration_of_variable, context::LintContext, rule::Rule, AstNode};
+use crate::{
+    ast_util::get_declaration_of_variable, context::LintContext, rule::Rule, utils::rule_option,
+    AstNode,
+};
 
 fn no_typeof_undefined_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint-plugin-unicorn(no-typeof-undefined): Compare with `undefined` directly instead of using `typeof`.").with_labels([span0.into()])
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
 pub struct NoTypeofUndefined {
+    /// Also report the `typeof` checks of global variables.
     check_global_variables: bool,
 }
 
@@ -34,7 +41,8 @@ declare_oxc_lint!(
     /// foo === undefined;
     /// ```
     NoTypeofUndefined,
-    pedantic
+    pedantic,
+    ast_types = [BinaryExpression]
 );
 
 impl Rule for NoTypeofUndefined {
@@ -73,10 +81,11 @@ impl Rule for NoTypeofUndefined {
     }
 
     fn from_configuration(value: serde_json::Value) -> Self {
-        let check_global_variables =
-            value.get("checkGlobalVariables").and_then(serde_json::Value::as_bool).unwrap_or(false);
+        rule_option(&value, 0)
+    }
 
-        Self { check_global_variables }
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<Self>()])
     }
 }
 
@@ -129,11 +138,11 @@ fn test() {
         (r#"typeof foo.bar === "undefined""#, None),
         (
             r#"let foo; typeof foo === "undefined""#,
-            Some(serde_json::json!({ "checkGlobalVariables": false })),
+            Some(serde_json::json!([{ "checkGlobalVariables": false }])),
         ),
         (
             r#"typeof foo === "undefined""#,
-            Some(serde_json::json!({ "checkGlobalVariables": true })),
+            Some(serde_json::json!([{ "checkGlobalVariables": true }])),
         ),
     ];
 
@@ -28,7 +28,8 @@ declare_oxc_lint!(
     /// await await promise;
     /// ```
     NoUnnecessaryAwait,
-    correctness
+    correctness,
+    ast_types = [AwaitExpression]
 );
 
 impl Rule for NoUnnecessaryAwait {
@@ -35,7 +35,8 @@ declare_oxc_lint!(
     ///
     /// ```
     NoUselessFallbackInSpread,
-    correctness
+    correctness,
+    ast_types = [LogicalExpression]
 );
 
 impl Rule for NoUselessFallbackInSpread {
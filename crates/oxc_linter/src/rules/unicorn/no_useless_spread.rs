@@ -362,7 +362,8 @@ fn check_useless_array_clone<'a>(array_expr: &ArrayExpression<'a>, ctx: &LintCon
             },
         );
 
-        ctx.diagnostic_with_fix(clone_array(span, &method), |fixer| {
+        // The result of these methods may not be an array, e.g. for a custom `map` method.
+        ctx.diagnostic_with_suggestion(clone_array(span, &method), |fixer| {
             fix_by_removing_spread(fixer, array_expr, spread_elem)
         });
     }
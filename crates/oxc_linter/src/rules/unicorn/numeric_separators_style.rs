@@ -7,8 +7,10 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use regex::Regex;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::Deserialize;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::rule_option, AstNode};
 
 fn numeric_separators_style_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(
@@ -50,6 +52,30 @@ impl Default for NumericSeparatorsStyleConfig {
     }
 }
 
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+struct NumericSeparatorsStyleOptions {
+    /// The grouping of binary literals, by `4` from `0` digits by default.
+    binary: Option<NumericSeparatorsStyleBase>,
+    /// The grouping of hexadecimal literals, by `2` from `0` digits by default.
+    hexadecimal: Option<NumericSeparatorsStyleBase>,
+    /// The grouping of decimal literals, by `3` from `5` digits by default.
+    number: Option<NumericSeparatorsStyleBase>,
+    /// The grouping of octal literals, by `4` from `0` digits by default.
+    octal: Option<NumericSeparatorsStyleBase>,
+    /// Only check the literals which already contain a separator.
+    only_if_contains_separator: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase")]
+struct NumericSeparatorsStyleBase {
+    /// The number of digits between separators.
+    group_length: Option<u32>,
+    /// The minimum number of digits of the literals which are checked.
+    minimum_digits: Option<u32>,
+}
+
 declare_oxc_lint!(
     /// ### What it does
     /// Enforces a convention of grouping digits using numeric separators.
@@ -81,7 +107,8 @@ declare_oxc_lint!(
     /// ];
     /// ```
     NumericSeparatorsStyle,
-    style
+    style,
+    ast_types = [NumericLiteral, BigintLiteral]
 );
 
 impl Rule for NumericSeparatorsStyle {
@@ -122,31 +149,31 @@ impl Rule for NumericSeparatorsStyle {
     }
 
     fn from_configuration(value: serde_json::Value) -> Self {
+        let options: NumericSeparatorsStyleOptions = rule_option(&value, 0);
         let mut cfg = NumericSeparatorsStyleConfig::default();
 
-        if let Some(config) = value.get(0) {
-            if let Some(config) = config.get("binary") {
-                cfg.binary.set_numeric_base_from_config(config);
-            }
-            if let Some(config) = config.get("hexadecimal") {
-                cfg.hexadecimal.set_numeric_base_from_config(config);
-            }
-            if let Some(config) = config.get("number") {
-                cfg.number.set_numeric_base_from_config(config);
-            }
-            if let Some(config) = config.get("octal") {
-                cfg.octal.set_numeric_base_from_config(config);
-            }
-
-            if let Some(val) =
-                config.get("onlyIfContainsSeparator").and_then(serde_json::Value::as_bool)
-            {
-                cfg.only_if_contains_separator = val;
-            }
+        if let Some(base) = &options.binary {
+            cfg.binary.set_numeric_base_from_config(base);
+        }
+        if let Some(base) = &options.hexadecimal {
+            cfg.hexadecimal.set_numeric_base_from_config(base);
+        }
+        if let Some(base) = &options.number {
+            cfg.number.set_numeric_base_from_config(base);
+        }
+        if let Some(base) = &options.octal {
+            cfg.octal.set_numeric_base_from_config(base);
+        }
+        if let Some(only_if_contains_separator) = options.only_if_contains_separator {
+            cfg.only_if_contains_separator = only_if_contains_separator;
         }
 
         Self(Box::new(cfg))
     }
+
+    fn options_schema(gen: &mut SchemaGenerator) -> Option<Vec<Schema>> {
+        Some(vec![gen.subschema_for::<NumericSeparatorsStyleOptions>()])
+    }
 }
 
 impl NumericSeparatorsStyle {
@@ -267,12 +294,12 @@ struct NumericBaseConfig {
     minimum_digits: usize,
 }
 impl NumericBaseConfig {
-    pub(self) fn set_numeric_base_from_config(&mut self, val: &serde_json::Value) {
-        if let Some(group_length) = val.get("groupLength").and_then(serde_json::Value::as_u64) {
-            self.group_length = usize::try_from(group_length).unwrap();
+    pub(self) fn set_numeric_base_from_config(&mut self, base: &NumericSeparatorsStyleBase) {
+        if let Some(group_length) = base.group_length {
+            self.group_length = group_length as usize;
         }
-        if let Some(minimum_digits) = val.get("minimumDigits").and_then(serde_json::Value::as_u64) {
-            self.minimum_digits = usize::try_from(minimum_digits).unwrap();
+        if let Some(minimum_digits) = base.minimum_digits {
+            self.minimum_digits = minimum_digits as usize;
         }
     }
 }
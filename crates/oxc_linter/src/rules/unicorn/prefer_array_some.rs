@@ -44,7 +44,8 @@ declare_oxc_lint!(
     /// const foo = array.some(fn) ? bar : baz;
     /// ```
     PreferArraySome,
-    pedantic
+    pedantic,
+    ast_types = [CallExpression, BinaryExpression]
 );
 
 impl Rule for PreferArraySome {
@@ -39,7 +39,8 @@ declare_oxc_lint!(
     /// const arrayBuffer = await blob.arrayBuffer();
     /// ```
     PreferBlobReadingMethods,
-    pedantic
+    pedantic,
+    ast_types = [CallExpression]
 );
 
 impl Rule for PreferBlobReadingMethods {
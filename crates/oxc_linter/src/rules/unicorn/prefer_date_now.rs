@@ -56,7 +56,8 @@ declare_oxc_lint!(
     /// const ts = Date.now();
     /// ```
     PreferDateNow,
-    pedantic
+    pedantic,
+    ast_types = [CallExpression, UnaryExpression, AssignmentExpression, BinaryExpression]
 );
 
 impl Rule for PreferDateNow {
@@ -36,7 +36,8 @@ declare_oxc_lint!(
     /// class Foo extends OtherClass {}
     /// ```
     PreferEventTarget,
-    pedantic
+    pedantic,
+    ast_types = [IdentifierReference]
 );
 
 impl Rule for PreferEventTarget {
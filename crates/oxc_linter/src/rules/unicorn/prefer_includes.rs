@@ -38,7 +38,8 @@ declare_oxc_lint!(
     /// if (str.includes('foo')) { }
     /// ```
     PreferIncludes,
-    style
+    style,
+    ast_types = [BinaryExpression]
 );
 
 impl Rule for PreferIncludes {
@@ -56,7 +56,8 @@ declare_oxc_lint!(
     /// ("parentNode.replaceChild(newChildNode, oldChildNode);", None),
     /// ```
     PreferModernDomApis,
-    style
+    style,
+    ast_types = [CallExpression]
 );
 
 impl Rule for PreferModernDomApis {
@@ -46,6 +46,7 @@ declare_oxc_lint!(
     /// ```
     PreferNumberProperties,
     restriction,
+    ast_types = [MemberExpression, IdentifierReference, CallExpression]
 );
 
 impl Rule for PreferNumberProperties {
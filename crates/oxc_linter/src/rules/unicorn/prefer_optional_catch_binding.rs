@@ -37,7 +37,8 @@ declare_oxc_lint!(
     /// } catch { }
     /// ```
     PreferOptionalCatchBinding,
-    style
+    style,
+    ast_types = [CatchParameter]
 );
 
 impl Rule for PreferOptionalCatchBinding {
@@ -38,7 +38,8 @@ declare_oxc_lint!(
     /// Reflect.apply(foo, null);
     /// ```
     PreferReflectApply,
-    style
+    style,
+    ast_types = [CallExpression]
 );
 
 fn is_apply_signature(first_arg: &Argument, second_arg: &Argument) -> bool {
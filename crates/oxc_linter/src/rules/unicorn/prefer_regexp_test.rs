@@ -39,7 +39,8 @@ declare_oxc_lint!(
     ///
     /// ```
     PreferRegexpTest,
-    pedantic
+    pedantic,
+    ast_types = [CallExpression]
 );
 
 impl Rule for PreferRegexpTest {
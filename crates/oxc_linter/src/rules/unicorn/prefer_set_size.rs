@@ -34,7 +34,8 @@ declare_oxc_lint!(
     ///
     /// ```
     PreferSetSize,
-    correctness
+    correctness,
+    ast_types = [MemberExpression]
 );
 
 impl Rule for PreferSetSize {
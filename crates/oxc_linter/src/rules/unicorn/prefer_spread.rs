@@ -39,7 +39,8 @@ declare_oxc_lint!(
     ///
     /// ```
     PreferSpread,
-    style
+    style,
+    ast_types = [CallExpression]
 );
 
 impl Rule for PreferSpread {
@@ -33,7 +33,8 @@ declare_oxc_lint!(
     /// ```javascript
     /// ```
     PreferStringReplaceAll,
-    pedantic
+    pedantic,
+    ast_types = [CallExpression]
 );
 
 impl Rule for PreferStringReplaceAll {
@@ -64,11 +65,28 @@ impl Rule for PreferStringReplaceAll {
         match method_name_str {
             "replaceAll" => {
                 if let Some(k) = get_pattern_replacement(pattern) {
-                    ctx.diagnostic(string_literal(static_member_expr.property.span, &k));
+                    let diagnostic = string_literal(static_member_expr.property.span, &k);
+                    // Other flags, such as `i`, can not be expressed with a string pattern.
+                    let fix = match pattern {
+                        Argument::RegExpLiteral(reg_exp_literal)
+                            if reg_exp_literal.regex.flags == RegExpFlags::G =>
+                        {
+                            quote_string(&k).map(|string| (reg_exp_literal.span, string))
+                        }
+                        _ => None,
+                    };
+                    if let Some((span, string)) = fix {
+                        ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(span, string));
+                    } else {
+                        ctx.diagnostic(diagnostic);
+                    }
                 }
             }
             "replace" if is_reg_exp_with_global_flag(pattern) => {
-                ctx.diagnostic(use_replace_all(static_member_expr.property.span));
+                ctx.diagnostic_with_fix(
+                    use_replace_all(static_member_expr.property.span),
+                    |fixer| fixer.replace(static_member_expr.property.span, "replaceAll"),
+                );
             }
             _ => {}
         }
@@ -109,6 +127,18 @@ fn get_pattern_replacement<'a>(expr: &'a Argument<'a>) -> Option<CompactStr> {
     Some(reg_exp_literal.regex.pattern.to_compact_str())
 }
 
+/// Quote a pattern accepted by [`is_simple_string`] as a string literal,
+/// `None` if it contains both kinds of quotes.
+fn quote_string(str: &str) -> Option<String> {
+    if !str.contains('"') {
+        Some(format!("\"{str}\""))
+    } else if !str.contains('\'') {
+        Some(format!("'{str}'"))
+    } else {
+        None
+    }
+}
+
 fn is_simple_string(str: &str) -> bool {
     str.chars()
         .all(|c| !matches!(c, '^' | '$' | '+' | '[' | '{' | '(' | '\\' | '.' | '?' | '*' | '|'))
@@ -201,5 +231,16 @@ fn test() {
         r#""Hello world".replaceAll(/world/g, 'world!');"#,
     ];
 
-    Tester::new(PreferStringReplaceAll::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        (r"foo.replace(/a/g, bar)", r"foo.replaceAll(/a/g, bar)"),
+        (r"foo.replace(/a/gi, bar)", r"foo.replaceAll(/a/gi, bar)"),
+        (r"foo.replaceAll(/a/g, bar)", r#"foo.replaceAll("a", bar)"#),
+        (r#"foo.replaceAll(/"/g, bar)"#, r#"foo.replaceAll('"', bar)"#),
+        (
+            r#""Hello world".replaceAll(/world/g, 'world!');"#,
+            r#""Hello world".replaceAll("world", 'world!');"#,
+        ),
+    ];
+
+    Tester::new(PreferStringReplaceAll::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
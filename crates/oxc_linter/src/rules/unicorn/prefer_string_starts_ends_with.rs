@@ -39,7 +39,8 @@ declare_oxc_lint!(
     /// foo.startsWith("abc");
     /// ```
     PreferStringStartsEndsWith,
-    correctness
+    correctness,
+    ast_types = [CallExpression]
 );
 
 impl Rule for PreferStringStartsEndsWith {
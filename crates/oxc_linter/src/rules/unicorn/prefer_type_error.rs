@@ -40,7 +40,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     PreferTypeError,
-    pedantic
+    pedantic,
+    ast_types = [ThrowStatement]
 );
 
 impl Rule for PreferTypeError {
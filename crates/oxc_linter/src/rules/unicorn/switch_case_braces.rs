@@ -33,7 +33,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     SwitchCaseBraces,
-    style
+    style,
+    ast_types = [SwitchStatement]
 );
 
 impl Rule for SwitchCaseBraces {
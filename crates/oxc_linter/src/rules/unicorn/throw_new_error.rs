@@ -47,7 +47,8 @@ declare_oxc_lint!(
     ///
     /// ```
     ThrowNewError,
-    style
+    style,
+    ast_types = [CallExpression]
 );
 
 impl Rule for ThrowNewError {
@@ -1,13 +1,17 @@
 use std::{
+    borrow::Cow,
     collections::HashMap,
     ffi::OsStr,
     fs,
+    hash::{Hash, Hasher},
+    ops::Range,
     path::{Path, PathBuf},
     rc::Rc,
-    sync::{Arc, Condvar, Mutex},
+    sync::{mpsc, Arc, Condvar, Mutex},
+    time::Instant,
 };
 
-use dashmap::DashMap;
+use dashmap::{DashMap, DashSet};
 use oxc_allocator::Allocator;
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic};
 use oxc_parser::Parser;
@@ -15,11 +19,15 @@ use oxc_resolver::Resolver;
 use oxc_semantic::{ModuleRecord, SemanticBuilder};
 use oxc_span::{SourceType, VALID_EXTENSIONS};
 use rayon::{iter::ParallelBridge, prelude::ParallelIterator};
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet, FxHasher};
+use serde_json::Value;
 
 use crate::{
+    cache::{content_hash, CachedSource, LintCache},
+    config::OXLINTRC,
+    disable_directives::DisableDirectivesBuilder,
     partial_loader::{JavaScriptSource, PartialLoader, LINT_PARTIAL_LOADER_EXT},
-    Fixer, LintContext, Linter, Message,
+    FileTiming, Fixer, LintContext, Linter, Message, ModuleGraph, OxlintConfig,
 };
 
 pub struct LintServiceOptions {
@@ -31,6 +39,16 @@ pub struct LintServiceOptions {
 
     /// TypeScript `tsconfig.json` path for reading path alias and project references
     pub tsconfig: Option<PathBuf>,
+
+    /// Cache of lint results from previous runs, results of unchanged files are reused
+    pub cache: Option<Arc<LintCache>>,
+
+    /// Only report diagnostics starting on these 1-based line ranges of each path, e.g. lines changed in git
+    pub changed_lines: Option<HashMap<Box<Path>, Vec<Range<usize>>>>,
+
+    /// Source text of the only path, read from stdin, in which case the path does not need to exist.
+    /// Fixed code is kept in [`LintService::fixed_stdin`] instead of being written to the path.
+    pub stdin: Option<String>,
 }
 
 #[derive(Clone)]
@@ -54,6 +72,29 @@ impl LintService {
         &self.runtime.linter
     }
 
+    /// The fixed code of the source text read from stdin, or `None` if nothing was fixed.
+    ///
+    /// # Panics
+    /// If the lock of the fixed code is poisoned.
+    pub fn fixed_stdin(&self) -> Option<String> {
+        self.runtime.fixed_stdin.lock().unwrap().take()
+    }
+
+    /// The configuration applying to `path`, including nested configuration files,
+    /// see [`Linter::resolved_config`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if a nested configuration file fails to load.
+    pub fn resolved_config(&self, path: &Path) -> Result<Value, Error> {
+        let (tx_error, rx_error) = mpsc::channel();
+        let linter = self.runtime.nested_linter(path, &tx_error);
+        if let Ok(Some((_, mut errors))) = rx_error.try_recv() {
+            return Err(errors.remove(0));
+        }
+        Ok(linter.as_deref().unwrap_or(&self.runtime.linter).resolved_config(path))
+    }
+
     pub fn number_of_dependencies(&self) -> usize {
         self.runtime.module_map.len() - self.runtime.paths.len()
     }
@@ -65,6 +106,9 @@ impl LintService {
             .iter()
             .par_bridge()
             .for_each_with(&self.runtime, |runtime, path| runtime.process_path(path, tx_error));
+        if self.runtime.linter.options().import_plugin {
+            self.runtime.run_on_module_graph(tx_error);
+        }
         tx_error.send(None).unwrap();
     }
 
@@ -129,7 +173,24 @@ pub struct Runtime {
     linter: Linter,
     resolver: Option<Resolver>,
     module_map: ModuleMap,
+    /// Module records of all processed files, for rules running on the module graph.
+    /// Unlike `module_map`, files are not ignored after reporting diagnostics.
+    module_records: DashMap<Box<Path>, Arc<ModuleRecord>>,
     cache_state: CacheState,
+    /// The nearest `.oxlintrc.json` of each visited directory.
+    nearest_configs: DashMap<Box<Path>, Option<Box<Path>>>,
+    /// Linters for nested configuration files, keyed by the nearest configuration file.
+    /// `None` if the configuration failed to load, in which case the base linter is used.
+    nested_linters: DashMap<Box<Path>, Option<Arc<Linter>>>,
+    cache: Option<Arc<LintCache>>,
+    /// Paths whose diagnostics were replayed from the cache,
+    /// only processed to build their module record for the import plugin.
+    cached_paths: DashSet<Box<Path>>,
+    /// Hash of the content of each file read, for validating the dependencies of cached files.
+    content_hashes: DashMap<Box<Path>, u64>,
+    changed_lines: Option<HashMap<Box<Path>, Vec<Range<usize>>>>,
+    stdin: Option<String>,
+    fixed_stdin: Mutex<Option<String>>,
 }
 
 impl Runtime {
@@ -143,7 +204,16 @@ impl Runtime {
             linter,
             resolver,
             module_map: ModuleMap::default(),
+            module_records: DashMap::default(),
             cache_state: CacheState::default(),
+            nearest_configs: DashMap::default(),
+            nested_linters: DashMap::default(),
+            cache: options.cache,
+            cached_paths: DashSet::default(),
+            content_hashes: DashMap::default(),
+            changed_lines: options.changed_lines,
+            stdin: options.stdin,
+            fixed_stdin: Mutex::default(),
         }
     }
 
@@ -166,6 +236,7 @@ impl Runtime {
     }
 
     fn get_source_type_and_text(
+        &self,
         path: &Path,
         ext: &str,
     ) -> Option<Result<(SourceType, String), Error>> {
@@ -176,6 +247,9 @@ impl Runtime {
             return None;
         }
         let source_type = source_type.unwrap_or_default();
+        if let Some(source_text) = self.stdin_source(path) {
+            return Some(Ok((source_type, source_text.to_string())));
+        }
         let file_result = fs::read_to_string(path).map_err(|e| {
             Error::new(OxcDiagnostic::error(format!(
                 "Failed to open file {path:?} with error \"{e}\""
@@ -197,7 +271,7 @@ impl Runtime {
             return;
         };
 
-        let Some(source_type_and_text) = Self::get_source_type_and_text(path, ext) else {
+        let Some(source_type_and_text) = self.get_source_type_and_text(path, ext) else {
             self.ignore_path(path);
             return;
         };
@@ -211,6 +285,44 @@ impl Runtime {
             }
         };
 
+        // The cache is only used when linting, fixed files are always processed again.
+        let cache = self
+            .cache
+            .as_ref()
+            .filter(|_| {
+                self.linter.options().fix.is_empty()
+                    && self.paths.contains(path)
+                    && self.stdin.is_none()
+            })
+            .map(|cache| (cache, self.cache_key(path, &source_text, tx_error)));
+
+        // Replay the diagnostics of an unchanged file.
+        let mut has_cached_diagnostics = false;
+        if let Some((cache, hash)) = cache {
+            if let Some(entry) = cache.get(path, hash, |path| self.content_hash(path)) {
+                for source in &entry.sources {
+                    let mut errors = source.diagnostics();
+                    has_cached_diagnostics |= !errors.is_empty();
+                    let line = Self::line_of(&source_text, source.start);
+                    let source_text = &source_text[source.start..source.end];
+                    self.retain_changed_lines(path, line, source_text, &mut errors);
+                    if errors.is_empty() {
+                        continue;
+                    }
+                    let path = path.strip_prefix(&self.cwd).unwrap_or(path);
+                    let diagnostics =
+                        DiagnosticService::wrap_diagnostics(path, source_text, errors);
+                    tx_error.send(Some(diagnostics)).unwrap();
+                }
+                if !self.linter.options().import_plugin {
+                    return;
+                }
+                // Other files may depend on this one, so its module record is still built.
+                self.cached_paths.insert(path.into());
+            }
+        }
+        let is_cached = self.cached_paths.contains(path);
+
         let sources = PartialLoader::parse(ext, &source_text);
         let is_processed_by_partial_loader = sources.is_some();
         let sources =
@@ -221,26 +333,251 @@ impl Runtime {
             return;
         }
 
-        for JavaScriptSource { source_text, source_type, .. } in sources {
-            let allocator = Allocator::default();
-            let mut messages =
-                self.process_source(path, &allocator, source_text, source_type, true, tx_error);
-
-            // TODO: Span is wrong, ban this feature for file process by `PartialLoader`.
-            if !is_processed_by_partial_loader && self.linter.options().fix {
-                let fix_result = Fixer::new(source_text, messages).fix();
-                fs::write(path, fix_result.fixed_code.as_bytes()).unwrap();
-                messages = fix_result.messages;
+        // Fixed code of each javascript source, written back to the file all at once.
+        let mut fixed_sources = vec![];
+        // Diagnostics of each javascript source and the modules depended on, stored in the cache.
+        let mut cached_sources = vec![];
+        let mut dependencies = FxHashSet::default();
+
+        for source in sources {
+            let line = Self::line_of(&source_text, source.start);
+            let JavaScriptSource { source_text, source_type, start } = source;
+            let (source_text, mut errors) = if self.linter.options().fix.is_empty() {
+                let allocator = Allocator::default();
+                let messages =
+                    self.process_source(path, &allocator, source_text, source_type, true, tx_error);
+                let errors = messages.into_iter().map(Message::into_diagnostic).collect::<Vec<_>>();
+                if cache.is_some() && !is_cached {
+                    cached_sources.push(CachedSource::new(
+                        start,
+                        start + source_text.len(),
+                        &errors,
+                    ));
+                    dependencies.extend(self.dependencies(path));
+                }
+                (Cow::Borrowed(source_text), errors)
+            } else {
+                let (fixed_code, errors) =
+                    Fixer::fix_iteratively(source_text, |allocator, source_text| {
+                        self.process_source(
+                            path,
+                            allocator,
+                            source_text,
+                            source_type,
+                            true,
+                            tx_error,
+                        )
+                    });
+                (Cow::Owned(fixed_code), errors)
+            };
+
+            if !errors.is_empty() {
+                self.ignore_path(path);
+                self.retain_changed_lines(path, line, &source_text, &mut errors);
             }
 
-            if !messages.is_empty() {
-                self.ignore_path(path);
-                let errors = messages.into_iter().map(|m| m.error).collect();
+            if !errors.is_empty() {
                 let path = path.strip_prefix(&self.cwd).unwrap_or(path);
-                let diagnostics = DiagnosticService::wrap_diagnostics(path, source_text, errors);
+                let diagnostics = DiagnosticService::wrap_diagnostics(path, &source_text, errors);
                 tx_error.send(Some(diagnostics)).unwrap();
             }
+
+            if let Cow::Owned(fixed_code) = source_text {
+                if fixed_code != source.source_text {
+                    fixed_sources.push((source, fixed_code));
+                }
+            }
+        }
+
+        if !fixed_sources.is_empty() {
+            let fixed_code = if is_processed_by_partial_loader {
+                PartialLoader::splice(&source_text, &fixed_sources)
+            } else {
+                fixed_sources.pop().unwrap().1
+            };
+            if self.stdin_source(path).is_some() {
+                *self.fixed_stdin.lock().unwrap() = Some(fixed_code);
+            } else {
+                fs::write(path, fixed_code.as_bytes()).unwrap();
+            }
+        }
+
+        if is_cached {
+            if has_cached_diagnostics {
+                self.ignore_path(path);
+            }
+        } else if let Some((cache, hash)) = cache {
+            let dependencies = dependencies
+                .into_iter()
+                .filter_map(|dependency| {
+                    self.content_hash(&dependency).map(|hash| (dependency, hash))
+                })
+                .collect();
+            cache.insert(path, hash, dependencies, cached_sources);
+        }
+    }
+
+    /// Report the diagnostics of the rules checking how the modules are used by each other,
+    /// see [`Linter::run_on_module_graph`].
+    fn run_on_module_graph(&self, tx_error: &DiagnosticSender) {
+        let modules = self.module_records.iter().map(|entry| Arc::clone(entry.value())).collect();
+        let graph = ModuleGraph::new(modules, &self.paths, &self.cwd);
+        // The linted paths grouped by the linter of their nested configuration files.
+        let mut linters = Vec::<(Option<Arc<Linter>>, Vec<&Path>)>::new();
+        for path in &self.paths {
+            let linter = self.nested_linter(path, tx_error);
+            let group =
+                linters.iter_mut().find(|(nested_linter, _)| match (nested_linter, &linter) {
+                    (Some(nested_linter), Some(linter)) => Arc::ptr_eq(nested_linter, linter),
+                    (None, None) => true,
+                    _ => false,
+                });
+            match group {
+                Some((_, paths)) => paths.push(path),
+                None => linters.push((linter, vec![path])),
+            }
+        }
+        let mut diagnostics = FxHashMap::<&Path, Vec<(&str, OxcDiagnostic)>>::default();
+        for (linter, paths) in &linters {
+            let linter = linter.as_deref().unwrap_or(&self.linter);
+            for (path, rule_name, diagnostic) in linter.run_on_module_graph(&graph, paths) {
+                diagnostics.entry(path).or_default().push((rule_name, diagnostic));
+            }
+        }
+        for (path, diagnostics) in diagnostics {
+            let source_text = match self.stdin_source(path) {
+                Some(source_text) => Cow::Borrowed(source_text),
+                None => match fs::read_to_string(path) {
+                    Ok(source_text) => Cow::Owned(source_text),
+                    Err(_) => continue,
+                },
+            };
+            let Some(source) = Self::module_source(path, &source_text) else {
+                continue;
+            };
+            let line = Self::line_of(&source_text, source.start);
+
+            // Parsed again for the disable directives of the file.
+            let allocator = Allocator::default();
+            let ret = Parser::new(&allocator, source.source_text, source.source_type)
+                .allow_return_outside_function(true)
+                .parse();
+            let disable_directives =
+                DisableDirectivesBuilder::new(source.source_text, ret.trivias).build();
+            let mut errors = diagnostics
+                .into_iter()
+                .filter(|(rule_name, diagnostic)| {
+                    let Some(label) = diagnostic.labels.as_ref().and_then(|labels| labels.first())
+                    else {
+                        return true;
+                    };
+                    #[allow(clippy::cast_possible_truncation)]
+                    let start = label.offset() as u32;
+                    !disable_directives.contains(rule_name, start)
+                })
+                .map(|(_, diagnostic)| diagnostic)
+                .collect::<Vec<_>>();
+
+            self.retain_changed_lines(path, line, source.source_text, &mut errors);
+            if errors.is_empty() {
+                continue;
+            }
+            let path = path.strip_prefix(&self.cwd).unwrap_or(path);
+            let diagnostics = DiagnosticService::wrap_diagnostics(path, source.source_text, errors);
+            tx_error.send(Some(diagnostics)).unwrap();
+        }
+    }
+
+    /// The javascript source of the file at `path` whose module record is in the module graph.
+    ///
+    /// Sources of files processed by the partial loader are processed in order,
+    /// so the module record of the file is the one of its last source.
+    fn module_source<'a>(path: &Path, source_text: &'a str) -> Option<JavaScriptSource<'a>> {
+        let ext = path.extension().and_then(OsStr::to_str)?;
+        if let Some(sources) = PartialLoader::parse(ext, source_text) {
+            return sources.into_iter().last();
+        }
+        let source_type = SourceType::from_path(path).ok()?;
+        Some(JavaScriptSource::new(source_text, source_type, 0))
+    }
+
+    /// The source text read from stdin for `path`, which replaces the content of the file.
+    fn stdin_source(&self, path: &Path) -> Option<&str> {
+        self.stdin.as_deref().filter(|_| self.paths.contains(path))
+    }
+
+    /// 1-based line number of `offset` in `source_text`.
+    fn line_of(source_text: &str, offset: usize) -> usize {
+        memchr::memchr_iter(b'\n', &source_text.as_bytes()[..offset]).count() + 1
+    }
+
+    /// Only keep the diagnostics starting on the changed lines of `path`, when limited to changed lines.
+    /// `source_text` is a javascript source starting on `line` of the file.
+    fn retain_changed_lines(
+        &self,
+        path: &Path,
+        line: usize,
+        source_text: &str,
+        errors: &mut Vec<OxcDiagnostic>,
+    ) {
+        let Some(changed_lines) = &self.changed_lines else {
+            return;
+        };
+        let ranges = changed_lines.get(path).map_or(&[][..], Vec::as_slice);
+        errors.retain(|error| {
+            let Some(label) = error.labels.as_ref().and_then(|labels| labels.first()) else {
+                return true;
+            };
+            let offset = label.offset().min(source_text.len());
+            let line = line + Self::line_of(source_text, offset) - 1;
+            ranges.iter().any(|range| range.contains(&line))
+        });
+    }
+
+    /// Hash of the content of `path` and of the configuration linting it.
+    fn cache_key(&self, path: &Path, source_text: &str, tx_error: &DiagnosticSender) -> u64 {
+        let content_hash = content_hash(source_text);
+        self.content_hashes.insert(path.into(), content_hash);
+        let config_hash = match self.nested_linter(path, tx_error) {
+            Some(linter) => linter.config_hash(path),
+            None => self.linter.config_hash(path),
+        };
+        let mut hasher = FxHasher::default();
+        (content_hash, config_hash).hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Hash of the content of `path`, or `None` if it cannot be read.
+    fn content_hash(&self, path: &Path) -> Option<u64> {
+        if let Some(hash) = self.content_hashes.get(path) {
+            return Some(*hash);
+        }
+        let hash = content_hash(&fs::read_to_string(path).ok()?);
+        self.content_hashes.insert(path.into(), hash);
+        Some(hash)
+    }
+
+    /// All modules `path` depends on, directly or transitively, with the import plugin.
+    fn dependencies(&self, path: &Path) -> Vec<PathBuf> {
+        let Some(module_state) = self.module_map.get(path) else {
+            return vec![];
+        };
+        let ModuleState::Resolved(module_record) = module_state.value() else {
+            return vec![];
+        };
+        let mut visited = FxHashSet::default();
+        visited.insert(module_record.resolved_absolute_path.clone());
+        let mut stack = vec![Arc::clone(module_record)];
+        drop(module_state);
+        while let Some(module_record) = stack.pop() {
+            for loaded_module in &module_record.loaded_modules {
+                if visited.insert(loaded_module.resolved_absolute_path.clone()) {
+                    stack.push(Arc::clone(loaded_module.value()));
+                }
+            }
         }
+        visited.remove(path);
+        visited.into_iter().collect()
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -253,9 +590,11 @@ impl Runtime {
         check_syntax_errors: bool,
         tx_error: &DiagnosticSender,
     ) -> Vec<Message<'a>> {
+        let start = Instant::now();
         let ret = Parser::new(allocator, source_text, source_type)
             .allow_return_outside_function(true)
             .parse();
+        let parse = start.elapsed();
 
         if !ret.errors.is_empty() {
             return ret.errors.into_iter().map(|err| Message::new(err, None)).collect();
@@ -267,18 +606,22 @@ impl Runtime {
 
         // Build the module record to unblock other threads from waiting for too long.
         // The semantic model is not built at this stage.
+        let start = Instant::now();
         let semantic_builder = SemanticBuilder::new(source_text, source_type)
             .with_cfg(true)
             .with_trivias(trivias)
             .with_check_syntax_error(check_syntax_errors)
             .build_module_record(path.to_path_buf(), program);
         let module_record = semantic_builder.module_record();
+        // Waiting for the dependencies is not counted.
+        let mut semantic = start.elapsed();
 
         if self.linter.options().import_plugin {
             self.module_map.insert(
                 path.to_path_buf().into_boxed_path(),
                 ModuleState::Resolved(Arc::clone(&module_record)),
             );
+            self.module_records.insert(path.into(), Arc::clone(&module_record));
             self.update_cache_state(path);
 
             // Retrieve all dependency modules from this module.
@@ -288,7 +631,10 @@ impl Runtime {
                 .keys()
                 .par_bridge()
                 .map_with(self.resolver.as_ref().unwrap(), |resolver, specifier| {
-                    resolver.resolve(dir, specifier).ok().map(|r| (specifier, r))
+                    let resolution = resolver.resolve(dir, specifier).ok();
+                    let resolved_path = resolution.as_ref().map(|r| r.path().to_path_buf());
+                    module_record.resolved_modules.insert(specifier.clone(), resolved_path);
+                    resolution.map(|r| (specifier, r))
                 })
                 .flatten()
                 .for_each_with(tx_error, |tx_error, (specifier, resolution)| {
@@ -340,13 +686,15 @@ impl Runtime {
                     .extend(remote_bindings);
             }
 
-            // Stop if the current module is not marked for lint.
-            if !self.paths.contains(path) {
+            // Stop if the current module is not marked for lint, or its diagnostics are cached.
+            if !self.paths.contains(path) || self.cached_paths.contains(path) {
                 return vec![];
             }
         }
 
+        let start = Instant::now();
         let semantic_ret = semantic_builder.build(program);
+        semantic += start.elapsed();
 
         if !semantic_ret.errors.is_empty() {
             return semantic_ret.errors.into_iter().map(|err| Message::new(err, None)).collect();
@@ -354,7 +702,65 @@ impl Runtime {
 
         let lint_ctx =
             LintContext::new(path.to_path_buf().into_boxed_path(), Rc::new(semantic_ret.semantic));
-        self.linter.run(lint_ctx)
+        let linter = self.nested_linter(path, tx_error);
+        let start = Instant::now();
+        let messages = match &linter {
+            Some(linter) => linter.run(lint_ctx),
+            None => self.linter.run(lint_ctx),
+        };
+        if let Some(timing) = self.linter.timing() {
+            timing.add_file(path, FileTiming { parse, semantic, lint: start.elapsed() });
+        }
+        messages
+    }
+
+    /// The linter configured by the `.oxlintrc.json` files applying to `path`,
+    /// or `None` to use the base linter.
+    ///
+    /// Nested configuration files are only used when no configuration file is given explicitly.
+    fn nested_linter(&self, path: &Path, tx_error: &DiagnosticSender) -> Option<Arc<Linter>> {
+        if self.linter.options().config_path.is_some() {
+            return None;
+        }
+        let dir = self.cwd.join(path.parent()?);
+        let config_path = self.nearest_config(&dir)?;
+        self.nested_linters
+            .entry(config_path.clone())
+            .or_insert_with(|| {
+                let mut config_paths = vec![config_path.to_path_buf()];
+                while let Some(next) = config_paths
+                    .last()
+                    .and_then(|path| path.parent()?.parent())
+                    .and_then(|dir| self.nearest_config(dir))
+                {
+                    config_paths.push(next.to_path_buf());
+                }
+                match OxlintConfig::from_files(&config_paths) {
+                    Ok(config) => Some(Arc::new(self.linter.with_nested_config(config))),
+                    Err(diagnostic) => {
+                        let error = Error::new(diagnostic);
+                        tx_error.send(Some((config_path.to_path_buf(), vec![error]))).unwrap();
+                        None
+                    }
+                }
+            })
+            .value()
+            .clone()
+    }
+
+    /// The nearest `.oxlintrc.json` in `dir` or its ancestors.
+    fn nearest_config(&self, dir: &Path) -> Option<Box<Path>> {
+        if let Some(config_path) = self.nearest_configs.get(dir) {
+            return config_path.clone();
+        }
+        let config_path = dir.join(OXLINTRC);
+        let config_path = if config_path.is_file() {
+            Some(config_path.into_boxed_path())
+        } else {
+            dir.parent().and_then(|dir| self.nearest_config(dir))
+        };
+        self.nearest_configs.insert(dir.into(), config_path.clone());
+        config_path
     }
 
     fn init_cache_state(&self, path: &Path) -> bool {
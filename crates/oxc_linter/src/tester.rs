@@ -9,8 +9,8 @@ use serde::Deserialize;
 use serde_json::Value;
 
 use crate::{
-    rules::RULES, AllowWarnDeny, Fixer, LintOptions, LintService, LintServiceOptions, Linter,
-    OxlintConfig, RuleEnum, RuleWithSeverity,
+    rules::RULES, AllowWarnDeny, FixKind, Fixer, LintOptions, LintService, LintServiceOptions,
+    Linter, OxlintConfig, RuleEnum, RuleWithSeverity,
 };
 
 #[derive(Eq, PartialEq)]
@@ -175,7 +175,8 @@ impl Tester {
     /// Add cases that should fix problems found in the source code.
     ///
     /// These cases will fail if no fixes are produced or if the fixed source
-    /// code does not match the expected result.
+    /// code does not match the expected result. Fixes of all kinds are applied,
+    /// including suggestions and dangerous fixes.
     ///
     /// ```
     /// use oxc_linter::tester::Tester;
@@ -259,7 +260,7 @@ impl Tester {
         let allocator = Allocator::default();
         let rule = self.find_rule().read_json(rule_config.unwrap_or_default());
         let options = LintOptions::default()
-            .with_fix(is_fix)
+            .with_fix(if is_fix { FixKind::all() } else { FixKind::None })
             .with_import_plugin(self.import_plugin)
             .with_jest_plugin(self.jest_plugin)
             .with_vitest_plugin(self.vitest_plugin)
@@ -284,7 +285,14 @@ impl Tester {
 
         let cwd = self.current_working_directory.clone();
         let paths = vec![path_to_lint.into_boxed_path()];
-        let options = LintServiceOptions { cwd, paths, tsconfig: None };
+        let options = LintServiceOptions {
+            cwd,
+            paths,
+            tsconfig: None,
+            cache: None,
+            changed_lines: None,
+            stdin: None,
+        };
         let lint_service = LintService::from_linter(linter, options);
         let diagnostic_service = DiagnosticService::default();
         let tx_error = diagnostic_service.sender();
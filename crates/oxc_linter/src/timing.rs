@@ -0,0 +1,180 @@
+use std::{fmt::Write, path::Path, time::Duration};
+
+use dashmap::DashMap;
+use serde_json::{json, Value};
+
+/// Time spent linting, measured with [`LintOptions::timing`](crate::LintOptions::timing).
+#[derive(Debug, Default)]
+pub struct LintTiming {
+    /// Cumulative time of each rule, keyed like in the configuration, e.g. `react/jsx-key`.
+    rules: DashMap<String, Duration>,
+    files: DashMap<Box<Path>, FileTiming>,
+}
+
+/// Time spent on a file.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FileTiming {
+    pub parse: Duration,
+    pub semantic: Duration,
+    /// Time of all rules.
+    pub lint: Duration,
+}
+
+impl LintTiming {
+    pub(crate) fn add_rule(&self, plugin_name: &str, rule_name: &str, duration: Duration) {
+        let key = match plugin_name {
+            "eslint" => rule_name.to_string(),
+            plugin_name => format!("{plugin_name}/{rule_name}"),
+        };
+        *self.rules.entry(key).or_default() += duration;
+    }
+
+    pub(crate) fn add_file(&self, path: &Path, timing: FileTiming) {
+        let mut entry = self.files.entry(path.into()).or_default();
+        // Files with multiple javascript sources, e.g. vue files, are timed for each of them.
+        entry.parse += timing.parse;
+        entry.semantic += timing.semantic;
+        entry.lint += timing.lint;
+    }
+
+    /// Rules sorted by decreasing time.
+    pub fn rules(&self) -> Vec<(String, Duration)> {
+        let mut rules =
+            self.rules.iter().map(|e| (e.key().clone(), *e.value())).collect::<Vec<_>>();
+        rules.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        rules
+    }
+
+    /// Files sorted by decreasing total time.
+    pub fn files(&self) -> Vec<(Box<Path>, FileTiming)> {
+        let mut files =
+            self.files.iter().map(|e| (e.key().clone(), *e.value())).collect::<Vec<_>>();
+        files.sort_by(|a, b| b.1.total().cmp(&a.1.total()).then_with(|| a.0.cmp(&b.0)));
+        files
+    }
+
+    /// Total time of all files.
+    pub fn total(&self) -> FileTiming {
+        self.files.iter().fold(FileTiming::default(), |total, e| FileTiming {
+            parse: total.parse + e.parse,
+            semantic: total.semantic + e.semantic,
+            lint: total.lint + e.lint,
+        })
+    }
+
+    /// The `limit` slowest rules in a markdown table, like ESLint's `TIMING=1`,
+    /// followed by the total time of each phase.
+    pub fn render_table(&self, limit: usize) -> String {
+        let rules = self.rules();
+        let rules = &rules[..limit.min(rules.len())];
+        let total_rules = self.rules.iter().map(|e| *e.value()).sum::<Duration>();
+        let width = rules.iter().map(|(rule, _)| rule.len()).max().unwrap_or(0).max("Rule".len());
+
+        let mut s = String::new();
+        writeln!(s, "{:<width$} | Time (ms) | Relative", "Rule").unwrap();
+        writeln!(s, ":{:-<width$}|----------:|--------:", "").unwrap();
+        for (rule, duration) in rules {
+            let relative = if total_rules.is_zero() {
+                0.0
+            } else {
+                duration.as_secs_f64() / total_rules.as_secs_f64() * 100.0
+            };
+            writeln!(s, "{rule:<width$} | {:>9.3} | {relative:>7.1}%", millis(*duration)).unwrap();
+        }
+
+        let total = self.total();
+        writeln!(s).unwrap();
+        writeln!(
+            s,
+            "Parse: {:.3}ms, semantic: {:.3}ms, lint: {:.3}ms",
+            millis(total.parse),
+            millis(total.semantic),
+            millis(total.lint)
+        )
+        .unwrap();
+        s
+    }
+
+    /// All timings in milliseconds, with rules and files sorted by decreasing time.
+    pub fn to_json(&self) -> Value {
+        let rules = self
+            .rules()
+            .into_iter()
+            .map(|(rule, duration)| json!({ "rule": rule, "time": millis(duration) }))
+            .collect::<Vec<_>>();
+        let files = self
+            .files()
+            .into_iter()
+            .map(|(path, timing)| {
+                let mut file = timing.to_json();
+                file["path"] = json!(path.to_string_lossy());
+                file
+            })
+            .collect::<Vec<_>>();
+        json!({ "total": self.total().to_json(), "rules": rules, "files": files })
+    }
+}
+
+impl FileTiming {
+    pub fn total(&self) -> Duration {
+        self.parse + self.semantic + self.lint
+    }
+
+    fn to_json(self) -> Value {
+        json!({
+            "parse": millis(self.parse),
+            "semantic": millis(self.semantic),
+            "lint": millis(self.lint),
+        })
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, time::Duration};
+
+    use super::{FileTiming, LintTiming};
+
+    #[test]
+    fn test_timing() {
+        let timing = LintTiming::default();
+        timing.add_rule("eslint", "no-debugger", Duration::from_millis(1));
+        timing.add_rule("react", "jsx-key", Duration::from_millis(3));
+        timing.add_rule("eslint", "no-debugger", Duration::from_millis(1));
+        let file = FileTiming {
+            parse: Duration::from_millis(2),
+            semantic: Duration::from_millis(1),
+            lint: Duration::from_millis(5),
+        };
+        timing.add_file(Path::new("a.js"), file);
+        timing.add_file(Path::new("b.js"), file);
+        timing.add_file(Path::new("b.js"), file);
+
+        assert_eq!(
+            timing.rules(),
+            vec![
+                ("react/jsx-key".to_string(), Duration::from_millis(3)),
+                ("no-debugger".to_string(), Duration::from_millis(2))
+            ]
+        );
+        assert_eq!(timing.files()[0].0.as_ref(), Path::new("b.js"));
+        assert_eq!(timing.total().lint, Duration::from_millis(15));
+
+        let table = timing.render_table(10);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "Rule          | Time (ms) | Relative");
+        assert_eq!(lines[1], ":-------------|----------:|--------:");
+        assert_eq!(lines[2], "react/jsx-key |     3.000 |    60.0%");
+        assert_eq!(lines[3], "no-debugger   |     2.000 |    40.0%");
+        assert_eq!(lines[5], "Parse: 6.000ms, semantic: 3.000ms, lint: 15.000ms");
+
+        let json = timing.to_json();
+        assert_eq!(json["rules"][0]["rule"], "react/jsx-key");
+        assert_eq!(json["files"][1]["path"], "a.js");
+        assert!((json["total"]["parse"].as_f64().unwrap() - 6.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,87 @@
+use oxc_ast::{
+    ast::{MethodDefinitionKind, PropertyKey, PropertyKind},
+    AstKind,
+};
+use oxc_semantic::AstNode;
+
+use crate::LintContext;
+
+/// Describes a function the way ESLint's `getFunctionNameWithKind` does, capitalized to start a
+/// message, e.g. `Function 'foo'`, `Arrow function`, `Async method 'bar'` or `Static getter 'baz'`.
+pub fn get_function_name_with_kind(node: &AstNode, ctx: &LintContext) -> String {
+    let (id, is_async, is_generator) = match node.kind() {
+        AstKind::Function(func) => (func.id.as_ref(), func.r#async, func.generator),
+        AstKind::ArrowFunctionExpression(arrow) => (None, arrow.r#async, false),
+        _ => (None, false, false),
+    };
+
+    let mut tokens = vec![];
+    let modifiers = [(is_async, "async"), (is_generator, "generator")]
+        .into_iter()
+        .filter_map(|(enabled, modifier)| enabled.then_some(modifier));
+    let key: Option<&PropertyKey> = match ctx.nodes().parent_kind(node.id()) {
+        Some(AstKind::MethodDefinition(method)) => {
+            if method.kind == MethodDefinitionKind::Constructor {
+                return "Constructor".to_string();
+            }
+            if method.r#static {
+                tokens.push("static");
+            }
+            if method.key.is_private_identifier() {
+                tokens.push("private");
+            }
+            tokens.extend(modifiers);
+            tokens.push(match method.kind {
+                MethodDefinitionKind::Get => "getter",
+                MethodDefinitionKind::Set => "setter",
+                _ => "method",
+            });
+            Some(&method.key)
+        }
+        Some(AstKind::PropertyDefinition(property)) => {
+            if property.r#static {
+                tokens.push("static");
+            }
+            if property.key.is_private_identifier() {
+                tokens.push("private");
+            }
+            tokens.extend(modifiers);
+            tokens.push("method");
+            Some(&property.key)
+        }
+        Some(AstKind::ObjectProperty(property)) => {
+            tokens.extend(modifiers);
+            tokens.push(match property.kind {
+                PropertyKind::Get => "getter",
+                PropertyKind::Set => "setter",
+                PropertyKind::Init => "method",
+            });
+            Some(&property.key)
+        }
+        _ => {
+            tokens.extend(modifiers);
+            if matches!(node.kind(), AstKind::ArrowFunctionExpression(_)) {
+                tokens.push("arrow");
+            }
+            tokens.push("function");
+            None
+        }
+    };
+
+    let mut description = tokens.join(" ");
+    if let Some(first) = description.get_mut(..1) {
+        first.make_ascii_uppercase();
+    }
+    let name = key
+        .and_then(|key| {
+            key.private_name()
+                .map(|name| format!("#{name}"))
+                .or_else(|| key.static_name().map(|name| format!("'{name}'")))
+        })
+        .or_else(|| id.map(|id| format!("'{}'", id.name)));
+    if let Some(name) = name {
+        description.push(' ');
+        description.push_str(&name);
+    }
+    description
+}
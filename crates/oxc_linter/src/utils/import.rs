@@ -0,0 +1,106 @@
+use std::path::{Component, Path};
+
+use phf::phf_set;
+
+/// Modules built into Node.js, which are also importable with a `node:` prefix.
+/// <https://nodejs.org/api/modules.html#built-in-modules>
+const NODE_BUILTIN_MODULES: phf::Set<&'static str> = phf_set! {
+    "assert", "assert/strict", "async_hooks", "buffer", "child_process", "cluster", "console",
+    "constants", "crypto", "dgram", "diagnostics_channel", "dns", "dns/promises", "domain",
+    "events", "fs", "fs/promises", "http", "http2", "https", "inspector", "inspector/promises",
+    "module", "net", "os", "path", "path/posix", "path/win32", "perf_hooks", "process",
+    "punycode", "querystring", "readline", "readline/promises", "repl", "stream",
+    "stream/consumers", "stream/promises", "stream/web", "string_decoder", "sys", "timers",
+    "timers/promises", "tls", "trace_events", "tty", "url", "util", "util/types", "v8", "vm",
+    "wasi", "worker_threads", "zlib",
+};
+
+/// Whether `specifier` is a module built into Node.js, e.g. `fs` or `node:fs`.
+pub fn is_builtin_module(specifier: &str) -> bool {
+    specifier.starts_with("node:") || NODE_BUILTIN_MODULES.contains(specifier)
+}
+
+/// The kind of module imported by a specifier, as classified by eslint-plugin-import.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportType {
+    /// `fs`, `node:path`
+    Builtin,
+    /// `react`, `@scope/package`, resolved to a `node_modules` directory or not resolved at all
+    External,
+    /// `src/foo`, `@/foo`, bare specifiers resolved outside of `node_modules`, e.g. with tsconfig paths
+    Internal,
+    /// `../foo`
+    Parent,
+    /// `./foo`
+    Sibling,
+    /// `.`, `./`, `./index`, `./index.js`
+    Index,
+    /// `/foo`
+    Absolute,
+    Unknown,
+}
+
+impl ImportType {
+    /// Classify `specifier`, resolved to `resolved_path` if it was resolved.
+    pub fn of(specifier: &str, resolved_path: Option<&Path>) -> Self {
+        if specifier.starts_with('/') {
+            Self::Absolute
+        } else if is_builtin_module(specifier) {
+            Self::Builtin
+        } else if specifier == ".." || specifier.starts_with("../") {
+            Self::Parent
+        } else if matches!(specifier, "." | "./" | "./index") || specifier.starts_with("./index.") {
+            Self::Index
+        } else if specifier.starts_with("./") {
+            Self::Sibling
+        } else if !is_external_looking_name(specifier) {
+            Self::Unknown
+        } else if resolved_path.map_or(true, is_in_node_modules) {
+            Self::External
+        } else {
+            Self::Internal
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Builtin => "builtin",
+            Self::External => "external",
+            Self::Internal => "internal",
+            Self::Parent => "parent",
+            Self::Sibling => "sibling",
+            Self::Index => "index",
+            Self::Absolute => "absolute",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+/// Whether `specifier` looks like the name of a package, e.g. `react`, `lodash/fp` or `@scope/package`.
+pub fn is_external_looking_name(specifier: &str) -> bool {
+    let mut chars = specifier.chars();
+    match chars.next() {
+        Some('@') => {
+            chars.next().is_some_and(|c| c.is_ascii_alphanumeric()) && specifier.contains('/')
+        }
+        Some(c) => c.is_ascii_alphanumeric() || c == '_',
+        None => false,
+    }
+}
+
+/// Whether `specifier` is the root of a package, e.g. `react` or `@scope/package`, but not `react/jsx-runtime`.
+pub fn is_external_root_module(specifier: &str) -> bool {
+    if !is_external_looking_name(specifier) {
+        return false;
+    }
+    let slashes = specifier.matches('/').count();
+    if specifier.starts_with('@') {
+        slashes == 1
+    } else {
+        slashes == 0
+    }
+}
+
+fn is_in_node_modules(path: &Path) -> bool {
+    path.components().any(|component| component == Component::Normal("node_modules".as_ref()))
+}
@@ -89,6 +89,19 @@ pub fn is_jest_file(ctx: &LintContext) -> bool {
         .any(|ext| file_path.ends_with(ext))
 }
 
+/// Whether the file may contain any Jest or Vitest function call, i.e. it references a Jest
+/// global, declares a binding named like one, or imports from `@jest/globals` or `vitest`.
+/// Rules only reporting Jest function calls can skip the files where this is `false`.
+pub fn may_contain_jest_fn_call(ctx: &LintContext) -> bool {
+    let requested_modules = &ctx.module_record().requested_modules;
+    if requested_modules.contains_key("@jest/globals") || requested_modules.contains_key("vitest") {
+        return true;
+    }
+    let root_unresolved_references = ctx.scopes().root_unresolved_references();
+    JEST_METHOD_NAMES.iter().any(|name| root_unresolved_references.contains_key(*name))
+        || ctx.symbols().names.iter().any(|name| JEST_METHOD_NAMES.contains(name.as_str()))
+}
+
 pub fn is_type_of_jest_fn_call<'a>(
     call_expr: &'a CallExpression<'a>,
     possible_jest_node: &PossibleJestNode<'a, '_>,
@@ -327,4 +340,30 @@ mod test {
         let ctx = LintContext::new(Box::from(path), semantic_ret);
         assert!(super::is_jest_file(&ctx));
     }
+
+    #[test]
+    fn test_may_contain_jest_fn_call() {
+        let may_contain_jest_fn_call = |source_text: &str| {
+            let allocator = Allocator::default();
+            let source_type = SourceType::default().with_module(true);
+            let parser_ret = Parser::new(&allocator, source_text, source_type).parse();
+            let program = allocator.alloc(parser_ret.program);
+            let semantic_ret = SemanticBuilder::new(source_text, source_type)
+                .with_cfg(true)
+                .build_module_record(Path::new("foo.js").to_path_buf(), program)
+                .build(program)
+                .semantic;
+            let ctx = LintContext::new(Box::from(Path::new("foo.js")), Rc::new(semantic_ret));
+            super::may_contain_jest_fn_call(&ctx)
+        };
+
+        assert!(may_contain_jest_fn_call("test('foo', () => {});"));
+        assert!(may_contain_jest_fn_call("describe.each([])('foo', () => {});"));
+        assert!(may_contain_jest_fn_call("import { it as check } from '@jest/globals';"));
+        assert!(may_contain_jest_fn_call("import { expect as e } from 'vitest'; e(1);"));
+        assert!(may_contain_jest_fn_call("const { test } = require('@jest/globals');"));
+
+        assert!(!may_contain_jest_fn_call("foo('bar', () => {}); bar.test('baz');"));
+        assert!(!may_contain_jest_fn_call("import { foo } from 'foo'; foo();"));
+    }
 }
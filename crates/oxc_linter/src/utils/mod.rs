@@ -1,3 +1,5 @@
+mod function;
+mod import;
 mod jest;
 mod jsdoc;
 mod nextjs;
@@ -6,10 +8,16 @@ mod react_perf;
 mod tree_shaking;
 mod unicorn;
 
+use oxc_semantic::SymbolId;
+use oxc_span::Span;
+use schemars::JsonSchema;
+use serde::{de::DeserializeOwned, Deserialize};
+
 use crate::LintContext;
 
 pub use self::{
-    jest::*, jsdoc::*, nextjs::*, react::*, react_perf::*, tree_shaking::*, unicorn::*,
+    function::*, import::*, jest::*, jsdoc::*, nextjs::*, react::*, react_perf::*, tree_shaking::*,
+    unicorn::*,
 };
 
 /// Check if the Jest rule is adapted to Vitest.
@@ -42,3 +50,46 @@ fn is_using_vitest(ctx: &LintContext) -> bool {
     // Or, find the eslint config file
     ctx.rules().iter().any(|rule| rule.plugin_name == "vitest")
 }
+
+/// The span of the name of a symbol, without the type annotation
+/// the span of its binding covers, e.g. `a` in `let a: number`.
+#[allow(clippy::cast_possible_truncation)]
+pub fn symbol_name_span(symbol_id: SymbolId, ctx: &LintContext) -> Span {
+    let symbols = ctx.symbols();
+    Span::sized(symbols.get_span(symbol_id).start, symbols.get_name(symbol_id).len() as u32)
+}
+
+/// Deserializes the rule option at `index`, e.g. `{ "max": 3 }` at `0` in `["error", { "max": 3 }]`.
+///
+/// Falls back to the default when the option is missing. Options are validated against the schema
+/// of their type when loading the configuration, so an invalid one also falls back to the default.
+pub fn rule_option<T: DeserializeOwned + Default>(options: &serde_json::Value, index: usize) -> T {
+    options.get(index).and_then(|option| T::deserialize(option).ok()).unwrap_or_default()
+}
+
+/// The maximum of the rules limiting a count, e.g. `max-depth`,
+/// given as a number or as `{ "max": 4 }`.
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum MaxOption {
+    Max(usize),
+    Object(MaxObjectOption),
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, JsonSchema)]
+pub struct MaxObjectOption {
+    /// The maximum.
+    max: Option<usize>,
+    /// Deprecated alias of `max`.
+    maximum: Option<usize>,
+}
+
+impl MaxOption {
+    /// The maximum, `None` when the object form sets neither `max` nor `maximum`.
+    pub fn max(self) -> Option<usize> {
+        match self {
+            Self::Max(max) => Some(max),
+            Self::Object(option) => option.max.or(option.maximum),
+        }
+    }
+}
@@ -22,3 +22,14 @@ pub fn get_next_script_import_local_name<'a>(ctx: &'a LintContext) -> Option<&'a
         }
     })
 }
+
+/// Whether the Next.js rules can apply to the file, which type definitions never do.
+pub fn is_nextjs_module(ctx: &LintContext) -> bool {
+    !ctx.source_type().is_typescript_definition()
+}
+
+/// Whether the Next.js rules checking JSX elements can apply to the file,
+/// i.e. it is a `.js`, `.jsx` or `.tsx` module.
+pub fn is_nextjs_jsx_module(ctx: &LintContext) -> bool {
+    is_nextjs_module(ctx) && ctx.source_type().is_jsx()
+}
@@ -1,3 +1,4 @@
+use oxc_ast::AstType;
 use oxc_linter::{RuleCategory, RuleMeta};
 use oxc_macros::declare_oxc_lint_test;
 
@@ -21,6 +22,15 @@ declare_oxc_lint_test!(
     correctness
 );
 
+struct TestRule3;
+
+declare_oxc_lint_test!(
+    /// Dummy description3
+    TestRule3,
+    correctness,
+    ast_types = [CallExpression, NewExpression]
+);
+
 #[test]
 fn test_declare_oxc_lint() {
     // Simple, multiline documentation
@@ -31,4 +41,8 @@ fn test_declare_oxc_lint() {
 
     // Auto-generated kebab-case name
     assert_eq!(TestRule::NAME, "test-rule");
+
+    // Rules visit every node by default
+    assert_eq!(TestRule::AST_TYPES, None);
+    assert_eq!(TestRule3::AST_TYPES, Some(&[AstType::CallExpression, AstType::NewExpression][..]));
 }
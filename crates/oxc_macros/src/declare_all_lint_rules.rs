@@ -87,6 +87,12 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
+            pub fn ast_types(&self) -> Option<&'static [oxc_ast::AstType]> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::AST_TYPES),*
+                }
+            }
+
             pub fn plugin_name(&self) -> &str {
                 match self {
                     #(Self::#struct_names(_) => #plugin_names),*
@@ -101,6 +107,21 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
+            pub fn options_schema(
+                &self,
+                gen: &mut schemars::gen::SchemaGenerator,
+            ) -> Option<Vec<schemars::schema::Schema>> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::options_schema(gen)),*
+                }
+            }
+
+            pub(super) fn should_run(&self, ctx: &LintContext) -> bool {
+                match self {
+                    #(Self::#struct_names(rule) => rule.should_run(ctx)),*
+                }
+            }
+
             pub(super) fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
                 match self {
                     #(Self::#struct_names(rule) => rule.run(node, ctx)),*
@@ -118,6 +139,21 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                     #(Self::#struct_names(rule) => rule.run_once(ctx)),*
                 }
             }
+
+            pub(super) fn runs_on_module_graph(&self) -> bool {
+                match self {
+                    #(Self::#struct_names(rule) => rule.runs_on_module_graph()),*
+                }
+            }
+
+            pub(super) fn run_on_module_graph(
+                &self,
+                graph: &crate::ModuleGraph,
+            ) -> Vec<(std::path::PathBuf, oxc_diagnostics::OxcDiagnostic)> {
+                match self {
+                    #(Self::#struct_names(rule) => rule.run_on_module_graph(graph)),*
+                }
+            }
         }
 
         impl std::hash::Hash for RuleEnum {
@@ -2,6 +2,7 @@ use convert_case::{Case, Casing};
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{
+    bracketed,
     parse::{Parse, ParseStream},
     Attribute, Error, Expr, Ident, Lit, LitStr, Meta, Result, Token,
 };
@@ -9,6 +10,8 @@ use syn::{
 pub struct LintRuleMeta {
     name: Ident,
     category: Ident,
+    /// AST node types visited by `Rule::run`, `None` for all of them.
+    ast_types: Option<Vec<Ident>>,
     documentation: String,
     pub used_in_test: bool,
 }
@@ -32,15 +35,29 @@ impl Parse for LintRuleMeta {
         input.parse::<Token!(,)>()?;
         let category = input.parse()?;
 
+        let mut ast_types = None;
+        if input.peek(Token!(,)) && input.peek2(Ident) {
+            input.parse::<Token!(,)>()?;
+            let option = input.parse::<Ident>()?;
+            if option != "ast_types" {
+                return Err(Error::new_spanned(option, "unexpected option"));
+            }
+            input.parse::<Token!(=)>()?;
+            let content;
+            bracketed!(content in input);
+            ast_types =
+                Some(content.parse_terminated(Ident::parse, Token!(,))?.into_iter().collect());
+        }
+
         // Ignore the rest
         input.parse::<proc_macro2::TokenStream>()?;
 
-        Ok(Self { name: struct_name, category, documentation, used_in_test: false })
+        Ok(Self { name: struct_name, category, ast_types, documentation, used_in_test: false })
     }
 }
 
 pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
-    let LintRuleMeta { name, category, documentation, used_in_test } = metadata;
+    let LintRuleMeta { name, category, ast_types, documentation, used_in_test } = metadata;
     let canonical_name = name.to_string().to_case(Case::Kebab);
     let category = match category.to_string().as_str() {
         "correctness" => quote! { RuleCategory::Correctness },
@@ -53,6 +70,13 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
         _ => panic!("invalid rule category"),
     };
 
+    let ast_types = ast_types.map(|ast_types| {
+        quote! {
+            const AST_TYPES: Option<&'static [oxc_ast::AstType]> =
+                Some(&[#(oxc_ast::AstType::#ast_types),*]);
+        }
+    });
+
     let import_statement = if used_in_test {
         None
     } else {
@@ -67,6 +91,8 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
 
             const CATEGORY: RuleCategory = #category;
 
+            #ast_types
+
             fn documentation() -> Option<&'static str> {
                 Some(#documentation)
             }
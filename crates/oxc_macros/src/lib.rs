@@ -11,6 +11,12 @@ mod declare_oxc_lint;
 /// 1. The documentation
 /// 2. The lint's struct
 ///
+/// followed by the rule category, and optionally by `ast_types = [...]`,
+/// the [`AstType`]s of the nodes `Rule::run` is interested in.
+/// Without it, `Rule::run` is called on every node.
+///
+/// [`AstType`]: https://docs.rs/oxc_ast/latest/oxc_ast/enum.AstType.html
+///
 /// # Example
 ///
 /// ```
@@ -38,6 +38,13 @@ pub struct ModuleRecord {
     /// The list does not contain two different Records with the same `[[Specifier]]`.
     pub loaded_modules: DashMap<CompactStr, Arc<ModuleRecord>, BuildHasherDefault<FxHasher>>,
 
+    /// Resolved paths of the requested modules, keyed by specifier,
+    /// `None` for the specifiers which failed to resolve.
+    ///
+    /// Only filled when resolving the module graph, unlike `loaded_modules` it also contains
+    /// the modules which are not parsed, e.g. json files.
+    pub resolved_modules: DashMap<CompactStr, Option<PathBuf>, BuildHasherDefault<FxHasher>>,
+
     /// `[[ImportEntries]]`
     ///
     /// A List of ImportEntry records derived from the code of this module
@@ -102,6 +109,7 @@ impl fmt::Debug for ModuleRecord {
             .field("resolved_absolute_path", &self.resolved_absolute_path)
             .field("requested_modules", &self.requested_modules)
             .field("loaded_modules", &loaded_modules)
+            .field("resolved_modules", &self.resolved_modules)
             .field("import_entries", &self.import_entries)
             .field("local_export_entries", &self.local_export_entries)
             .field("indirect_export_entries", &self.indirect_export_entries)
@@ -96,6 +96,14 @@ impl Renderer {
     }
 
     fn get_referenced_schema<'a>(&'a self, object: &'a SchemaObject) -> &'a SchemaObject {
+        // A reference with only a default value is wrapped as
+        // `{ "default": ..., "allOf": [{ "$ref": ... }] }`.
+        let has_description = object.metadata.as_ref().is_some_and(|m| m.description.is_some());
+        if let (false, Some([schema])) =
+            (has_description, object.subschemas.as_ref().and_then(|s| s.all_of.as_deref()))
+        {
+            return self.get_referenced_schema(Self::get_schema_object(schema));
+        }
         if let Some(reference) = &object.reference {
             let definitions = &self.root_schema.definitions;
             let definition = definitions.get(reference.trim_start_matches("#/definitions/"));